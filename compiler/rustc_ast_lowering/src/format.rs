@@ -2,7 +2,7 @@
 use rustc_ast as ast;
 use rustc_ast::visit::{self, Visitor};
 use rustc_ast::*;
-use rustc_data_structures::fx::FxIndexMap;
+use rustc_data_structures::fx::{FxHashMap, FxIndexMap};
 use rustc_hir as hir;
 use rustc_span::{
     sym,
@@ -16,12 +16,24 @@ pub(crate) fn lower_format_args(&mut self, sp: Span, fmt: &FormatArgs) -> hir::E
         // Never call the const constructor of `fmt::Arguments` if the
         // format_args!() had any arguments _before_ flattening/inlining.
         let allow_const = fmt.arguments.all_args().is_empty();
+        // Remember where each argument was written before flattening/inlining moves them,
+        // so that errors can point out the argument of the invocation.
+        let positions: FxHashMap<_, _> = fmt
+            .arguments
+            .all_args()
+            .iter()
+            .enumerate()
+            .map(|(i, arg)| match arg.kind {
+                FormatArgumentKind::Captured(_) => (arg.expr.id, None),
+                _ => (arg.expr.id, Some(i)),
+            })
+            .collect();
         let mut fmt = Cow::Borrowed(fmt);
         if self.tcx.sess.opts.unstable_opts.flatten_format_args {
             fmt = flatten_format_args(fmt);
             fmt = inline_literals(fmt);
         }
-        expand_format_args(self, sp, &fmt, allow_const)
+        expand_format_args(self, sp, &fmt, &positions, allow_const)
     }
 }
 
@@ -181,7 +193,8 @@ enum ArgumentType {
     Usize,
 }
 
-/// Generate a hir expression representing an argument to a format_args invocation.
+/// Generate a hir expression representing an argument to a format_args invocation,
+/// and record the position of the argument in the invocation, if known.
 ///
 /// Generates:
 ///
@@ -193,6 +206,7 @@ fn make_argument<'hir>(
     sp: Span,
     arg: &'hir hir::Expr<'hir>,
     ty: ArgumentType,
+    position: Option<Option<usize>>,
 ) -> hir::Expr<'hir> {
     use ArgumentType::*;
     use FormatTrait::*;
@@ -212,7 +226,11 @@ fn make_argument<'hir>(
             Usize => sym::from_usize,
         },
     ));
-    ctx.expr_call_mut(sp, new_fn, std::slice::from_ref(arg))
+    let call = ctx.expr_call_mut(sp, new_fn, std::slice::from_ref(arg));
+    if let Some(position) = position {
+        ctx.format_arguments.insert(call.hir_id.local_id, position);
+    }
+    call
 }
 
 /// Generate a hir expression for a format_args Count.
@@ -350,6 +368,7 @@ fn expand_format_args<'hir>(
     ctx: &mut LoweringContext<'_, 'hir>,
     macsp: Span,
     fmt: &FormatArgs,
+    positions: &FxHashMap<NodeId, Option<usize>>,
     allow_const: bool,
 ) -> hir::ExprKind<'hir> {
     let mut incomplete_lit = String::new();
@@ -487,12 +506,13 @@ fn expand_format_args<'hir>(
                     FormatArgumentKind::Captured(_) => placeholder_span,
                     _ => arg.expr.span.with_ctxt(macsp.ctxt()),
                 };
+                let position = positions.get(&arg.expr.id).copied();
                 let arg = ctx.lower_expr(&arg.expr);
                 let ref_arg = ctx.arena.alloc(ctx.expr(
                     arg_span,
                     hir::ExprKind::AddrOf(hir::BorrowKind::Ref, hir::Mutability::Not, arg),
                 ));
-                make_argument(ctx, placeholder_span, ref_arg, ty)
+                make_argument(ctx, placeholder_span, ref_arg, ty, position)
             })
             .collect();
         ctx.expr_array_ref(macsp, ctx.arena.alloc_from_iter(elements))
@@ -517,6 +537,7 @@ fn expand_format_args<'hir>(
                     FormatArgumentKind::Captured(_) => placeholder_span,
                     _ => arg.expr.span.with_ctxt(macsp.ctxt()),
                 };
+                let position = positions.get(&arg.expr.id).copied();
                 let args_ident_expr = ctx.expr_ident(macsp, args_ident, args_hir_id);
                 let arg = ctx.arena.alloc(ctx.expr(
                     arg_span,
@@ -525,7 +546,7 @@ fn expand_format_args<'hir>(
                         Ident::new(sym::integer(arg_index), macsp),
                     ),
                 ));
-                make_argument(ctx, placeholder_span, arg, ty)
+                make_argument(ctx, placeholder_span, arg, ty, position)
             },
         ));
         let elements: Vec<_> = arguments
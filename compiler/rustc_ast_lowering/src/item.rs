@@ -69,6 +69,7 @@ fn with_lctx(
             item_local_id_counter: hir::ItemLocalId::new(0),
             node_id_to_local_id: Default::default(),
             trait_map: Default::default(),
+            format_arguments: Default::default(),
 
             // Lowering state.
             catch_scope: None,
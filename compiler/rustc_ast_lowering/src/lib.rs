@@ -127,6 +127,7 @@ struct LoweringContext<'a, 'hir> {
     current_hir_id_owner: hir::OwnerId,
     item_local_id_counter: hir::ItemLocalId,
     trait_map: FxHashMap<ItemLocalId, Box<[TraitCandidate]>>,
+    format_arguments: FxHashMap<ItemLocalId, Option<usize>>,
 
     impl_trait_defs: Vec<hir::GenericParam<'hir>>,
     impl_trait_bounds: Vec<hir::WherePredicate<'hir>>,
@@ -587,6 +588,7 @@ fn with_hir_id_owner(
         let current_bodies = std::mem::take(&mut self.bodies);
         let current_node_ids = std::mem::take(&mut self.node_id_to_local_id);
         let current_trait_map = std::mem::take(&mut self.trait_map);
+        let current_format_arguments = std::mem::take(&mut self.format_arguments);
         let current_owner =
             std::mem::replace(&mut self.current_hir_id_owner, hir::OwnerId { def_id });
         let current_local_counter =
@@ -613,6 +615,7 @@ fn with_hir_id_owner(
         self.bodies = current_bodies;
         self.node_id_to_local_id = current_node_ids;
         self.trait_map = current_trait_map;
+        self.format_arguments = current_format_arguments;
         self.current_hir_id_owner = current_owner;
         self.item_local_id_counter = current_local_counter;
         self.impl_trait_defs = current_impl_trait_defs;
@@ -647,6 +650,7 @@ fn make_owner_info(&mut self, node: hir::OwnerNode<'hir>) -> &'hir hir::OwnerInf
         let attrs = std::mem::take(&mut self.attrs);
         let mut bodies = std::mem::take(&mut self.bodies);
         let trait_map = std::mem::take(&mut self.trait_map);
+        let format_arguments = std::mem::take(&mut self.format_arguments);
 
         #[cfg(debug_assertions)]
         for (id, attrs) in attrs.iter() {
@@ -682,7 +686,7 @@ fn make_owner_info(&mut self, node: hir::OwnerNode<'hir>) -> &'hir hir::OwnerInf
         let nodes = hir::OwnerNodes { opt_hash_including_bodies, nodes, bodies };
         let attrs = hir::AttributeMap { map: attrs, opt_hash: attrs_hash };
 
-        self.arena.alloc(hir::OwnerInfo { nodes, parenting, attrs, trait_map })
+        self.arena.alloc(hir::OwnerInfo { nodes, parenting, attrs, trait_map, format_arguments })
     }
 
     /// This method allocates a new `HirId` for the given `NodeId` and stores it in
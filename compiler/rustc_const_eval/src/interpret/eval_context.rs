@@ -894,6 +894,21 @@ fn deallocate_local(&mut self, local: LocalValue<M::Provenance>) -> InterpResult
         Ok(())
     }
 
+    /// If the current frame evaluates the length of an array type, returns the span of that type.
+    fn array_len_ty_span(&self) -> Option<Span> {
+        let def_id = self.stack().last()?.instance.def_id().as_local()?;
+        let hir = self.tcx.hir();
+        let hir::Node::Ty(ty) = hir.find_parent(hir.local_def_id_to_hir_id(def_id))? else {
+            return None;
+        };
+        match ty.kind {
+            hir::TyKind::Array(_, hir::ArrayLen::Body(len)) if len.def_id == def_id => {
+                Some(ty.span)
+            }
+            _ => None,
+        }
+    }
+
     /// Call a query that can return `ErrorHandled`. If `span` is `Some`, point to that span when an error occurs.
     pub fn ctfe_query<T>(
         &self,
@@ -906,7 +921,16 @@ pub fn ctfe_query<T>(
                 ErrorHandled::Reported(err) => {
                     if !err.is_tainted_by_errors() && let Some(span) = span {
                         // To make it easier to figure out where this error comes from, also add a note at the current location.
-                        self.tcx.sess.span_note_without_error(span, "erroneous constant used");
+                        let mut note =
+                            self.tcx.sess.struct_note_without_error("erroneous constant used");
+                        note.set_span(span);
+                        if let Some(array_ty_span) = self.array_len_ty_span() {
+                            note.span_note(
+                                array_ty_span,
+                                "the erroneous constant is used as the length of this array type",
+                            );
+                        }
+                        note.emit();
                     }
                     err_inval!(AlreadyReported(err))
                 }
@@ -577,6 +577,18 @@ fn push_suggestion(&mut self, suggestion: CodeSuggestion) {
         }
     }
 
+    /// Assigns a stable identifier to the suggestion added last, such as
+    /// `typeck::remove_semicolon`. It is emitted in JSON diagnostics, so that tools can
+    /// pick which automated fixes to apply by their identifier rather than their message.
+    pub fn suggestion_id(&mut self, id: &'static str) -> &mut Self {
+        if let Ok(suggestions) = &mut self.suggestions
+            && let Some(suggestion) = suggestions.last_mut()
+        {
+            suggestion.id = Some(Cow::Borrowed(id));
+        }
+        self
+    }
+
     /// Show a suggestion that has multiple parts to it.
     /// In other words, multiple changes need to be applied as part of this suggestion.
     pub fn multipart_suggestion(
@@ -640,6 +652,7 @@ pub fn multipart_suggestion_with_style(
             msg: self.subdiagnostic_message_to_diagnostic_message(msg),
             style,
             applicability,
+            id: None,
         });
         self
     }
@@ -718,6 +731,7 @@ pub fn span_suggestion_with_style(
             msg: self.subdiagnostic_message_to_diagnostic_message(msg),
             style,
             applicability,
+            id: None,
         });
         self
     }
@@ -784,6 +798,7 @@ pub fn span_suggestions_with_style(
             msg: self.subdiagnostic_message_to_diagnostic_message(msg),
             style,
             applicability,
+            id: None,
         });
         self
     }
@@ -828,6 +843,7 @@ pub fn multipart_suggestions(
             msg: self.subdiagnostic_message_to_diagnostic_message(msg),
             style: SuggestionStyle::ShowCode,
             applicability,
+            id: None,
         });
         self
     }
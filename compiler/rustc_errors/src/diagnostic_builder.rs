@@ -682,6 +682,7 @@ pub fn span_labels(
 
     forward!(pub fn disable_suggestions(&mut self,) -> &mut Self);
     forward!(pub fn clear_suggestions(&mut self,) -> &mut Self);
+    forward!(pub fn suggestion_id(&mut self, id: &'static str) -> &mut Self);
 
     forward!(pub fn multipart_suggestion(
         &mut self,
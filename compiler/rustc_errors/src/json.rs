@@ -235,6 +235,9 @@ struct Diagnostic {
     children: Vec<Diagnostic>,
     /// The message as rustc would render it.
     rendered: Option<String>,
+    /// The stable identifier of the suggestion this diagnostic carries, if it has one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    suggestion_id: Option<String>,
 }
 
 #[derive(Serialize)]
@@ -339,6 +342,7 @@ fn from_errors_diagnostic(diag: &crate::Diagnostic, je: &JsonEmitter) -> Diagnos
                 spans: DiagnosticSpan::from_suggestion(sugg, &args, je),
                 children: vec![],
                 rendered: None,
+                suggestion_id: sugg.id.as_ref().map(|id| id.to_string()),
             }
         });
 
@@ -388,6 +392,7 @@ fn flush(&mut self) -> io::Result<()> {
                 .chain(sugg)
                 .collect(),
             rendered: Some(output),
+            suggestion_id: None,
         }
     }
 
@@ -408,6 +413,7 @@ fn from_sub_diagnostic(
                 .unwrap_or_else(|| DiagnosticSpan::from_multispan(&diag.span, args, je)),
             children: vec![],
             rendered: None,
+            suggestion_id: None,
         }
     }
 }
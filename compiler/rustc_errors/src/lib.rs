@@ -139,6 +139,9 @@ pub struct CodeSuggestion {
     /// which are useful for users but not useful for
     /// tools like rustfix
     pub applicability: Applicability,
+    /// A stable identifier for this kind of suggestion (e.g. `typeck::remove_semicolon`),
+    /// emitted in JSON diagnostics so that tools can choose which fixes to apply.
+    pub id: Option<Cow<'static, str>>,
 }
 
 #[derive(Clone, Debug, PartialEq, Hash, Encodable, Decodable)]
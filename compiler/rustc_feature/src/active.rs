@@ -150,6 +150,9 @@ pub fn set(&self, features: &mut Features, span: Span) {
     (active, allow_internal_unstable, "1.0.0", None, None),
     /// Allows using anonymous lifetimes in argument-position impl-trait.
     (active, anonymous_lifetime_in_impl_trait, "1.63.0", None, None),
+    /// Allows coercing a borrowed rvalue to the pointer type expected behind the borrow,
+    /// as in `&&s` where `&&str` is expected. Experimental, pending a lang team decision.
+    (active, coerce_borrowed_pointers, "CURRENT_RUSTC_VERSION", None, None),
     /// Allows identifying the `compiler_builtins` crate.
     (active, compiler_builtins, "1.13.0", None, None),
     /// Allows writing custom MIR
@@ -902,6 +902,11 @@ pub struct OwnerInfo<'hir> {
     /// Map indicating what traits are in scope for places where this
     /// is relevant; generated by resolve.
     pub trait_map: FxHashMap<ItemLocalId, Box<[TraitCandidate]>>,
+    /// Map from the calls to `fmt::Argument` constructors that `format_args!` is lowered into
+    /// to the position of the formatted argument in the invocation of `format_args!`, or `None`
+    /// if it was captured from the format string. Arguments of a nested `format_args!` that was
+    /// flattened into its parent have no entry.
+    pub format_arguments: FxHashMap<ItemLocalId, Option<usize>>,
 }
 
 impl<'tcx> OwnerInfo<'tcx> {
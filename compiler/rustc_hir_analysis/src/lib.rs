@@ -105,7 +105,7 @@
 use rustc_infer::infer::TyCtxtInferExt;
 use rustc_middle::middle;
 use rustc_middle::query::Providers;
-use rustc_middle::ty::{self, Ty, TyCtxt};
+use rustc_middle::ty::{self, Ty, TyCtxt, TypeVisitableExt};
 use rustc_middle::util;
 use rustc_session::{config::EntryFnType, parse::feature_err};
 use rustc_span::def_id::{DefId, LocalDefId, CRATE_DEF_ID};
@@ -303,19 +303,23 @@ fn main_fn_return_type_span(tcx: TyCtxt<'_>, def_id: DefId) -> Option<Span> {
             error = true;
         }
         let return_ty = return_ty.skip_binder();
-        let infcx = tcx.infer_ctxt().build();
-        let cause = traits::ObligationCause::new(
-            return_ty_span,
-            main_diagnostics_def_id,
-            ObligationCauseCode::MainFunctionType,
-        );
-        let ocx = traits::ObligationCtxt::new(&infcx);
-        let norm_return_ty = ocx.normalize(&cause, param_env, return_ty);
-        ocx.register_bound(cause, param_env, norm_return_ty, term_did);
-        let errors = ocx.select_all_or_error();
-        if !errors.is_empty() {
-            infcx.err_ctxt().report_fulfillment_errors(&errors);
-            error = true;
+        // The return type of a local `main` is required to implement `Termination` while
+        // type checking its body, unless it is opaque.
+        if !main_def_id.is_local() || return_ty.has_opaque_types() {
+            let infcx = tcx.infer_ctxt().build();
+            let cause = traits::ObligationCause::new(
+                return_ty_span,
+                main_diagnostics_def_id,
+                ObligationCauseCode::MainFunctionType,
+            );
+            let ocx = traits::ObligationCtxt::new(&infcx);
+            let norm_return_ty = ocx.normalize(&cause, param_env, return_ty);
+            ocx.register_bound(cause, param_env, norm_return_ty, term_did);
+            let errors = ocx.select_all_or_error();
+            if !errors.is_empty() {
+                infcx.err_ctxt().report_fulfillment_errors(&errors);
+                error = true;
+            }
         }
         // now we can take the return type of the given main function
         expected_return_type = main_fnsig.output();
@@ -17,12 +17,29 @@ hir_typeck_yield_expr_outside_of_generator =
 hir_typeck_struct_expr_non_exhaustive =
     cannot create non-exhaustive {$what} using struct expression
 
+hir_typeck_coercion_nesting_too_deep =
+    blocks, `if` and `match` expressions are nested too deeply to check their types
+    .outermost_label = this expression contains more than {$limit} levels of nested blocks, `if` and `match` expressions
+    .label = the limit is exceeded here
+    .help = consider splitting this expression up, for example by moving parts of it into `let` bindings with explicit type annotations
+
+hir_typeck_too_many_coercion_sites =
+    too many expressions to unify their types
+    .label = the types of more than {$limit} expressions are unified into one here
+    .largest_label = the largest of the previous expressions is here
+    .help = consider splitting the enclosing expression up, for example by moving parts of it into `let` bindings with explicit type annotations
+
 hir_typeck_method_call_on_unknown_type =
     the type of this value must be known to call a method on a raw pointer on it
 
 hir_typeck_address_of_temporary_taken = cannot take address of a temporary
     .label = temporary value
 
+hir_typeck_missing_lang_item =
+    {$construct} requires the `{$name}` lang item, which is not defined
+    .label = requires the `{$name}` lang item
+    .help = add `#[lang = "{$name}"]` to the {$target} that should be used here, or link to `core`, which defines it
+
 hir_typeck_add_return_type_add = try adding a return type
 
 hir_typeck_add_return_type_missing_here = a return type might be missing here
@@ -73,6 +90,79 @@ hir_typeck_const_select_must_be_fn = this argument must be a function item
 hir_typeck_union_pat_multiple_fields = union patterns should have exactly one field
 hir_typeck_union_pat_dotdot = `..` cannot be used in union patterns
 
+hir_typeck_arg_count_mismatch = {$this ->
+        [true] this {""}
+        *[false] {""}
+    }{$callee_kind ->
+        [struct] struct
+        [variant] enum variant
+        [method] method
+        *[function] function
+    } takes {$c_variadic ->
+        [true] at least {""}
+        *[false] {""}
+    }{$expected_count} {$expected_count ->
+        [one] argument
+        *[other] arguments
+    } but {$provided_count} {$provided_count ->
+        [one] argument was
+        *[other] arguments were
+    } supplied
+
+hir_typeck_args_incorrect = arguments to this {$callee_kind ->
+        [struct] struct
+        [variant] enum variant
+        [method] method
+        *[function] function
+    } are incorrect
+
+hir_typeck_arg_unexpected = unexpected argument{$unexpected_ty ->
+        [NONE] {""}
+        *[other] {" "}of type `{$unexpected_ty}`
+    }
+
+hir_typeck_arg_unexpected_caller_location = the caller location is passed implicitly
+
+hir_typeck_arg_missing = {$missing_count ->
+        [1] an argument
+        [2] two arguments
+        *[3] three arguments
+    }{$missing_first ->
+        [NONE] {""}
+        *[other] {" "}of type `{$missing_first}`{$missing_count ->
+            [1] {""}
+            [2] {" "}and `{$missing_second}`
+            *[3] , `{$missing_second}`, and `{$missing_third}`
+        }
+    } {$missing_count ->
+        [1] is
+        *[other] are
+    } missing
+
+hir_typeck_arg_missing_many = multiple arguments are missing
+
+hir_typeck_arg_expected = expected `{$expected_ty}`{$found_ty ->
+        [NONE] {""}
+        *[other] , found `{$found_ty}`
+    }
+
+hir_typeck_suggest_provide_args = provide the {$plural ->
+        [true] arguments
+        *[false] argument
+    }
+hir_typeck_suggest_remove_args = remove the extra {$plural ->
+        [true] arguments
+        *[false] argument
+    }
+hir_typeck_suggest_swap_args = swap these arguments
+hir_typeck_suggest_reorder_args = reorder these arguments
+hir_typeck_suggest_args_did_you_mean = did you mean
+
+hir_typeck_track_caller_location_note = the caller location of a `#[track_caller]` {$callee_kind ->
+        [method] method
+        *[function] function
+    } is supplied by the compiler, not passed as an argument
+
 hir_typeck_arg_mismatch_indeterminate = argument type mismatch was detected, but rustc had trouble determining where
     .note = we would appreciate a bug report: https://github.com/rust-lang/rust/issues/new
 
@@ -90,3 +180,25 @@ hir_typeck_candidate_trait_note = `{$trait_name}` defines an item `{$item_name}`
     [implement] , perhaps you need to implement it
     *[other] , perhaps you need to restrict type parameter `{$action_or_ty}` with it
 }
+
+hir_typeck_fn_ptr_cast_header_mismatch =
+    cannot cast `{$expr_ty}` as `{$cast_ty}` because {$mismatch ->
+        [abi] their ABIs differ
+        [unsafety] the function is `unsafe` but the function pointer is not
+        [variadic] only one of them is C-variadic
+        *[multiple] their ABI, unsafety or C-variadicness differ
+    }
+    .label = invalid cast
+
+hir_typeck_fn_ptr_cast_abi_note =
+    a function with the {$expr_abi} ABI cannot be cast to a function pointer with the {$cast_abi} ABI
+
+hir_typeck_fn_ptr_cast_unsafety_note =
+    an `unsafe` function cannot be cast to a safe function pointer
+
+hir_typeck_fn_ptr_cast_variadic_note = {$expr_variadic ->
+        [true] a C-variadic function cannot be cast to a non-variadic function pointer
+        *[false] a non-variadic function cannot be cast to a C-variadic function pointer
+    }
+
+hir_typeck_fn_ptr_cast_to_closest_type = consider casting to `{$closest_ty}` instead
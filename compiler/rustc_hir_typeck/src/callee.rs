@@ -457,16 +457,27 @@ fn confirm_builtin_call(
             fn_sig.output(),
             fn_sig.inputs(),
         );
-        self.check_argument_types(
-            call_expr.span,
-            call_expr,
-            fn_sig.inputs(),
-            expected_arg_tys,
-            arg_exprs,
-            fn_sig.c_variadic,
-            TupleArgumentsFlag::DontTupleArguments,
-            def_id,
-        );
+        // `start..=end` is lowered to a call to `RangeInclusive::new`, but its endpoints
+        // are checked like those of `start..end`.
+        if let hir::ExprKind::Path(hir::QPath::LangItem(hir::LangItem::RangeInclusiveNew, ..)) =
+            callee_expr.kind
+            && let [start, end] = arg_exprs
+            && let [idx_ty, _] = fn_sig.inputs()[..]
+        {
+            let expected_idx_ty = expected_arg_tys.map_or(idx_ty, |tys| tys[0]);
+            self.check_range_endpoints(start, end, idx_ty, expected_idx_ty);
+        } else {
+            self.check_argument_types(
+                call_expr.span,
+                call_expr,
+                fn_sig.inputs(),
+                expected_arg_tys,
+                arg_exprs,
+                fn_sig.c_variadic,
+                TupleArgumentsFlag::DontTupleArguments,
+                def_id,
+            );
+        }
 
         if fn_sig.abi == abi::Abi::RustCall {
             let sp = arg_exprs.last().map_or(call_expr.span, |expr| expr.span);
@@ -607,6 +618,16 @@ fn report_invalid_callee(
             unit_variant = Some((removal_span, descr, rustc_hir_pretty::qpath_to_string(qpath)));
         }
 
+        // `Type::CONST()`, where the path resolved to an associated constant rather than to an
+        // associated function.
+        let mut assoc_const = None;
+        if let hir::ExprKind::Path(qpath) = &callee_expr.kind
+            && let Res::Def(def::DefKind::AssocConst, def_id)
+                = self.typeck_results.borrow().qpath_res(qpath, callee_expr.hir_id)
+        {
+            assoc_const = Some((def_id, rustc_hir_pretty::qpath_to_string(qpath)));
+        }
+
         let callee_ty = self.resolve_vars_if_possible(callee_ty);
         let mut err = type_error_struct!(
             self.tcx.sess,
@@ -614,9 +635,10 @@ fn report_invalid_callee(
             callee_ty,
             E0618,
             "expected function, found {}",
-            match &unit_variant {
-                Some((_, kind, path)) => format!("{kind} `{path}`"),
-                None => format!("`{callee_ty}`"),
+            match (&unit_variant, &assoc_const) {
+                (Some((_, kind, path)), _) => format!("{kind} `{path}`"),
+                (None, Some((_, path))) => format!("associated constant `{path}`"),
+                (None, None) => format!("`{callee_ty}`"),
             }
         );
 
@@ -638,6 +660,17 @@ fn report_invalid_callee(
             );
         }
 
+        if let Some((_, path)) = &assoc_const {
+            self.suggest_using_assoc_const_value(
+                &mut err,
+                call_expr,
+                callee_expr,
+                callee_ty,
+                arg_exprs,
+                path,
+            );
+        }
+
         let mut inner_callee_path = None;
         let def = match callee_expr.kind {
             hir::ExprKind::Path(ref qpath) => {
@@ -666,7 +699,16 @@ fn report_invalid_callee(
             _ => Res::Err,
         };
 
-        if !self.maybe_suggest_bad_array_definition(&mut err, call_expr, callee_expr) {
+        if let Some((def_id, _)) = &assoc_const {
+            let parent = self.tcx.parent(*def_id);
+            let descr = match self.tcx.def_kind(parent) {
+                def::DefKind::Trait => {
+                    format!("an associated constant of trait `{}`", self.tcx.def_path_str(parent))
+                }
+                _ => "an associated constant".to_string(),
+            };
+            err.span_label(callee_expr.span, format!("this is {descr}, not a method"));
+        } else if !self.maybe_suggest_bad_array_definition(&mut err, call_expr, callee_expr) {
             if let Some((maybe_def, output_ty, _)) = self.extract_callable_info(callee_ty)
                 && !self.type_is_sized_modulo_regions(self.param_env, output_ty)
             {
@@ -692,6 +734,9 @@ fn report_invalid_callee(
             let callee_ty = callee_ty.to_string();
             let label = match (unit_variant, inner_callee_path) {
                 (Some((_, kind, path)), _) => Some(format!("{kind} `{path}` defined here")),
+                _ if let Some((_, path)) = &assoc_const => {
+                    Some(format!("associated constant `{path}` defined here"))
+                }
                 (_, Some(hir::QPath::Resolved(_, path))) => self
                     .tcx
                     .sess
@@ -722,6 +767,62 @@ fn report_invalid_callee(
         err.emit()
     }
 
+    /// When an associated constant is called like a function, suggest using its value instead:
+    /// removing the parentheses, or indexing into the constant or accessing one of its fields
+    /// when it is called with a single argument that fits.
+    fn suggest_using_assoc_const_value(
+        &self,
+        err: &mut Diagnostic,
+        call_expr: &'tcx hir::Expr<'tcx>,
+        callee_expr: &'tcx hir::Expr<'tcx>,
+        callee_ty: Ty<'tcx>,
+        arg_exprs: &'tcx [hir::Expr<'tcx>],
+        path: &str,
+    ) {
+        if call_expr.span.from_expansion() {
+            return;
+        }
+        let args_span = callee_expr.span.shrink_to_hi().to(call_expr.span.shrink_to_hi());
+        match arg_exprs {
+            [] => {
+                err.span_suggestion_verbose(
+                    args_span,
+                    format!(
+                        "`{path}` is an associated constant of type `{callee_ty}`, remove the \
+                         parentheses to use its value",
+                    ),
+                    "",
+                    Applicability::MachineApplicable,
+                );
+            }
+            [arg] => match callee_ty.peel_refs().kind() {
+                ty::Array(..) | ty::Slice(_) => {
+                    let open_span = callee_expr.span.shrink_to_hi().to(arg.span.shrink_to_lo());
+                    let close_span = arg.span.shrink_to_hi().to(call_expr.span.shrink_to_hi());
+                    err.multipart_suggestion_verbose(
+                        format!("consider indexing into the associated constant `{path}`"),
+                        vec![(open_span, "[".to_string()), (close_span, "]".to_string())],
+                        Applicability::MaybeIncorrect,
+                    );
+                }
+                ty::Tuple(tys)
+                    if let hir::ExprKind::Lit(lit) = arg.kind
+                        && let rustc_ast::LitKind::Int(idx, _) = lit.node
+                        && (idx as usize) < tys.len() =>
+                {
+                    err.span_suggestion_verbose(
+                        args_span,
+                        format!("consider accessing a field of the associated constant `{path}`"),
+                        format!(".{idx}"),
+                        Applicability::MaybeIncorrect,
+                    );
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+
     fn confirm_deferred_closure_call(
         &self,
         call_expr: &'tcx hir::Expr<'tcx>,
@@ -788,7 +889,11 @@ pub struct DeferredCallResolution<'tcx> {
 }
 
 impl<'a, 'tcx> DeferredCallResolution<'tcx> {
-    pub fn resolve(self, fcx: &FnCtxt<'a, 'tcx>) {
+    /// Resolves the call now that the kind of the closure is known.
+    ///
+    /// Returns the span of the call if no call trait could be found for it, so that
+    /// the caller can report all such calls to the same closure together.
+    pub fn resolve(self, fcx: &FnCtxt<'a, 'tcx>) -> Result<(), Span> {
         debug!("DeferredCallResolution::resolve() {:?}", self);
 
         // we should not be invoked until the closure kind has been
@@ -823,20 +928,42 @@ pub fn resolve(self, fcx: &FnCtxt<'a, 'tcx>) {
                 fcx.apply_adjustments(self.callee_expr, adjustments);
 
                 fcx.write_method_call(self.call_expr.hir_id, method_callee);
+                Ok(())
             }
             None => {
                 // This can happen if `#![no_core]` is used and the `fn/fn_mut/fn_once`
                 // lang items are not defined (issue #86238).
-                let mut err = fcx.inh.tcx.sess.struct_span_err(
-                    self.call_expr.span,
-                    "failed to find an overloaded call trait for closure call",
-                );
-                err.help(
-                    "make sure the `fn`/`fn_mut`/`fn_once` lang items are defined \
-                     and have correctly defined `call`/`call_mut`/`call_once` methods",
-                );
-                err.emit();
+                Err(self.call_expr.span)
             }
         }
     }
 }
+
+impl<'a, 'tcx> FnCtxt<'a, 'tcx> {
+    /// Reports the calls to the closure `closure_def_id` that could not be resolved once its
+    /// kind was inferred, all in a single error.
+    pub(crate) fn report_unresolved_closure_calls(
+        &self,
+        closure_def_id: LocalDefId,
+        call_spans: Vec<Span>,
+    ) -> ErrorGuaranteed {
+        let mut err = self.tcx.sess.struct_span_err(
+            call_spans,
+            "failed to find an overloaded call trait for closure call",
+        );
+        err.span_label(
+            self.tcx.def_span(closure_def_id),
+            "the kind of this closure is only known after its captures are analyzed",
+        );
+        err.note(
+            "calls to a closure in the body that defines it are resolved to `Fn`, `FnMut` or \
+             `FnOnce` only after the closure's kind has been inferred",
+        );
+        err.help(
+            "make sure the `fn`/`fn_mut`/`fn_once` lang items are defined \
+             and have correctly defined `call`/`call_mut`/`call_once` methods",
+        );
+        err.help("alternatively, use a `fn` item, which can be called without the call traits");
+        err.emit()
+    }
+}
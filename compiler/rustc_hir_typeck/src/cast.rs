@@ -30,6 +30,7 @@
 
 use super::FnCtxt;
 
+use crate::errors;
 use crate::type_error_struct;
 use hir::ExprKind;
 use rustc_errors::{
@@ -377,6 +378,10 @@ fn report_cast_error(&self, fcx: &FnCtxt<'a, 'tcx>, e: CastError) {
                 err.emit();
             }
             CastError::NonScalar => {
+                if let Some(mismatch) = self.fn_ptr_header_mismatch(fcx) {
+                    fcx.tcx.sess.emit_err(mismatch);
+                    return;
+                }
                 let mut err = type_error_struct!(
                     fcx.tcx.sess,
                     self.span,
@@ -686,6 +691,77 @@ fn report_cast_to_unsized_type(&self, fcx: &FnCtxt<'a, 'tcx>) -> ErrorGuaranteed
         err.emit()
     }
 
+    /// When casting a function item or pointer to a function pointer type only fails because
+    /// of the ABI, the unsafety or the C-variadicness of the function, returns an error that
+    /// says which.
+    fn fn_ptr_header_mismatch(
+        &self,
+        fcx: &FnCtxt<'a, 'tcx>,
+    ) -> Option<errors::FnPtrCastHeaderMismatch> {
+        let (ty::FnDef(..) | ty::FnPtr(..), ty::FnPtr(cast_sig)) =
+            (self.expr_ty.kind(), self.cast_ty.kind())
+        else {
+            return None;
+        };
+        if (self.expr_ty, self.cast_ty).references_error() {
+            return None;
+        }
+        let expr_sig = self.expr_ty.fn_sig(fcx.tcx);
+        let from = expr_sig.skip_binder();
+        let to = cast_sig.skip_binder();
+        // Only explain the header if the cast would work with the target's header.
+        let adjusted_sig = expr_sig.map_bound(|sig| ty::FnSig {
+            abi: to.abi,
+            unsafety: to.unsafety,
+            c_variadic: to.c_variadic,
+            ..sig
+        });
+        if !fcx.can_coerce(fcx.tcx.mk_fn_ptr(adjusted_sig), self.cast_ty) {
+            return None;
+        }
+
+        let abi = from.abi != to.abi;
+        let unsafety =
+            from.unsafety == hir::Unsafety::Unsafe && to.unsafety == hir::Unsafety::Normal;
+        let variadic = from.c_variadic != to.c_variadic;
+        let mismatch = match (abi, unsafety, variadic) {
+            (true, false, false) => "abi",
+            (false, true, false) => "unsafety",
+            (false, false, true) => "variadic",
+            _ => "multiple",
+        };
+
+        // Function pointer casts are coercions, so there's never a legal intermediate type
+        // between two headers. The closest type that the expression can be cast to is the
+        // target with the parts of the header that can't change taken from the expression.
+        let closest_sig = cast_sig.map_bound(|sig| ty::FnSig {
+            abi: from.abi,
+            unsafety: if unsafety { hir::Unsafety::Unsafe } else { sig.unsafety },
+            c_variadic: from.c_variadic,
+            ..sig
+        });
+        let closest_ty = fcx.tcx.mk_fn_ptr(closest_sig);
+        let suggestion =
+            fcx.can_coerce(self.expr_ty, closest_ty).then(|| errors::FnPtrCastToClosestType {
+                span: self.cast_span,
+                closest_ty: fcx.ty_to_string(closest_ty),
+            });
+
+        Some(errors::FnPtrCastHeaderMismatch {
+            span: self.span,
+            expr_ty: fcx.ty_to_string(self.expr_ty),
+            cast_ty: fcx.ty_to_string(self.cast_ty),
+            mismatch,
+            abi: abi.then_some(()),
+            expr_abi: from.abi.to_string(),
+            cast_abi: to.abi.to_string(),
+            unsafety: unsafety.then_some(()),
+            variadic: variadic.then_some(()),
+            expr_variadic: from.c_variadic,
+            suggestion,
+        })
+    }
+
     fn trivial_cast_lint(&self, fcx: &FnCtxt<'a, 'tcx>) {
         let t_cast = self.cast_ty;
         let t_expr = self.expr_ty;
@@ -741,6 +817,7 @@ pub fn check(mut self, fcx: &FnCtxt<'a, 'tcx>) {
                     match self.do_check(fcx) {
                         Ok(k) => {
                             debug!(" -> {:?}", k);
+                            self.pointer_integer_cast_lint(fcx, k);
                         }
                         Err(e) => self.report_cast_error(fcx, e),
                     };
@@ -860,7 +937,10 @@ pub fn do_check(&self, fcx: &FnCtxt<'a, 'tcx>) -> Result<CastKind, CastError> {
             }
             (Int(Char) | Int(Bool), Int(_)) => Ok(CastKind::PrimIntCast),
 
-            (Int(_) | Float, Int(_) | Float) => Ok(CastKind::NumericCast),
+            (Int(_) | Float, Int(_) | Float) => {
+                self.lossy_numeric_cast_lint(fcx);
+                Ok(CastKind::NumericCast)
+            }
 
             (_, DynStar) => {
                 if fcx.tcx.features().dyn_star {
@@ -1069,6 +1149,96 @@ fn lossy_provenance_ptr2int_lint(&self, fcx: &FnCtxt<'a, 'tcx>, t_c: ty::cast::I
         );
     }
 
+    fn lossy_numeric_cast_lint(&self, fcx: &FnCtxt<'a, 'tcx>) {
+        let tcx = fcx.tcx;
+        let ptr_bits = tcx.data_layout.pointer_size.bits();
+        let int_bits = |ty: Ty<'tcx>| match *ty.kind() {
+            ty::Int(t) => Some(t.bit_width().unwrap_or(ptr_bits)),
+            ty::Uint(t) => Some(t.bit_width().unwrap_or(ptr_bits)),
+            _ => None,
+        };
+        let float_to_int = self.expr_ty.is_floating_point();
+        match (int_bits(self.expr_ty), int_bits(self.cast_ty)) {
+            (Some(from_bits), Some(to_bits)) if to_bits < from_bits => {}
+            (None, Some(_)) if float_to_int => {}
+            _ => return,
+        }
+        tcx.struct_span_lint_hir(
+            lint::builtin::LOSSY_NUMERIC_CASTS,
+            self.expr.hir_id,
+            self.span,
+            DelayDm(|| {
+                format!("casting `{}` to `{}` may lose information", self.expr_ty, self.cast_ty)
+            }),
+            |lint| {
+                if float_to_int {
+                    // There is no fallible conversion from floats to integers to suggest.
+                    lint.note(
+                        "the fractional part is dropped, and values out of range saturate at \
+                         the bounds of the integer type",
+                    );
+                    return lint;
+                }
+                let msg =
+                    format!("use `TryFrom` to handle values that don't fit in `{}`", self.cast_ty);
+                // `TryFrom` is only in the prelude since Rust 2021, and its methods
+                // can't be called in constants.
+                if !self.span.rust_2021()
+                    || self.constness == hir::Constness::Const
+                    || self.span.from_expansion()
+                {
+                    lint.help(msg);
+                    return lint;
+                }
+                // Propagate the error if the enclosing function can, instead of panicking.
+                let ret_ty = (tcx.hir().enclosing_body_owner(self.expr.hir_id) == fcx.body_id)
+                    .then(|| fcx.body_fn_sig())
+                    .flatten()
+                    .map(|sig| sig.output());
+                let unwrap = match ret_ty.map(|ty| ty.kind()) {
+                    Some(ty::Adt(def, _)) if tcx.is_diagnostic_item(sym::Result, def.did()) => "?",
+                    Some(ty::Adt(def, _)) if tcx.is_diagnostic_item(sym::Option, def.did()) => {
+                        ".ok()?"
+                    }
+                    _ => ".unwrap()",
+                };
+                lint.multipart_suggestion(
+                    msg,
+                    vec![
+                        (self.expr_span.shrink_to_lo(), format!("{}::try_from(", self.cast_ty)),
+                        (self.expr_span.shrink_to_hi().to(self.cast_span), format!("){unwrap}")),
+                    ],
+                    Applicability::MaybeIncorrect,
+                );
+                lint
+            },
+        );
+    }
+
+    /// Reports casts between pointers and integers once the cast has been checked, so that the
+    /// types inferred for e.g. `addr as *const _` are the ones that get reported.
+    fn pointer_integer_cast_lint(&self, fcx: &FnCtxt<'a, 'tcx>, kind: CastKind) {
+        let (expr_kind, cast_kind) = match kind {
+            CastKind::PtrAddrCast | CastKind::FnPtrAddrCast => ("pointer", "integer"),
+            CastKind::AddrPtrCast => ("integer", "pointer"),
+            _ => return,
+        };
+        fcx.tcx.struct_span_lint_hir(
+            lint::builtin::POINTER_INTEGER_CASTS,
+            self.expr.hir_id,
+            self.span,
+            DelayDm(|| {
+                format!("casting {expr_kind} `{}` to {cast_kind} `{}`", self.expr_ty, self.cast_ty)
+            }),
+            |lint| {
+                lint.note(format!(
+                    "pointers are {} bits wide on this target",
+                    fcx.tcx.data_layout.pointer_size.bits()
+                ))
+            },
+        );
+    }
+
     fn fuzzy_provenance_int2ptr_lint(&self, fcx: &FnCtxt<'a, 'tcx>) {
         fcx.tcx.struct_span_lint_hir(
             lint::builtin::FUZZY_PROVENANCE_CASTS,
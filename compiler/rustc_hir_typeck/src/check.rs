@@ -12,7 +12,8 @@
 use rustc_hir_analysis::check::fn_maybe_err;
 use rustc_infer::infer::type_variable::{TypeVariableOrigin, TypeVariableOriginKind};
 use rustc_infer::infer::RegionVariableOrigin;
-use rustc_middle::ty::{self, Binder, Ty, TyCtxt};
+use rustc_middle::ty::{self, Binder, Ty, TyCtxt, TypeVisitableExt};
+use rustc_session::config::EntryFnType;
 use rustc_span::def_id::LocalDefId;
 use rustc_target::spec::abi::Abi;
 use rustc_trait_selection::traits;
@@ -122,13 +123,13 @@ pub(super) fn check_fn<'a, 'tcx>(
         fcx.check_return_expr(&body.value, false);
     }
 
-    // We insert the deferred_generator_interiors entry after visiting the body.
+    // We insert the deferred generator interior entry after visiting the body.
     // This ensures that all nested generators appear before the entry of this generator.
     // resolve_generator_interiors relies on this property.
     let gen_ty = if let (Some(_), Some(gen_kind)) = (can_be_generator, body.generator_kind) {
         let interior = fcx
             .next_ty_var(TypeVariableOrigin { kind: TypeVariableOriginKind::MiscVariable, span });
-        fcx.deferred_generator_interiors.borrow_mut().push((
+        fcx.deferred.borrow_mut().generator_interiors.push((
             fn_def_id,
             body.id(),
             interior,
@@ -179,9 +180,42 @@ pub(super) fn check_fn<'a, 'tcx>(
         check_lang_start_fn(tcx, fn_sig, decl, fn_def_id);
     }
 
+    if let Some((entry_def_id, EntryFnType::Main { .. })) = tcx.entry_fn(())
+        && entry_def_id == fn_def_id.to_def_id()
+    {
+        check_main_fn_return_ty(fcx, decl, fn_def_id, declared_ret_ty);
+    }
+
     gen_ty
 }
 
+/// Requires the return type of the `main` function to implement `Termination`, so that
+/// errors about it are reported while checking `main`, pointing at its return type.
+/// Generic, `async` and opaque `main` functions are left to `check_main_fn_ty` in
+/// `rustc_hir_analysis`, which also rejects the former two on their own.
+fn check_main_fn_return_ty<'tcx>(
+    fcx: &FnCtxt<'_, 'tcx>,
+    decl: &'tcx hir::FnDecl<'tcx>,
+    fn_def_id: LocalDefId,
+    declared_ret_ty: Ty<'tcx>,
+) {
+    let tcx = fcx.tcx;
+    let Some(term_did) = tcx.lang_items().termination() else { return };
+    if tcx.generics_of(fn_def_id).count() != 0
+        || !tcx.predicates_of(fn_def_id).predicates.is_empty()
+        || tcx.asyncness(fn_def_id).is_async()
+        || declared_ret_ty.has_opaque_types()
+    {
+        return;
+    }
+    fcx.require_type_meets(
+        declared_ret_ty,
+        decl.output.span(),
+        traits::ObligationCauseCode::MainFunctionType,
+        term_did,
+    );
+}
+
 fn check_panic_info_fn(
     tcx: TyCtxt<'_>,
     fn_id: LocalDefId,
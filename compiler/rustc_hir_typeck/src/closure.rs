@@ -59,6 +59,10 @@ pub fn check_expr_closure(
             Some(ty) => self.deduce_closure_signature(ty),
             None => (None, None),
         };
+        if let Some(ExpectedSig { cause_span: Some(cause_span), .. }) = expected_sig {
+            let hir_id = self.tcx.hir().local_def_id_to_hir_id(closure.def_id);
+            self.typeck_results.borrow_mut().closure_sig_origins_mut().insert(hir_id, cause_span);
+        }
         let body = self.tcx.hir().body(closure.body);
         self.check_closure(closure, expr_span, expected_kind, body, expected_sig)
     }
@@ -35,6 +35,7 @@
 //! // and are then unable to coerce `&7i32` to `&mut i32`.
 //! ```
 
+use crate::errors;
 use crate::FnCtxt;
 use rustc_errors::{
     struct_span_err, Applicability, Diagnostic, DiagnosticBuilder, ErrorGuaranteed, MultiSpan,
@@ -1419,6 +1420,15 @@ pub fn coerce_forced_unit<'a>(
         )
     }
 
+    /// The expressions that have been coerced so far.
+    fn previous_expressions(&self) -> impl Iterator<Item = &hir::Expr<'_>> {
+        let (dynamic, up_front) = match self.expressions {
+            Expressions::Dynamic(ref exprs) => (&exprs[..], &[][..]),
+            Expressions::UpFront(coercion_sites) => (&[][..], &coercion_sites[..self.pushed]),
+        };
+        dynamic.iter().copied().chain(up_front.iter().map(|site| site.as_coercion_site()))
+    }
+
     /// The inner coercion "engine". If `expression` is `None`, this
     /// is a forced-unit case, and hence `expression_ty` must be
     /// `Nil`.
@@ -1448,6 +1458,22 @@ pub(crate) fn coerce_inner<'a>(
             return;
         }
 
+        // Each new expression is unified with all of the previous ones, which gets
+        // slow when there are very many of them, so give up at some point.
+        if let Some(limit) = fcx.tcx.sess.opts.unstable_opts.coercion_sites_limit
+            && self.pushed >= limit
+            && let Some(expression) = expression
+        {
+            let largest = self.previous_expressions().max_by_key(|e| e.span.hi() - e.span.lo());
+            let guar = fcx.tcx.sess.emit_err(errors::TooManyCoercionSites {
+                span: expression.span,
+                largest: largest.map(|e| e.span),
+                limit,
+            });
+            self.final_ty = Some(fcx.tcx.ty_error(guar));
+            return;
+        }
+
         // Handle the actual type unification etc.
         let result = if let Some(expression) = expression {
             if self.pushed == 0 {
@@ -1,4 +1,4 @@
-use crate::FnCtxt;
+use crate::{FnCtxt, LocalTyOrigin};
 use rustc_ast::util::parser::PREC_POSTFIX;
 use rustc_errors::MultiSpan;
 use rustc_errors::{Applicability, Diagnostic, DiagnosticBuilder, ErrorGuaranteed};
@@ -85,10 +85,44 @@ pub fn emit_coerce_suggestions(
         self.annotate_expected_due_to_let_ty(err, expr, error);
         self.emit_type_mismatch_suggestions(err, expr, expr_ty, expected, expected_ty_expr, error);
         self.note_type_is_not_clone(err, expected, expr_ty, expr);
+        self.note_implicit_ref_binding(err, expr, expr_ty, expected);
         self.note_internal_mutation_in_method(err, expr, Some(expected), expr_ty);
         self.suggest_method_call_on_range_literal(err, expr, expr_ty, expected);
         self.suggest_return_binding_for_missing_tail_expr(err, expr, expr_ty, expected);
         self.note_wrong_return_ty_due_to_generic_arg(err, expr, expr_ty);
+        self.note_loop_value_is_unit(err, expr, expr_ty, expected);
+        self.note_method_chain_types(err, expr);
+        self.note_local_ty_from_initializer(err, expr);
+    }
+
+    /// When `expr` is a binding whose type was inferred from a method call initializer, points
+    /// at that initializer, as the type isn't written anywhere in the source.
+    ///
+    /// Other initializers are deliberately left alone: a literal, a struct expression or a call
+    /// of a function names its type or the item whose signature gives it right where the binding
+    /// is declared. The type of a method call also depends on autoderef and on which trait the
+    /// method came from, so it's the one case where finding the initializer helps.
+    fn note_local_ty_from_initializer(&self, err: &mut Diagnostic, expr: &hir::Expr<'_>) {
+        let hir::ExprKind::Path(hir::QPath::Resolved(None, path)) = expr.kind else { return };
+        let ([segment], hir::def::Res::Local(hir_id)) = (path.segments, path.res) else { return };
+        let Some(local_ty) = self.locals.borrow().get(&hir_id).copied() else { return };
+        if local_ty.origin != LocalTyOrigin::Initializer
+            || !matches!(
+                self.tcx.hir().find_parent(hir_id),
+                Some(hir::Node::Local(hir::Local {
+                    init: Some(hir::Expr { kind: hir::ExprKind::MethodCall(..), .. }),
+                    ..
+                }))
+            )
+            // Don't repeat what `note_source_of_type_mismatch_constraint` already said.
+            || err.span.span_labels().iter().any(|label| label.span == local_ty.origin_span)
+        {
+            return;
+        }
+        err.span_label(
+            local_ty.origin_span,
+            format!("the type of `{}` was inferred from this initializer", segment.ident),
+        );
     }
 
     /// Requires that the two types unify, and prints an error message if
@@ -162,8 +196,36 @@ pub fn demand_coerce(
         expected_ty_expr: Option<&'tcx hir::Expr<'tcx>>,
         allow_two_phase: AllowTwoPhase,
     ) -> Ty<'tcx> {
-        let (ty, err) =
-            self.demand_coerce_diag(expr, checked_ty, expected, expected_ty_expr, allow_two_phase);
+        self.demand_coerce_with_note(
+            expr,
+            checked_ty,
+            expected,
+            expected_ty_expr,
+            allow_two_phase,
+            |_| {},
+        )
+    }
+
+    /// Like `demand_coerce`, but lets the caller attach context of its own (e.g. where the
+    /// expected type comes from) to the mismatch error through `decorate`, alongside the
+    /// usual coercion suggestions.
+    pub fn demand_coerce_with_note(
+        &self,
+        expr: &hir::Expr<'tcx>,
+        checked_ty: Ty<'tcx>,
+        expected: Ty<'tcx>,
+        expected_ty_expr: Option<&'tcx hir::Expr<'tcx>>,
+        allow_two_phase: AllowTwoPhase,
+        decorate: impl FnOnce(&mut Diagnostic),
+    ) -> Ty<'tcx> {
+        let (ty, err) = self.demand_coerce_diag_with_note(
+            expr,
+            checked_ty,
+            expected,
+            expected_ty_expr,
+            allow_two_phase,
+            decorate,
+        );
         if let Some(mut err) = err {
             err.emit();
         }
@@ -174,7 +236,6 @@ pub fn demand_coerce(
     ///
     /// N.B., this code relies on `self.diverges` to be accurate. In particular, assignments to `!`
     /// will be permitted if the diverges flag is currently "always".
-    #[instrument(level = "debug", skip(self, expr, expected_ty_expr, allow_two_phase))]
     pub fn demand_coerce_diag(
         &self,
         expr: &hir::Expr<'tcx>,
@@ -182,6 +243,26 @@ pub fn demand_coerce_diag(
         expected: Ty<'tcx>,
         expected_ty_expr: Option<&'tcx hir::Expr<'tcx>>,
         allow_two_phase: AllowTwoPhase,
+    ) -> (Ty<'tcx>, Option<DiagnosticBuilder<'tcx, ErrorGuaranteed>>) {
+        self.demand_coerce_diag_with_note(
+            expr,
+            checked_ty,
+            expected,
+            expected_ty_expr,
+            allow_two_phase,
+            |_| {},
+        )
+    }
+
+    #[instrument(level = "debug", skip(self, expr, expected_ty_expr, allow_two_phase, decorate))]
+    fn demand_coerce_diag_with_note(
+        &self,
+        expr: &hir::Expr<'tcx>,
+        checked_ty: Ty<'tcx>,
+        expected: Ty<'tcx>,
+        expected_ty_expr: Option<&'tcx hir::Expr<'tcx>>,
+        allow_two_phase: AllowTwoPhase,
+        decorate: impl FnOnce(&mut Diagnostic),
     ) -> (Ty<'tcx>, Option<DiagnosticBuilder<'tcx, ErrorGuaranteed>>) {
         let expected = self.resolve_vars_with_obligations(expected);
 
@@ -214,6 +295,7 @@ pub fn demand_coerce_diag(
                 expected_ty_expr,
                 Some(e),
             );
+            decorate(&mut err);
         }
 
         (expected, Some(err))
@@ -466,12 +548,6 @@ fn annotate_expected_due_to_let_ty(
     ) {
         let parent = self.tcx.hir().parent_id(expr.hir_id);
         match (self.tcx.hir().find(parent), error) {
-            (Some(hir::Node::Local(hir::Local { ty: Some(ty), init: Some(init), .. })), _)
-                if init.hir_id == expr.hir_id =>
-            {
-                // Point at `let` assignment type.
-                err.span_label(ty.span, "expected due to this");
-            }
             (
                 Some(hir::Node::Expr(hir::Expr {
                     kind: hir::ExprKind::Assign(lhs, rhs, _), ..
@@ -1604,7 +1680,10 @@ pub fn suggest_cast(
 
         let mut sugg = vec![];
 
-        if let Some(hir::Node::ExprField(field)) = self.tcx.hir().find_parent(expr.hir_id) {
+        if let Some(hir::Node::ExprField(field)) = self.tcx.hir().find_parent(expr.hir_id)
+            // The fields of `start..end` are its endpoints, which can be cast as usual.
+            && !is_range_literal(self.tcx.hir().expect_expr(self.tcx.hir().parent_id(field.hir_id)))
+        {
             // `expr` is a literal field for a struct, only suggest if appropriate
             if field.is_shorthand {
                 // This is a field literal
@@ -1,3 +1,8 @@
+use rustc_session::lint::builtin::{
+    UNREACHABLE_ARMS, UNREACHABLE_BLOCK_TAILS, UNREACHABLE_CALL_ARGS, UNREACHABLE_EXPRESSIONS,
+    UNREACHABLE_STATEMENTS,
+};
+use rustc_session::lint::Lint;
 use rustc_span::source_map::DUMMY_SP;
 use rustc_span::{self, Span};
 use std::{cmp, ops};
@@ -76,3 +81,50 @@ pub(super) fn is_always(self) -> bool {
         self >= Diverges::Always { span: DUMMY_SP, custom_note: None }
     }
 }
+
+/// The kind of node that `warn_if_unreachable` is linting, which
+/// determines both the wording and which `unreachable_code` lint is used.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum UnreachableKind {
+    Statement,
+    Arm,
+    /// An argument following a diverging argument, e.g. `f(todo!(), x)`.
+    CallArg,
+    /// The trailing expression of a block with a diverging statement.
+    BlockTail,
+    /// The callee of a call with diverging arguments.
+    Call,
+    /// The `then` block of an `if` or `while` with a diverging condition.
+    IfBlock,
+    /// The initializer of a `let` expression.
+    LetInit,
+    Expression,
+}
+
+impl UnreachableKind {
+    pub(super) fn descr(self) -> &'static str {
+        match self {
+            UnreachableKind::Statement => "statement",
+            UnreachableKind::Arm => "arm",
+            UnreachableKind::Call => "call",
+            UnreachableKind::IfBlock => "block in `if` or `while` expression",
+            UnreachableKind::LetInit => "block in `let` expression",
+            UnreachableKind::CallArg | UnreachableKind::BlockTail | UnreachableKind::Expression => {
+                "expression"
+            }
+        }
+    }
+
+    pub(super) fn lint(self) -> &'static Lint {
+        match self {
+            UnreachableKind::Statement => UNREACHABLE_STATEMENTS,
+            UnreachableKind::Arm => UNREACHABLE_ARMS,
+            UnreachableKind::CallArg => UNREACHABLE_CALL_ARGS,
+            UnreachableKind::BlockTail => UNREACHABLE_BLOCK_TAILS,
+            UnreachableKind::Call
+            | UnreachableKind::IfBlock
+            | UnreachableKind::LetInit
+            | UnreachableKind::Expression => UNREACHABLE_EXPRESSIONS,
+        }
+    }
+}
@@ -7,7 +7,7 @@
 use rustc_middle::ty::Ty;
 use rustc_span::{
     edition::{Edition, LATEST_STABLE_EDITION},
-    symbol::Ident,
+    symbol::{Ident, Symbol},
     Span,
 };
 
@@ -48,6 +48,30 @@ pub struct StructExprNonExhaustive {
     pub what: &'static str,
 }
 
+#[derive(Diagnostic)]
+#[diag(hir_typeck_coercion_nesting_too_deep)]
+#[help]
+pub struct CoercionNestingTooDeep {
+    #[primary_span]
+    #[label(hir_typeck_outermost_label)]
+    pub span: Span,
+    #[label]
+    pub innermost: Span,
+    pub limit: usize,
+}
+
+#[derive(Diagnostic)]
+#[diag(hir_typeck_too_many_coercion_sites)]
+#[help]
+pub struct TooManyCoercionSites {
+    #[primary_span]
+    #[label]
+    pub span: Span,
+    #[label(hir_typeck_largest_label)]
+    pub largest: Option<Span>,
+    pub limit: usize,
+}
+
 #[derive(Diagnostic)]
 #[diag(hir_typeck_method_call_on_unknown_type, code = "E0699")]
 pub struct MethodCallOnUnknownType {
@@ -70,6 +94,18 @@ pub struct AddressOfTemporaryTaken {
     pub span: Span,
 }
 
+#[derive(Diagnostic)]
+#[diag(hir_typeck_missing_lang_item)]
+#[help]
+pub struct MissingLangItem {
+    #[primary_span]
+    #[label]
+    pub span: Span,
+    pub name: Symbol,
+    pub construct: &'static str,
+    pub target: &'static str,
+}
+
 #[derive(Subdiagnostic)]
 pub enum AddReturnTypeSuggestion {
     #[suggestion(
@@ -319,3 +355,80 @@ pub struct CandidateTraitNote {
     pub item_name: Ident,
     pub action_or_ty: String,
 }
+
+#[derive(Diagnostic)]
+#[diag(hir_typeck_fn_ptr_cast_header_mismatch, code = "E0605")]
+pub struct FnPtrCastHeaderMismatch {
+    #[primary_span]
+    #[label]
+    pub span: Span,
+    pub expr_ty: String,
+    pub cast_ty: String,
+    /// Which part of the header differs: `abi`, `unsafety`, `variadic` or `multiple`.
+    pub mismatch: &'static str,
+    #[note(hir_typeck_fn_ptr_cast_abi_note)]
+    pub abi: Option<()>,
+    pub expr_abi: String,
+    pub cast_abi: String,
+    #[note(hir_typeck_fn_ptr_cast_unsafety_note)]
+    pub unsafety: Option<()>,
+    #[note(hir_typeck_fn_ptr_cast_variadic_note)]
+    pub variadic: Option<()>,
+    pub expr_variadic: bool,
+    #[subdiagnostic]
+    pub suggestion: Option<FnPtrCastToClosestType>,
+}
+
+#[derive(Subdiagnostic)]
+#[suggestion(
+    hir_typeck_fn_ptr_cast_to_closest_type,
+    code = "{closest_ty}",
+    style = "verbose",
+    applicability = "maybe-incorrect"
+)]
+pub struct FnPtrCastToClosestType {
+    #[primary_span]
+    pub span: Span,
+    pub closest_ty: String,
+}
+
+/// A label on an argument of a call whose arguments don't match the callee's signature.
+///
+/// Types that aren't worth printing, e.g. because they contain errors, are passed as `NONE`.
+/// As a call can have several of these labels, they must be added with
+/// `Diagnostic::eager_subdiagnostic`.
+#[derive(Subdiagnostic)]
+pub enum ArgumentLabel {
+    #[label(hir_typeck_arg_unexpected)]
+    Unexpected {
+        #[primary_span]
+        span: Span,
+        unexpected_ty: String,
+    },
+    #[label(hir_typeck_arg_unexpected_caller_location)]
+    UnexpectedCallerLocation {
+        #[primary_span]
+        span: Span,
+    },
+    #[label(hir_typeck_arg_missing)]
+    Missing {
+        #[primary_span]
+        span: Span,
+        missing_count: usize,
+        missing_first: String,
+        missing_second: String,
+        missing_third: String,
+    },
+    #[label(hir_typeck_arg_missing_many)]
+    MissingMany {
+        #[primary_span]
+        span: Span,
+    },
+    #[label(hir_typeck_arg_expected)]
+    Expected {
+        #[primary_span]
+        span: Span,
+        expected_ty: String,
+        found_ty: String,
+    },
+}
@@ -8,8 +8,8 @@
 use crate::errors::TypeMismatchFruTypo;
 use crate::errors::{AddressOfTemporaryTaken, ReturnStmtOutsideOfFnBody, StructExprNonExhaustive};
 use crate::errors::{
-    FieldMultiplySpecifiedInInitializer, FunctionalRecordUpdateOnNonStruct, HelpUseLatestEdition,
-    YieldExprOutsideOfGenerator,
+    CoercionNestingTooDeep, FieldMultiplySpecifiedInInitializer, FunctionalRecordUpdateOnNonStruct,
+    HelpUseLatestEdition, YieldExprOutsideOfGenerator,
 };
 use crate::fatally_break_rust;
 use crate::method::SelfSource;
@@ -17,9 +17,10 @@
 use crate::Expectation::{self, ExpectCastableToType, ExpectHasType, NoExpectation};
 use crate::{
     report_unexpected_variant_res, BreakableCtxt, Diverges, FnCtxt, Needs,
-    TupleArgumentsFlag::DontTupleArguments,
+    TupleArgumentsFlag::DontTupleArguments, UnreachableKind,
 };
 use rustc_ast as ast;
+use rustc_ast::util::parser::{ExprPrecedence, PREC_PREFIX};
 use rustc_data_structures::fx::FxHashMap;
 use rustc_data_structures::stack::ensure_sufficient_stack;
 use rustc_errors::{
@@ -33,6 +34,7 @@
 use rustc_hir::lang_items::LangItem;
 use rustc_hir::{ExprKind, HirId, QPath};
 use rustc_hir_analysis::astconv::AstConv as _;
+use rustc_hir_analysis::autoderef::AutoderefKind;
 use rustc_hir_analysis::check::ty_kind_suggestion;
 use rustc_infer::infer;
 use rustc_infer::infer::type_variable::{TypeVariableOrigin, TypeVariableOriginKind};
@@ -54,6 +56,7 @@
 use rustc_target::abi::FieldIdx;
 use rustc_target::spec::abi::Abi::RustIntrinsic;
 use rustc_trait_selection::infer::InferCtxtExt;
+use rustc_trait_selection::traits::error_reporting::suggestions::TypeErrCtxtExt as _;
 use rustc_trait_selection::traits::error_reporting::TypeErrCtxtExt;
 use rustc_trait_selection::traits::ObligationCtxt;
 use rustc_trait_selection::traits::{self, ObligationCauseCode};
@@ -148,10 +151,11 @@ fn check_expr_with_expectation_and_needs(
         expected: Expectation<'tcx>,
         needs: Needs,
     ) -> Ty<'tcx> {
-        let ty = self.check_expr_with_expectation(expr, expected);
+        let ty = self.check_expr_with_expectation_args_and_needs(expr, expected, &[], needs);
 
-        // If the expression is used in a place whether mutable place is required
-        // e.g. LHS of assignment, perform the conversion.
+        // Overloaded derefs and indices along the place projection were already
+        // selected for `needs`, but autoderefs of field and index bases were not,
+        // so convert those now.
         if let Needs::MutPlace = needs {
             self.convert_place_derefs_to_mutable(expr);
         }
@@ -197,6 +201,19 @@ pub(super) fn check_expr_with_expectation_and_args(
         expr: &'tcx hir::Expr<'tcx>,
         expected: Expectation<'tcx>,
         args: &'tcx [hir::Expr<'tcx>],
+    ) -> Ty<'tcx> {
+        self.check_expr_with_expectation_args_and_needs(expr, expected, args, Needs::None)
+    }
+
+    /// Same as `check_expr_with_expectation_and_args`, but if `needs` is `Needs::MutPlace`,
+    /// selects `DerefMut` and `IndexMut` for the overloaded derefs and indices that the
+    /// place `expr` is projected through, falling back to `Deref` and `Index`.
+    fn check_expr_with_expectation_args_and_needs(
+        &self,
+        expr: &'tcx hir::Expr<'tcx>,
+        expected: Expectation<'tcx>,
+        args: &'tcx [hir::Expr<'tcx>],
+        needs: Needs,
     ) -> Ty<'tcx> {
         if self.tcx().sess.verbose() {
             // make this code only run with -Zverbose because it is probably slow
@@ -227,18 +244,24 @@ pub(super) fn check_expr_with_expectation_and_args(
 
         // Warn for expressions after diverging siblings.
         if !is_try_block_generated_unit_expr {
-            self.warn_if_unreachable(expr.hir_id, expr.span, "expression");
+            self.warn_if_unreachable(expr.hir_id, expr.span, UnreachableKind::Expression);
         }
 
         // Hide the outer diverging and has_errors flags.
         let old_diverges = self.diverges.replace(Diverges::Maybe);
 
-        let ty = ensure_sufficient_stack(|| match &expr.kind {
-            hir::ExprKind::Path(
-                qpath @ (hir::QPath::Resolved(..) | hir::QPath::TypeRelative(..)),
-            ) => self.check_expr_path(qpath, expr, args),
-            _ => self.check_expr_kind(expr, expected),
-        });
+        let old_coercion_nesting = self.coercion_nesting.get();
+        let ty = if let Err(guar) = self.enter_coercion_nesting(expr) {
+            self.tcx.ty_error(guar)
+        } else {
+            ensure_sufficient_stack(|| match &expr.kind {
+                hir::ExprKind::Path(
+                    qpath @ (hir::QPath::Resolved(..) | hir::QPath::TypeRelative(..)),
+                ) => self.check_expr_path(qpath, expr, args),
+                _ => self.check_expr_kind(expr, expected, needs),
+            })
+        };
+        self.coercion_nesting.set(old_coercion_nesting);
         let ty = self.resolve_vars_if_possible(ty);
 
         // Warn for non-block expressions with diverging children.
@@ -252,11 +275,13 @@ pub(super) fn check_expr_with_expectation_and_args(
             // diverging expression (e.g. it arose from desugaring of `try { return }`),
             // we skip issuing a warning because it is autogenerated code.
             ExprKind::Call(..) if expr.span.is_desugaring(DesugaringKind::TryBlock) => {}
-            ExprKind::Call(callee, _) => self.warn_if_unreachable(expr.hir_id, callee.span, "call"),
+            ExprKind::Call(callee, _) => {
+                self.warn_if_unreachable(expr.hir_id, callee.span, UnreachableKind::Call)
+            }
             ExprKind::MethodCall(segment, ..) => {
-                self.warn_if_unreachable(expr.hir_id, segment.ident.span, "call")
+                self.warn_if_unreachable(expr.hir_id, segment.ident.span, UnreachableKind::Call)
             }
-            _ => self.warn_if_unreachable(expr.hir_id, expr.span, "expression"),
+            _ => self.warn_if_unreachable(expr.hir_id, expr.span, UnreachableKind::Expression),
         }
 
         // Any expression that produces a value of type `!` must have diverged
@@ -278,11 +303,41 @@ pub(super) fn check_expr_with_expectation_and_args(
         ty
     }
 
+    /// Blocks, `if` and `match` expressions unify the types of the expressions they may
+    /// evaluate to, so checking deeply nested ones gets slow and may exhaust the stack.
+    /// If `-Zcoercion-nesting-limit` is set, records that `expr` is entered and errors once
+    /// the limit is exceeded.
+    fn enter_coercion_nesting(&self, expr: &hir::Expr<'_>) -> Result<(), ErrorGuaranteed> {
+        let Some(limit) = self.tcx.sess.opts.unstable_opts.coercion_nesting_limit else {
+            return Ok(());
+        };
+        if !matches!(expr.kind, ExprKind::Block(..) | ExprKind::If(..) | ExprKind::Match(..)) {
+            return Ok(());
+        }
+        let (depth, outermost) = self.coercion_nesting.get();
+        if depth > limit {
+            let guar = self.coercion_nesting_error.get().unwrap_or_else(|| {
+                self.tcx.sess.emit_err(CoercionNestingTooDeep {
+                    span: outermost.unwrap_or(expr.span),
+                    innermost: expr.span,
+                    limit,
+                })
+            });
+            self.coercion_nesting_error.set(Some(guar));
+            return Err(guar);
+        }
+        // The outermost site is the body's own block, which is not worth pointing at.
+        let outermost = if depth == 1 { Some(expr.span) } else { outermost };
+        self.coercion_nesting.set((depth + 1, outermost));
+        Ok(())
+    }
+
     #[instrument(skip(self, expr), level = "debug")]
     fn check_expr_kind(
         &self,
         expr: &'tcx hir::Expr<'tcx>,
         expected: Expectation<'tcx>,
+        needs: Needs,
     ) -> Ty<'tcx> {
         trace!("expr={:#?}", expr);
 
@@ -296,7 +351,9 @@ fn check_expr_kind(
             ExprKind::AssignOp(op, lhs, rhs) => {
                 self.check_binop_assign(expr, op, lhs, rhs, expected)
             }
-            ExprKind::Unary(unop, oprnd) => self.check_expr_unary(unop, oprnd, expected, expr),
+            ExprKind::Unary(unop, oprnd) => {
+                self.check_expr_unary(unop, oprnd, expected, needs, expr)
+            }
             ExprKind::AddrOf(kind, mutbl, oprnd) => {
                 self.check_expr_addr_of(kind, mutbl, oprnd, expected, expr)
             }
@@ -358,8 +415,8 @@ fn check_expr_kind(
             ExprKind::Struct(qpath, fields, ref base_expr) => {
                 self.check_expr_struct(expr, expected, qpath, fields, base_expr)
             }
-            ExprKind::Field(base, field) => self.check_field(expr, &base, field, expected),
-            ExprKind::Index(base, idx) => self.check_expr_index(base, idx, expr),
+            ExprKind::Field(base, field) => self.check_field(expr, &base, field, expected, needs),
+            ExprKind::Index(base, idx) => self.check_expr_index(base, idx, needs, expr),
             ExprKind::Yield(value, ref src) => self.check_expr_yield(value, expr, src),
             hir::ExprKind::Err(guar) => tcx.ty_error(guar),
         }
@@ -370,20 +427,22 @@ fn check_expr_unary(
         unop: hir::UnOp,
         oprnd: &'tcx hir::Expr<'tcx>,
         expected: Expectation<'tcx>,
+        needs: Needs,
         expr: &'tcx hir::Expr<'tcx>,
     ) -> Ty<'tcx> {
         let tcx = self.tcx;
-        let expected_inner = match unop {
-            hir::UnOp::Not | hir::UnOp::Neg => expected,
-            hir::UnOp::Deref => NoExpectation,
+        let (expected_inner, needs) = match unop {
+            hir::UnOp::Not | hir::UnOp::Neg => (expected, Needs::None),
+            hir::UnOp::Deref => (NoExpectation, needs),
         };
-        let mut oprnd_t = self.check_expr_with_expectation(&oprnd, expected_inner);
+        let mut oprnd_t =
+            self.check_expr_with_expectation_args_and_needs(&oprnd, expected_inner, &[], needs);
 
         if !oprnd_t.references_error() {
             oprnd_t = self.structurally_resolved_type(expr.span, oprnd_t);
             match unop {
                 hir::UnOp::Deref => {
-                    if let Some(ty) = self.lookup_derefing(expr, oprnd, oprnd_t) {
+                    if let Some(ty) = self.lookup_derefing(expr, oprnd, oprnd_t, needs) {
                         oprnd_t = ty;
                     } else {
                         let mut err = type_error_struct!(
@@ -429,6 +488,7 @@ fn check_expr_addr_of(
         expected: Expectation<'tcx>,
         expr: &'tcx hir::Expr<'tcx>,
     ) -> Ty<'tcx> {
+        let mut coerce_oprnd_to = None;
         let hint = expected.only_has_type(self).map_or(NoExpectation, |ty| {
             match ty.kind() {
                 ty::Ref(_, ty, _) | ty::RawPtr(ty::TypeAndMut { ty, .. }) => {
@@ -438,14 +498,30 @@ fn check_expr_addr_of(
                         // the last field of a struct can be unsized.
                         ExpectHasType(*ty)
                     } else {
+                        // An rvalue that is itself expected to be a pointer, like the
+                        // `&s` in `&&s` where `&&str` is expected, has to be coerced
+                        // before it is borrowed: coercing the outer borrow only ever
+                        // looks through one level of indirection.
+                        if self.tcx.features().coerce_borrowed_pointers
+                            && let ty::Ref(..) | ty::RawPtr(..) = ty.kind()
+                        {
+                            coerce_oprnd_to = Some(*ty);
+                        }
                         Expectation::rvalue_hint(self, *ty)
                     }
                 }
                 _ => NoExpectation,
             }
         });
-        let ty =
+        let mut ty =
             self.check_expr_with_expectation_and_needs(&oprnd, hint, Needs::maybe_mut_place(mutbl));
+        if let Some(target) = coerce_oprnd_to
+            && !ty.references_error()
+            && !self.can_eq(self.param_env, ty, target)
+            && let Ok(coerced) = self.try_coerce(oprnd, ty, target, AllowTwoPhase::No, None)
+        {
+            ty = coerced;
+        }
 
         let tm = ty::TypeAndMut { ty, mutbl };
         match kind {
@@ -657,7 +733,40 @@ fn check_expr_break(
 
             if let Some(ref mut coerce) = ctxt.coerce {
                 if let Some(ref e) = expr_opt {
-                    coerce.coerce(self, &cause, e, e_ty);
+                    let prior_breaks = &ctxt.break_value_spans;
+                    let prior_ty = self.resolve_vars_if_possible(coerce.merged_ty());
+                    let mut mismatched = false;
+                    coerce.coerce_inner(
+                        self,
+                        &cause,
+                        Some(e),
+                        e_ty,
+                        Some(&mut |err: &mut Diagnostic| {
+                            mismatched = true;
+                            // Like for `match` arms, point at the `break`s that determined
+                            // the type this one was expected to have.
+                            if prior_breaks.len() <= 4 {
+                                for &sp in prior_breaks {
+                                    err.span_label(
+                                        sp,
+                                        format!("this is found to be of type `{prior_ty}`"),
+                                    );
+                                }
+                            } else if let Some(&sp) = prior_breaks.last() {
+                                err.span_label(
+                                    sp,
+                                    format!(
+                                        "this and all prior `break`s are found to be of type \
+                                         `{prior_ty}`"
+                                    ),
+                                );
+                            }
+                        }),
+                        false,
+                    );
+                    if !mismatched {
+                        ctxt.break_value_spans.push(e.span);
+                    }
                 } else {
                     assert!(e_ty.is_unit());
                     let ty = coerce.expected_ty();
@@ -978,11 +1087,7 @@ fn check_then_else(
     ) -> Ty<'tcx> {
         let cond_ty = self.check_expr_has_type_or_error(cond_expr, self.tcx.types.bool, |_| {});
 
-        self.warn_if_unreachable(
-            cond_expr.hir_id,
-            then_expr.span,
-            "block in `if` or `while` expression",
-        );
+        self.warn_if_unreachable(cond_expr.hir_id, then_expr.span, UnreachableKind::IfBlock);
 
         let cond_diverges = self.diverges.get();
         self.diverges.set(Diverges::Maybe);
@@ -1162,7 +1267,7 @@ fn check_expr_assign(
     pub(super) fn check_expr_let(&self, let_expr: &'tcx hir::Let<'tcx>) -> Ty<'tcx> {
         // for let statements, this is done in check_stmt
         let init = let_expr.init;
-        self.warn_if_unreachable(init.hir_id, init.span, "block in `let` expression");
+        self.warn_if_unreachable(init.hir_id, init.span, UnreachableKind::LetInit);
         // otherwise check exactly as a let statement
         self.check_decl(let_expr.into());
         // but return a bool, for this is a boolean expression
@@ -1189,6 +1294,7 @@ fn check_expr_loop(
         let ctxt = BreakableCtxt {
             coerce,
             may_break: false, // Will get updated if/when we find a `break`.
+            break_value_spans: Vec::new(),
         };
 
         let (ctxt, ()) = self.with_breakable_ctxt(expr.hir_id, ctxt, || {
@@ -1237,7 +1343,7 @@ fn check_method_call(
             }
             Err(error) => {
                 if segment.ident.name != kw::Empty {
-                    if let Some(mut err) = self.report_method_error(
+                    if let Some(err) = self.report_method_error(
                         span,
                         rcvr_t,
                         segment.ident,
@@ -1247,7 +1353,7 @@ fn check_method_call(
                         expected,
                         false,
                     ) {
-                        err.emit();
+                        self.emit_once_per_macro_expansion(err);
                     }
                 }
                 Err(())
@@ -1276,7 +1382,6 @@ fn check_expr_cast(
             self.tcx.ty_error(guar)
         } else {
             // Defer other checks until we're done type checking.
-            let mut deferred_cast_checks = self.deferred_cast_checks.borrow_mut();
             match cast::CastCheck::new(
                 self,
                 e,
@@ -1291,7 +1396,7 @@ fn check_expr_cast(
                         "check_expr_cast: deferring cast from {:?} to {:?}: {:?}",
                         t_cast, t_expr, cast_check,
                     );
-                    deferred_cast_checks.push(cast_check);
+                    self.deferred.borrow_mut().cast_checks.push(cast_check);
                     t_cast
                 }
                 Err(guar) => self.tcx.ty_error(guar),
@@ -1425,7 +1530,7 @@ fn check_expr_repeat(
             return tcx.ty_error(guar);
         }
 
-        self.check_repeat_element_needs_copy_bound(element, count, element_ty);
+        self.check_repeat_element_needs_copy_bound(expr, element, count, element_ty);
 
         self.register_wf_obligation(
             tcx.mk_array_with_const_len(t, count).into(),
@@ -1438,6 +1543,7 @@ fn check_expr_repeat(
 
     fn check_repeat_element_needs_copy_bound(
         &self,
+        expr: &hir::Expr<'_>,
         element: &hir::Expr<'_>,
         count: ty::Const<'tcx>,
         element_ty: Ty<'tcx>,
@@ -1469,8 +1575,34 @@ fn check_repeat_element_needs_copy_bound(
         // don't copy that one element, we move it. Only check for Copy if the length is larger.
         if count.try_eval_target_usize(tcx, self.param_env).map_or(true, |len| len > 1) {
             let lang_item = self.tcx.require_lang_item(LangItem::Copy, None);
-            let code = traits::ObligationCauseCode::RepeatElementCopy { is_const_fn };
-            self.require_type_meets(element_ty, element.span, code, lang_item);
+            let code = traits::ObligationCauseCode::RepeatElementCopy {
+                is_const_fn,
+                repeat_hir_id: expr.hir_id,
+            };
+            let element_ty = self.resolve_vars_if_possible(element_ty);
+            if element_ty.has_non_region_infer() {
+                self.require_type_meets(element_ty, element.span, code, lang_item);
+                return;
+            }
+            // The element type is already known, so report a missing `Copy` impl right
+            // away instead of once pending obligations are next selected, which may be
+            // well after errors that only happen because of it.
+            let cause = traits::ObligationCause::new(element.span, self.body_id, code);
+            let obligation = traits::Obligation::new(
+                tcx,
+                cause,
+                self.param_env,
+                ty::TraitRef::new(tcx, lang_item, [element_ty]),
+            );
+            let ocx = ObligationCtxt::new(self);
+            ocx.register_obligation(obligation.clone());
+            let errors = ocx.select_where_possible();
+            if errors.is_empty() {
+                self.register_predicate(obligation);
+            } else {
+                let guar = self.err_ctxt().report_fulfillment_errors(&errors);
+                self.set_tainted_by_errors(guar);
+            }
         }
     }
 
@@ -1541,7 +1673,6 @@ fn check_expr_struct(
             expr.span,
         );
 
-        self.require_type_is_sized(adt_ty, expr.span, traits::StructInitializerSized);
         adt_ty
     }
 
@@ -1583,10 +1714,26 @@ fn check_expr_struct_fields(
 
         let mut error_happened = false;
 
+        // `start..end` is lowered to a struct literal, but its endpoints are checked
+        // together once the type of both fields is known.
+        let is_range_literal =
+            ast_fields.len() == 2 && hir::is_range_literal(tcx.hir().expect_expr(expr_id));
+        let mut range_endpoints = vec![];
+
+        // A struct whose last field may be dynamically sized is exactly as sized as that field.
+        let tail_field = match adt_kind {
+            AdtKind::Struct if !adt.sized_constraint(tcx).skip_binder().is_empty() => {
+                variant.fields.iter().last()
+            }
+            _ => None,
+        };
+        let mut tail_field_sized = false;
+
         // Type-check each field.
         for (idx, field) in ast_fields.iter().enumerate() {
             let ident = tcx.adjust_ident(field.ident, variant.def_id);
-            let field_type = if let Some((i, v_field)) = remaining_fields.remove(&ident) {
+            let (field_type, v_field) = if let Some((i, v_field)) = remaining_fields.remove(&ident)
+            {
                 seen_fields.insert(ident, field.span);
                 self.write_field_index(field.hir_id, i);
 
@@ -1597,7 +1744,7 @@ fn check_expr_struct_fields(
                     tcx.check_stability(v_field.did, Some(expr_id), field.span, None);
                 }
 
-                self.field_ty(field.span, v_field, substs)
+                (self.field_ty(field.span, v_field, substs), Some(v_field))
             } else {
                 error_happened = true;
                 let guar = if let Some(prev_span) = seen_fields.get(&ident) {
@@ -1617,9 +1764,14 @@ fn check_expr_struct_fields(
                     )
                 };
 
-                tcx.ty_error(guar)
+                (tcx.ty_error(guar), None)
             };
 
+            if is_range_literal {
+                range_endpoints.push((field.expr, field_type));
+                continue;
+            }
+
             // Make sure to give a type to the field even if there's
             // an error, so we can continue type-checking.
             let ty = self.check_expr_with_hint(&field.expr, field_type);
@@ -1627,6 +1779,9 @@ fn check_expr_struct_fields(
                 self.demand_coerce_diag(&field.expr, ty, field_type, None, AllowTwoPhase::No);
 
             if let Some(mut diag) = diag {
+                if let Some(v_field) = v_field {
+                    self.note_generic_field_type(&mut diag, *adt, v_field, field_type, substs);
+                }
                 if idx == ast_fields.len() - 1 {
                     if remaining_fields.is_empty() {
                         self.suggest_fru_from_range(field, variant, substs, &mut diag);
@@ -1638,6 +1793,27 @@ fn check_expr_struct_fields(
                     diag.emit();
                 }
             }
+
+            // Require the value of a possibly unsized last field to be sized rather than the
+            // whole struct, to be able to point at the field and explain why it must be sized.
+            if let Some(v_field) = v_field
+                && tail_field.map_or(false, |tail| tail.did == v_field.did)
+            {
+                self.require_type_is_sized(
+                    field_type,
+                    field.expr.span,
+                    traits::StructInitializerFieldSized { field: v_field.did },
+                );
+                tail_field_sized = true;
+            }
+        }
+
+        if let [(start, idx_ty), (end, _)] = range_endpoints[..] {
+            self.check_range_endpoints(start, end, idx_ty, idx_ty);
+        }
+
+        if !tail_field_sized {
+            self.require_type_is_sized(adt_ty, expr_span, traits::StructInitializerSized);
         }
 
         // Make sure the programmer specified correct number of fields.
@@ -1746,11 +1922,14 @@ fn check_expr_struct_fields(
                     return;
                 }
             } else {
-                self.check_expr_has_type_or_error(base_expr, adt_ty, |_| {
+                self.check_expr_has_type_or_error(base_expr, adt_ty, |err| {
                     let base_ty = self.typeck_results.borrow().expr_ty(*base_expr);
-                    let same_adt = matches!((adt_ty.kind(), base_ty.kind()),
-                        (ty::Adt(adt, _), ty::Adt(base_adt, _)) if adt == base_adt);
-                    if self.tcx.sess.is_nightly_build() && same_adt {
+                    let base_ty = self.resolve_vars_if_possible(base_ty);
+                    let ty::Adt(base_adt, base_substs) = base_ty.kind() else { return };
+                    if base_adt != adt {
+                        return;
+                    }
+                    if self.tcx.sess.is_nightly_build() {
                         feature_err(
                             &self.tcx.sess.parse_sess,
                             sym::type_changing_struct_update,
@@ -1759,13 +1938,32 @@ fn check_expr_struct_fields(
                         )
                         .emit();
                     }
+                    // Point at the fields that are taken from the base expression
+                    // but have a different type there.
+                    for field in &variant.fields {
+                        let ident = self.tcx.adjust_ident(field.ident(self.tcx), variant.def_id);
+                        if !remaining_fields.contains_key(&ident) {
+                            continue;
+                        }
+                        let field_ty = self.field_ty(base_expr.span, field, substs);
+                        let field_ty = self.resolve_vars_if_possible(field_ty);
+                        let base_field_ty = self.field_ty(base_expr.span, field, base_substs);
+                        let base_field_ty = self.resolve_vars_if_possible(base_field_ty);
+                        if field_ty != base_field_ty {
+                            err.span_note(
+                                self.tcx.def_span(field.did),
+                                format!(
+                                    "field `{ident}` is expected to be of type `{field_ty}`, \
+                                     but the base expression provides `{base_field_ty}`"
+                                ),
+                            );
+                        }
+                    }
                 });
                 match adt_ty.kind() {
-                    ty::Adt(adt, substs) if adt.is_struct() => variant
-                        .fields
-                        .iter()
-                        .map(|f| self.normalize(expr_span, f.ty(self.tcx, substs)))
-                        .collect(),
+                    ty::Adt(adt, substs) if adt.is_struct() => {
+                        variant.fields.iter().map(|f| self.field_ty(expr_span, f, substs)).collect()
+                    }
                     _ => {
                         self.tcx
                             .sess
@@ -1793,11 +1991,46 @@ fn check_expr_struct_fields(
                     variant,
                     ast_fields,
                     substs,
+                    expr_span,
                 );
             }
         }
     }
 
+    /// Checks the endpoints of `start..end` or `start..=end` against the type `idx_ty`
+    /// they share, where `expected_idx_ty` is what the context expects that type to be.
+    ///
+    /// Both endpoints are checked with the same expectation before either one is
+    /// coerced, so that neither one decides what the other is expected to be. Each
+    /// endpoint is then coerced to `idx_ty` on its own. If the start is what decided
+    /// `idx_ty`, a mismatch of the end also points at the start.
+    pub(super) fn check_range_endpoints(
+        &self,
+        start: &'tcx hir::Expr<'tcx>,
+        end: &'tcx hir::Expr<'tcx>,
+        idx_ty: Ty<'tcx>,
+        expected_idx_ty: Ty<'tcx>,
+    ) {
+        let start_ty = self.check_expr_with_hint(start, expected_idx_ty);
+        let end_ty = self.check_expr_with_hint(end, expected_idx_ty);
+
+        let idx_ty_unknown = self.resolve_vars_with_obligations(idx_ty).is_ty_var();
+        let (_, start_err) =
+            self.demand_coerce_diag(start, start_ty, idx_ty, None, AllowTwoPhase::No);
+        let decided_by_start = idx_ty_unknown && start_err.is_none();
+        if let Some(err) = start_err {
+            err.emit();
+        }
+        if let (_, Some(mut err)) =
+            self.demand_coerce_diag(end, end_ty, idx_ty, None, AllowTwoPhase::No)
+        {
+            if decided_by_start {
+                err.span_label(start.span, "expected because of this");
+            }
+            err.emit();
+        }
+    }
+
     fn check_struct_fields_on_error(
         &self,
         fields: &'tcx [hir::ExprField<'tcx>],
@@ -1830,6 +2063,7 @@ fn report_missing_fields(
         variant: &'tcx ty::VariantDef,
         ast_fields: &'tcx [hir::ExprField<'tcx>],
         substs: SubstsRef<'tcx>,
+        expr_span: Span,
     ) {
         let len = remaining_fields.len();
 
@@ -1867,22 +2101,96 @@ fn report_missing_fields(
         );
         err.span_label(span, format!("missing {remaining_fields_names}{truncated_fields_error}"));
 
-        if let Some(last) = ast_fields.last() {
-            self.suggest_fru_from_range(last, variant, substs, &mut err);
+        let suggested_range = ast_fields
+            .last()
+            .is_some_and(|last| self.suggest_fru_from_range(last, variant, substs, &mut err));
+        if !suggested_range {
+            self.suggest_fru_from_default(adt_ty, span, ast_fields, expr_span, &mut err);
         }
 
         err.emit();
     }
 
+    /// When the type of a field in a struct literal is built from the generic arguments of the
+    /// struct, point at the field declaration and say which arguments it was substituted with.
+    /// Fields declared with a bare type parameter are left alone, as the expected type of the
+    /// mismatch already says what it was substituted with.
+    fn note_generic_field_type(
+        &self,
+        err: &mut Diagnostic,
+        adt: ty::AdtDef<'tcx>,
+        field: &ty::FieldDef,
+        field_ty: Ty<'tcx>,
+        substs: SubstsRef<'tcx>,
+    ) {
+        let field_ty = self.resolve_vars_if_possible(field_ty);
+        let declared_ty = self.tcx.type_of(field.did).subst_identity();
+        if field_ty.references_error() || matches!(declared_ty.kind(), ty::Param(_)) {
+            return;
+        }
+        let generics = self.tcx.generics_of(adt.did());
+        let mut params = vec![];
+        for arg in declared_ty.walk() {
+            let index = match arg.unpack() {
+                ty::GenericArgKind::Type(ty) => match ty.kind() {
+                    ty::Param(param) => param.index,
+                    _ => continue,
+                },
+                ty::GenericArgKind::Const(ct) => match ct.kind() {
+                    ty::ConstKind::Param(param) => param.index,
+                    _ => continue,
+                },
+                ty::GenericArgKind::Lifetime(_) => continue,
+            };
+            let Some(subst) = substs.get(index as usize) else { continue };
+            let subst = self.resolve_vars_if_possible(*subst);
+            // Inference variables would only be printed as `_`, which doesn't explain anything.
+            if subst.has_non_region_infer() {
+                continue;
+            }
+            // Nothing to explain if the parameter is just passed through, e.g. in `Self { .. }`.
+            let passed_through = match subst.unpack() {
+                ty::GenericArgKind::Type(ty) => {
+                    matches!(ty.kind(), ty::Param(param) if param.index == index)
+                }
+                ty::GenericArgKind::Const(ct) => {
+                    matches!(ct.kind(), ty::ConstKind::Param(param) if param.index == index)
+                }
+                ty::GenericArgKind::Lifetime(_) => false,
+            };
+            if passed_through {
+                continue;
+            }
+            let param = generics.param_at(index as usize, self.tcx);
+            let note = format!("`{}` is `{}`", param.name, subst);
+            if !params.contains(&note) {
+                params.push(note);
+            }
+        }
+        let because = match &params[..] {
+            [] => return,
+            [param] => param.clone(),
+            [init @ .., last] => format!("{} and {last}", init.join(", ")),
+        };
+        err.span_note(
+            self.tcx.def_span(field.did),
+            format!(
+                "field `{}` is declared with type `{declared_ty}`, which is `{field_ty}` because \
+                 {because}",
+                field.name,
+            ),
+        );
+    }
+
     /// If the last field is a range literal, but it isn't supposed to be, then they probably
     /// meant to use functional update syntax.
     fn suggest_fru_from_range(
         &self,
         last_expr_field: &hir::ExprField<'tcx>,
-        variant: &ty::VariantDef,
+        variant: &'tcx ty::VariantDef,
         substs: SubstsRef<'tcx>,
         err: &mut Diagnostic,
-    ) {
+    ) -> bool {
         // I don't use 'is_range_literal' because only double-sided, half-open ranges count.
         if let ExprKind::Struct(
                 QPath::LangItem(LangItem::Range, ..),
@@ -1893,7 +2201,7 @@ fn suggest_fru_from_range(
                 variant.fields.iter().find(|field| field.ident(self.tcx) == last_expr_field.ident)
             && let range_def_id = self.tcx.lang_items().range_struct()
             && variant_field
-                .and_then(|field| field.ty(self.tcx, substs).ty_adt_def())
+                .and_then(|field| self.field_ty(last_expr_field.span, field, substs).ty_adt_def())
                 .map(|adt| adt.did())
                 != range_def_id
         {
@@ -1930,7 +2238,80 @@ fn suggest_fru_from_range(
                 fru_span,
                 expr,
             });
+            return true;
+        }
+        false
+    }
+
+    /// When a struct literal is missing fields and the struct implements `Default`, suggest
+    /// taking the remaining fields from `..Default::default()`.
+    fn suggest_fru_from_default(
+        &self,
+        adt_ty: Ty<'tcx>,
+        path_span: Span,
+        ast_fields: &'tcx [hir::ExprField<'tcx>],
+        expr_span: Span,
+        err: &mut Diagnostic,
+    ) {
+        let adt_ty = self.resolve_vars_if_possible(adt_ty);
+        let ty::Adt(adt, _) = adt_ty.kind() else { return };
+        // Functional record update syntax is only allowed for structs.
+        if !adt.is_struct()
+            || adt_ty.has_non_region_infer()
+            || !expr_span.can_be_used_for_suggestions()
+        {
+            return;
         }
+        let Some(default_trait) = self.tcx.get_diagnostic_item(sym::Default) else { return };
+        if !self
+            .infcx
+            .type_implements_trait(default_trait, [adt_ty], self.param_env)
+            .must_apply_modulo_regions()
+        {
+            return;
+        }
+        let sm = self.tcx.sess.source_map();
+        let (span, sugg) = match ast_fields.last() {
+            Some(last) => {
+                let rest = last.span.shrink_to_hi().to(expr_span.shrink_to_hi());
+                let Ok(snippet) = sm.span_to_snippet(rest) else { return };
+                let trimmed = snippet.trim_start();
+                // A trailing comma can't follow the base expression, so insert it after the comma.
+                if let Some(after_comma) = trimmed.strip_prefix(',')
+                    && after_comma.trim() == "}"
+                {
+                    let comma = snippet.len() - trimmed.len();
+                    let lo = rest.lo() + rustc_span::BytePos(comma as u32 + 1);
+                    (rest.with_lo(lo).shrink_to_lo(), " ..Default::default()")
+                } else if trimmed == "}" {
+                    (last.span.shrink_to_hi(), ", ..Default::default()")
+                } else {
+                    // Leave literals that needed parser recovery alone.
+                    return;
+                }
+            }
+            None => {
+                let braces = path_span.shrink_to_hi().to(expr_span.shrink_to_hi());
+                let Ok(snippet) = sm.span_to_snippet(braces) else { return };
+                let Some(open) = snippet.find('{') else { return };
+                let Some(between) = snippet[open + 1..].strip_suffix('}') else { return };
+                if !between.trim().is_empty() {
+                    return;
+                }
+                let inner = braces
+                    .with_lo(braces.lo() + rustc_span::BytePos(open as u32 + 1))
+                    .with_hi(braces.hi() - rustc_span::BytePos(1));
+                (inner, " ..Default::default() ")
+            }
+        };
+        err.span_suggestion_verbose(
+            span,
+            format!(
+                "consider taking the remaining fields from `{adt_ty}`'s `Default` implementation"
+            ),
+            sugg,
+            Applicability::MaybeIncorrect,
+        );
     }
 
     /// Report an error for a struct field expression when there are invisible fields.
@@ -2206,9 +2587,11 @@ fn check_field(
         base: &'tcx hir::Expr<'tcx>,
         field: Ident,
         expected: Expectation<'tcx>,
+        needs: Needs,
     ) -> Ty<'tcx> {
         debug!("check_field(expr: {:?}, base: {:?}, field: {:?})", expr, base, field);
-        let base_ty = self.check_expr(base);
+        let base_ty =
+            self.check_expr_with_expectation_args_and_needs(base, NoExpectation, &[], needs);
         let base_ty = self.structurally_resolved_type(base.span, base_ty);
         let mut private_candidate = None;
         let mut autoderef = self.autoderef(expr.span, base_ty);
@@ -2804,9 +3187,11 @@ fn check_expr_index(
         &self,
         base: &'tcx hir::Expr<'tcx>,
         idx: &'tcx hir::Expr<'tcx>,
+        needs: Needs,
         expr: &'tcx hir::Expr<'tcx>,
     ) -> Ty<'tcx> {
-        let base_t = self.check_expr(&base);
+        let base_t =
+            self.check_expr_with_expectation_args_and_needs(base, NoExpectation, &[], needs);
         let idx_t = self.check_expr(&idx);
 
         if base_t.references_error() {
@@ -2815,12 +3200,14 @@ fn check_expr_index(
             idx_t
         } else {
             let base_t = self.structurally_resolved_type(base.span, base_t);
-            match self.lookup_indexing(expr, base, base_t, idx, idx_t) {
+            match self.lookup_indexing(expr, base, base_t, idx, idx_t, needs) {
                 Some((index_ty, element_ty)) => {
                     // two-phase not needed because index_ty is never mutable
                     self.demand_coerce(idx, idx_t, index_ty, None, AllowTwoPhase::No);
                     self.select_obligations_where_possible(|errors| {
-                        self.point_at_index_if_possible(errors, idx.span)
+                        self.point_at_index_if_possible(errors, idx.span);
+                        self.report_index_key_borrow_errors(errors, expr, base_t, idx, idx_t);
+                        self.report_integer_index_errors(errors, idx, idx_t);
                     });
                     element_ty
                 }
@@ -2978,6 +3365,271 @@ fn point_at_index_if_possible(
         }
     }
 
+    /// Reports an unsatisfied `K: Borrow<Q>` bound of an `Index<&Q>` impl (as used by
+    /// `HashMap` and `BTreeMap`) in terms of the index expression, listing the types the
+    /// key can be borrowed as and suggesting how to index with one of them.
+    fn report_index_key_borrow_errors(
+        &self,
+        errors: &mut Vec<traits::FulfillmentError<'tcx>>,
+        expr: &'tcx hir::Expr<'tcx>,
+        base_t: Ty<'tcx>,
+        idx: &'tcx hir::Expr<'tcx>,
+        idx_t: Ty<'tcx>,
+    ) {
+        let tcx = self.tcx;
+        let Some(borrow_def_id) = tcx.get_diagnostic_item(sym::Borrow) else { return };
+        let idx_t = self.resolve_vars_if_possible(idx_t);
+        let ty::Ref(_, idx_pointee_ty, _) = *idx_t.kind() else { return };
+        let Some(pos) = errors.iter().position(|error| {
+            matches!(
+                error.obligation.predicate.kind().skip_binder(),
+                ty::PredicateKind::Clause(ty::Clause::Trait(pred))
+                    if pred.def_id() == borrow_def_id
+                        && tcx.erase_regions(
+                            self.resolve_vars_if_possible(pred.trait_ref.substs.type_at(1)),
+                        ) == tcx.erase_regions(idx_pointee_ty)
+            )
+        }) else {
+            return;
+        };
+        let error = errors.remove(pos);
+        let ty::PredicateKind::Clause(ty::Clause::Trait(pred)) =
+            error.obligation.predicate.kind().skip_binder()
+        else {
+            unreachable!()
+        };
+        let key_ty = self.resolve_vars_if_possible(pred.self_ty());
+
+        // Collect the types the key can be borrowed as, going through the `Borrow` impls
+        // that apply to it.
+        let cause = self.misc(idx.span);
+        let mut borrowable = vec![];
+        for impl_def_id in tcx.all_impls(borrow_def_id) {
+            let target = self.probe(|_| {
+                let substs = self.fresh_substs_for_item(idx.span, impl_def_id);
+                let trait_ref = tcx.impl_trait_ref(impl_def_id)?.subst(tcx, substs);
+                let _ = self
+                    .at(&cause, self.param_env)
+                    .eq(DefineOpaqueTypes::No, trait_ref.self_ty(), key_ty)
+                    .ok()?;
+                let target = self.resolve_vars_if_possible(trait_ref.substs.type_at(1));
+                (!target.has_non_region_infer()).then(|| tcx.erase_regions(target))
+            });
+            if let Some(target) = target && !borrowable.contains(&target) {
+                borrowable.push(target);
+            }
+        }
+
+        let mut err = struct_span_err!(
+            tcx.sess,
+            expr.span,
+            E0277,
+            "the type `{base_t}` cannot be indexed by `{idx_t}`",
+        );
+        err.span_label(
+            idx.span,
+            format!("keys of type `{key_ty}` cannot be borrowed as `{idx_pointee_ty}`"),
+        );
+        if !borrowable.is_empty() {
+            let borrowable_list =
+                borrowable.iter().map(|ty| format!("`{ty}`")).collect::<Vec<_>>().join(", ");
+            err.help(format!("`{key_ty}` can only be borrowed as {borrowable_list}"));
+        }
+
+        // If dereferencing the index reaches a type the key can be borrowed as, suggest
+        // doing so.
+        let mut autoderef = self.autoderef(idx.span, idx_pointee_ty).silence_errors();
+        let target = autoderef.by_ref().skip(1).find(|&(step_ty, _)| {
+            borrowable.iter().any(|&target| self.can_eq(self.param_env, step_ty, target))
+        });
+        let only_builtin_derefs =
+            autoderef.steps().iter().all(|&(_, kind)| matches!(kind, AutoderefKind::Builtin));
+        if let Some((target, steps)) = target {
+            if let hir::ExprKind::AddrOf(hir::BorrowKind::Ref, _, inner) = idx.kind
+                && inner.precedence().order() >= PREC_PREFIX
+            {
+                if steps == 1 && only_builtin_derefs {
+                    err.span_suggestion_verbose(
+                        idx.span.until(inner.span),
+                        "consider removing the borrow",
+                        "",
+                        Applicability::MachineApplicable,
+                    );
+                } else {
+                    err.span_suggestion_verbose(
+                        inner.span.shrink_to_lo(),
+                        format!("consider dereferencing the key to borrow it as `{target}`"),
+                        "*".repeat(steps),
+                        Applicability::MachineApplicable,
+                    );
+                }
+            } else if idx.precedence().order() >= PREC_PREFIX {
+                let sugg = if only_builtin_derefs {
+                    "*".repeat(steps)
+                } else {
+                    format!("&{}", "*".repeat(steps + 1))
+                };
+                err.span_suggestion_verbose(
+                    idx.span.shrink_to_lo(),
+                    format!("consider dereferencing the key to borrow it as `{target}`"),
+                    sugg,
+                    Applicability::MachineApplicable,
+                );
+            }
+        }
+
+        let guar = err.emit();
+        self.set_tainted_by_errors(guar);
+    }
+
+    /// Reports an unsatisfied `SliceIndex<[T]>` bound for an integer index that isn't a
+    /// `usize`, suggesting how to convert the index based on whether it may be out of the
+    /// range of `usize` and on whether the enclosing function can propagate a failed
+    /// conversion with `?`.
+    fn report_integer_index_errors(
+        &self,
+        errors: &mut Vec<traits::FulfillmentError<'tcx>>,
+        idx: &'tcx hir::Expr<'tcx>,
+        idx_t: Ty<'tcx>,
+    ) {
+        let tcx = self.tcx;
+        let idx_t = self.resolve_vars_if_possible(idx_t);
+        // Whether the conversion may fail: `usize` only implements `From` the unsigned
+        // integers of up to 16 bits.
+        let (signed, fallible) = match idx_t.kind() {
+            ty::Int(_) => (true, true),
+            ty::Uint(ty::UintTy::Usize) => return,
+            ty::Uint(ty::UintTy::U8 | ty::UintTy::U16) => (false, false),
+            ty::Uint(_) => (false, true),
+            _ => return,
+        };
+        let Some(pos) = errors.iter().position(|error| {
+            matches!(
+                error.obligation.predicate.kind().skip_binder(),
+                ty::PredicateKind::Clause(ty::Clause::Trait(pred))
+                    if tcx.is_diagnostic_item(sym::SliceIndex, pred.def_id())
+                        && self.resolve_vars_if_possible(pred.self_ty()) == idx_t
+                        && self
+                            .resolve_vars_if_possible(pred.trait_ref.substs.type_at(1))
+                            .is_slice()
+            )
+        }) else {
+            return;
+        };
+        let error = errors.remove(pos);
+        let ty::PredicateKind::Clause(ty::Clause::Trait(pred)) =
+            error.obligation.predicate.kind().skip_binder()
+        else {
+            unreachable!()
+        };
+        let pred = self.resolve_vars_if_possible(pred);
+        let slice_ty = pred.trait_ref.substs.type_at(1);
+
+        let mut err = struct_span_err!(
+            tcx.sess,
+            idx.span,
+            E0277,
+            "the type `{slice_ty}` cannot be indexed by `{idx_t}`",
+        );
+        err.span_label(idx.span, "slice indices are of type `usize` or ranges of `usize`");
+        err.help(format!(
+            "the trait `{}` is not implemented for `{idx_t}`",
+            pred.trait_ref.print_only_trait_path()
+        ));
+        // Explain how indexing led to the `SliceIndex` bound, e.g. through `Index` for `Vec`.
+        self.err_ctxt().note_obligation_cause_code(
+            self.body_id,
+            &mut err,
+            error.obligation.predicate,
+            error.obligation.param_env,
+            error.obligation.cause.code(),
+            &mut vec![],
+            &mut Default::default(),
+        );
+
+        // A negative or too large index can't be cast without wrapping around or being
+        // truncated, so prefer a checked conversion if its failure can be propagated out
+        // of the enclosing function.
+        let propagation = if fallible && tcx.hir().body_const_context(self.body_id).is_none() {
+            self.ret_coercion.as_ref().and_then(|ret_coercion| {
+                let ret_ty = self.resolve_vars_if_possible(ret_coercion.borrow().expected_ty());
+                self.try_from_index_propagation(ret_ty, idx_t)
+            })
+        } else {
+            None
+        };
+        if let Some(propagation) = propagation {
+            err.multipart_suggestion_verbose(
+                "consider converting the index with `usize::try_from`, returning early if it is \
+                 out of range",
+                vec![
+                    (idx.span.shrink_to_lo(), "usize::try_from(".to_string()),
+                    (idx.span.shrink_to_hi(), format!("){propagation}")),
+                ],
+                Applicability::MaybeIncorrect,
+            );
+        } else {
+            let msg = if signed {
+                "consider casting the index to `usize` if it can't be negative"
+            } else if fallible {
+                "consider casting the index to `usize` if it can't exceed `usize::MAX`"
+            } else {
+                "consider casting the index to `usize`"
+            };
+            let sugg = if idx.precedence().order() < ExprPrecedence::Cast.order() {
+                vec![
+                    (idx.span.shrink_to_lo(), "(".to_string()),
+                    (idx.span.shrink_to_hi(), ") as usize".to_string()),
+                ]
+            } else {
+                vec![(idx.span.shrink_to_hi(), " as usize".to_string())]
+            };
+            err.multipart_suggestion_verbose(msg, sugg, Applicability::MaybeIncorrect);
+        }
+
+        let guar = err.emit();
+        self.set_tainted_by_errors(guar);
+    }
+
+    /// Returns how a failed `usize::try_from` conversion of an index of type `idx_t` can be
+    /// propagated out of a function returning `ret_ty` with `?`, if it can be.
+    fn try_from_index_propagation(
+        &self,
+        ret_ty: Ty<'tcx>,
+        idx_t: Ty<'tcx>,
+    ) -> Option<&'static str> {
+        let tcx = self.tcx;
+        let ty::Adt(def, substs) = ret_ty.kind() else { return None };
+        if tcx.is_diagnostic_item(sym::Option, def.did()) {
+            return Some(".ok()?");
+        }
+        if !tcx.is_diagnostic_item(sym::Result, def.did()) {
+            return None;
+        }
+        let try_from_trait = tcx.get_diagnostic_item(sym::TryFrom)?;
+        let from_trait = tcx.get_diagnostic_item(sym::From)?;
+        let error_item = tcx.associated_items(try_from_trait).find_by_name_and_kind(
+            tcx,
+            Ident::with_dummy_span(sym::Error),
+            ty::AssocKind::Type,
+            try_from_trait,
+        )?;
+        let conversion_error = tcx
+            .try_normalize_erasing_regions(
+                self.param_env,
+                tcx.mk_projection(error_item.def_id, [tcx.types.usize, idx_t]),
+            )
+            .ok()?;
+        self.infcx
+            .type_implements_trait(
+                from_trait,
+                [substs.type_at(1), conversion_error],
+                self.param_env,
+            )
+            .must_apply_modulo_regions()
+            .then_some("?")
+    }
+
     fn check_expr_yield(
         &self,
         value: &'tcx hir::Expr<'tcx>,
@@ -4,7 +4,11 @@
     graph::WithSuccessors,
     graph::{iterate::DepthFirstSearch, vec_graph::VecGraph},
 };
-use rustc_middle::ty::{self, Ty};
+use rustc_errors::{Applicability, DelayDm};
+use rustc_hir as hir;
+use rustc_middle::ty::{self, Ty, TypeVisitableExt};
+use rustc_session::lint;
+use rustc_span::Span;
 
 impl<'tcx> FnCtxt<'_, 'tcx> {
     /// Performs type inference fallback, setting `FnCtxt::fallback_has_occurred`
@@ -31,12 +35,14 @@ pub(super) fn type_inference_fallback(&self) {
 
         let diverging_fallback = self.calculate_diverging_fallback(&unsolved_variables);
 
+        let mut numeric_literals = self.unresolved_numeric_literals(&unsolved_variables);
+
         // We do fallback in two passes, to try to generate
         // better error messages.
         // The first time, we do *not* replace opaque types.
         for ty in unsolved_variables {
             debug!("unsolved_variable = {:?}", ty);
-            self.fallback_if_possible(ty, &diverging_fallback);
+            self.fallback_if_possible(ty, &diverging_fallback, &mut numeric_literals);
         }
 
         // We now see if we can make progress. This might cause us to
@@ -84,6 +90,7 @@ fn fallback_if_possible(
         &self,
         ty: Ty<'tcx>,
         diverging_fallback: &FxHashMap<Ty<'tcx>, Ty<'tcx>>,
+        numeric_literals: &mut Vec<(hir::HirId, Span, Ty<'tcx>)>,
     ) {
         // Careful: we do NOT shallow-resolve `ty`. We know that `ty`
         // is an unsolved variable, and we determine its fallback
@@ -121,6 +128,93 @@ fn fallback_if_possible(
             .unwrap_or(rustc_span::DUMMY_SP);
         self.demand_eqtype(span, ty, fallback);
         self.fallback_has_occurred.set(true);
+
+        if matches!(ty.kind(), ty::Infer(ty::IntVar(_) | ty::FloatVar(_)))
+            && !fallback.references_error()
+        {
+            self.lint_numeric_fallback(numeric_literals, fallback);
+        }
+    }
+
+    /// Returns the literals whose type is an unresolved integer or float
+    /// variable, in source order. Whether the `numeric_fallback` lint fires
+    /// is decided at each literal, as its level may differ within the body.
+    fn unresolved_numeric_literals(
+        &self,
+        unsolved_variables: &[Ty<'tcx>],
+    ) -> Vec<(hir::HirId, Span, Ty<'tcx>)> {
+        if !unsolved_variables
+            .iter()
+            .any(|ty| matches!(ty.kind(), ty::Infer(ty::IntVar(_) | ty::FloatVar(_))))
+        {
+            return vec![];
+        }
+
+        let typeck_results = self.typeck_results.borrow();
+        let hir_owner = typeck_results.hir_owner;
+        typeck_results
+            .node_types()
+            .items_in_stable_order()
+            .into_iter()
+            .filter_map(|(local_id, &ty)| {
+                if !matches!(
+                    self.shallow_resolve(ty).kind(),
+                    ty::Infer(ty::IntVar(_) | ty::FloatVar(_))
+                ) {
+                    return None;
+                }
+                let hir_id = hir::HirId { owner: hir_owner, local_id };
+                match self.tcx.hir().find(hir_id) {
+                    Some(hir::Node::Expr(expr @ hir::Expr { kind: hir::ExprKind::Lit(_), .. })) => {
+                        Some((hir_id, expr.span, ty))
+                    }
+                    _ => None,
+                }
+            })
+            .collect()
+    }
+
+    /// Lints the first of `numeric_literals` that got resolved by falling back to
+    /// `fallback`, which is the literal that created the variable, and forgets
+    /// about the others that were unified with it.
+    fn lint_numeric_fallback(
+        &self,
+        numeric_literals: &mut Vec<(hir::HirId, Span, Ty<'tcx>)>,
+        fallback: Ty<'tcx>,
+    ) {
+        let (resolved, unresolved): (Vec<_>, Vec<_>) = std::mem::take(numeric_literals)
+            .into_iter()
+            .partition(|&(_, _, ty)| !self.shallow_resolve(ty).is_ty_or_numeric_infer());
+        *numeric_literals = unresolved;
+        let Some(&(hir_id, span, _)) = resolved.first() else { return };
+
+        let snippet = self.tcx.sess.source_map().span_to_snippet(span).ok();
+        self.tcx.struct_span_lint_hir(
+            lint::builtin::NUMERIC_FALLBACK,
+            hir_id,
+            span,
+            DelayDm(|| match &snippet {
+                Some(snippet) => format!("the type of `{snippet}` falls back to `{fallback}`"),
+                None => format!("the type of this literal falls back to `{fallback}`"),
+            }),
+            |lint| {
+                if let Some(snippet) = &snippet && !span.from_expansion() {
+                    // `1.f64` would be parsed as a field access.
+                    let suffix = if snippet.ends_with('.') {
+                        format!("0{fallback}")
+                    } else {
+                        fallback.to_string()
+                    };
+                    lint.span_suggestion_verbose(
+                        span.shrink_to_hi(),
+                        "add a suffix to make the type explicit",
+                        suffix,
+                        Applicability::MachineApplicable,
+                    );
+                }
+                lint
+            },
+        );
     }
 
     /// The "diverging fallback" system is rather complicated. This is
@@ -1,13 +1,20 @@
 use crate::callee::{self, DeferredCallResolution};
+use crate::errors::MissingLangItem;
 use crate::method::{self, MethodCallee, SelfSource};
 use crate::rvalue_scopes;
-use crate::{BreakableCtxt, Diverges, Expectation, FnCtxt, LocalTy, RawTy};
+use crate::{
+    BreakableCtxt, BreakableCtxtGuard, Diverges, Expectation, FnCtxt, LocalTy, LocalTyOrigin,
+    RawTy, UnreachableKind,
+};
 use rustc_data_structures::captures::Captures;
-use rustc_data_structures::fx::FxHashSet;
-use rustc_errors::{Applicability, Diagnostic, ErrorGuaranteed, MultiSpan, StashKey};
+use rustc_data_structures::fx::{FxHashSet, IndexEntry};
+use rustc_errors::{
+    pluralize, Applicability, Diagnostic, DiagnosticBuilder, ErrorGuaranteed, MultiSpan, StashKey,
+};
 use rustc_hir as hir;
 use rustc_hir::def::{CtorOf, DefKind, Res};
 use rustc_hir::def_id::DefId;
+use rustc_hir::intravisit::{self, Visitor};
 use rustc_hir::lang_items::LangItem;
 use rustc_hir::{ExprKind, GenericArg, Node, QPath};
 use rustc_hir_analysis::astconv::generics::{
@@ -19,6 +26,7 @@
 };
 use rustc_infer::infer::canonical::{Canonical, OriginalQueryValues, QueryResponse};
 use rustc_infer::infer::error_reporting::TypeAnnotationNeeded::E0282;
+use rustc_infer::infer::type_variable::TypeVariableOriginKind;
 use rustc_infer::infer::{DefineOpaqueTypes, InferResult};
 use rustc_middle::ty::adjustment::{Adjust, Adjustment, AutoBorrow, AutoBorrowMutability};
 use rustc_middle::ty::error::TypeError;
@@ -28,9 +36,9 @@
     self, AdtKind, CanonicalUserType, GenericParamDefKind, Ty, TyCtxt, UserType,
 };
 use rustc_middle::ty::{GenericArgKind, SubstsRef, UserSelfTy, UserSubsts};
-use rustc_session::lint;
+use rustc_session::config::MissingNodeTypes;
 use rustc_span::def_id::LocalDefId;
-use rustc_span::hygiene::DesugaringKind;
+use rustc_span::hygiene::{DesugaringKind, ExpnKind, MacroKind, SyntaxContext};
 use rustc_span::symbol::{kw, sym, Ident};
 use rustc_span::Span;
 use rustc_target::abi::FieldIdx;
@@ -43,7 +51,12 @@
 impl<'a, 'tcx> FnCtxt<'a, 'tcx> {
     /// Produces warning on the given node, if the current point in the
     /// function is unreachable, and there hasn't been another warning.
-    pub(in super::super) fn warn_if_unreachable(&self, id: hir::HirId, span: Span, kind: &str) {
+    pub(in super::super) fn warn_if_unreachable(
+        &self,
+        id: hir::HirId,
+        span: Span,
+        kind: UnreachableKind,
+    ) {
         // FIXME: Combine these two 'if' expressions into one once
         // let chains are implemented
         if let Diverges::Always { span: orig_span, custom_note } = self.diverges.get() {
@@ -56,22 +69,15 @@ pub(in super::super) fn warn_if_unreachable(&self, id: hir::HirId, span: Span, k
             {
                 self.diverges.set(Diverges::WarnedAlways);
 
-                debug!("warn_if_unreachable: id={:?} span={:?} kind={}", id, span, kind);
+                debug!("warn_if_unreachable: id={:?} span={:?} kind={:?}", id, span, kind);
 
-                let msg = format!("unreachable {}", kind);
-                self.tcx().struct_span_lint_hir(
-                    lint::builtin::UNREACHABLE_CODE,
-                    id,
-                    span,
-                    msg.clone(),
-                    |lint| {
-                        lint.span_label(span, msg).span_label(
-                            orig_span,
-                            custom_note
-                                .unwrap_or("any code following this expression is unreachable"),
-                        )
-                    },
-                )
+                let msg = format!("unreachable {}", kind.descr());
+                self.tcx().struct_span_lint_hir(kind.lint(), id, span, msg.clone(), |lint| {
+                    lint.span_label(span, msg).span_label(
+                        orig_span,
+                        custom_note.unwrap_or("any code following this expression is unreachable"),
+                    )
+                })
             }
         }
     }
@@ -116,16 +122,14 @@ pub(in super::super) fn record_deferred_call_resolution(
         closure_def_id: LocalDefId,
         r: DeferredCallResolution<'tcx>,
     ) {
-        let mut deferred_call_resolutions = self.deferred_call_resolutions.borrow_mut();
-        deferred_call_resolutions.entry(closure_def_id).or_default().push(r);
+        self.deferred.borrow_mut().call_resolutions.entry(closure_def_id).or_default().push(r);
     }
 
     pub(in super::super) fn remove_deferred_call_resolutions(
         &self,
         closure_def_id: LocalDefId,
     ) -> Vec<DeferredCallResolution<'tcx>> {
-        let mut deferred_call_resolutions = self.deferred_call_resolutions.borrow_mut();
-        deferred_call_resolutions.remove(&closure_def_id).unwrap_or_default()
+        self.deferred.borrow_mut().call_resolutions.remove(&closure_def_id).unwrap_or_default()
     }
 
     pub fn tag(&self) -> String {
@@ -133,14 +137,41 @@ pub fn tag(&self) -> String {
     }
 
     pub fn local_ty(&self, span: Span, nid: hir::HirId) -> LocalTy<'tcx> {
-        self.locals.borrow().get(&nid).cloned().unwrap_or_else(|| {
-            span_bug!(span, "no type for local variable {}", self.tcx.hir().node_to_string(nid))
+        let local_ty = self.locals.borrow().get(&nid).cloned();
+        local_ty.unwrap_or_else(|| {
+            let msg = format!("no type for local variable {}", self.tcx.hir().node_to_string(nid));
+            let Some(guar) = self.recover_missing_node_type(span, &msg) else {
+                span_bug!(span, "{msg}")
+            };
+            let err = self.tcx.ty_error(guar);
+            LocalTy {
+                decl_ty: err,
+                revealed_ty: err,
+                origin: LocalTyOrigin::Inference,
+                origin_span: span,
+            }
         })
     }
 
+    /// Called when no type was recorded for a node. If errors were already reported for this
+    /// body, those may have stopped its type from being recorded, so this delays a bug and
+    /// returns the guarantee to recover with, unless `-Z treat-missing-node-types=ice` is set.
+    fn recover_missing_node_type(&self, span: Span, msg: &str) -> Option<ErrorGuaranteed> {
+        if self.tcx.sess.opts.unstable_opts.treat_missing_node_types == MissingNodeTypes::Ice {
+            return None;
+        }
+        self.tainted_by_errors()?;
+        Some(self.tcx.sess.delay_span_bug(span, msg.to_string()))
+    }
+
     #[inline]
     pub fn write_ty(&self, id: hir::HirId, ty: Ty<'tcx>) {
-        debug!("write_ty({:?}, {:?}) in fcx {}", id, self.resolve_vars_if_possible(ty), self.tag());
+        // Resolving the type is only needed for the debug output, and this is called for every
+        // expression, pattern and local.
+        if tracing::enabled!(tracing::Level::DEBUG) {
+            let ty = self.resolve_vars_if_possible(ty);
+            debug!("write_ty({:?}, {:?}) in fcx {}", id, ty, self.tag());
+        }
         self.typeck_results.borrow_mut().node_types_mut().insert(id, ty);
 
         if let Err(e) = ty.error_reported() {
@@ -148,6 +179,61 @@ pub fn write_ty(&self, id: hir::HirId, ty: Ty<'tcx>) {
         }
     }
 
+    /// Emits `err`, unless it points into the expansion of a bang macro. Such errors are
+    /// held back until the body has been checked, so that an error reported with the same
+    /// message at the same place in several expansions of a macro is only emitted once.
+    /// See `emit_macro_expansion_errors`.
+    pub(crate) fn emit_once_per_macro_expansion(
+        &self,
+        err: DiagnosticBuilder<'_, ErrorGuaranteed>,
+    ) {
+        let Some(span) = err.span.primary_span() else {
+            err.emit();
+            return;
+        };
+        let ExpnKind::Macro(MacroKind::Bang, macro_name) = span.ctxt().outer_expn_data().kind
+        else {
+            err.emit();
+            return;
+        };
+        // The error is emitted right away if the handler doesn't allow holding it back.
+        let Some((diag, handler)) = err.into_diagnostic() else { return };
+        // Key on the rendered messages, so that errors only differing in their arguments, like
+        // the number of arguments a function takes, are kept apart.
+        let messages = diag
+            .styled_message()
+            .iter()
+            .map(|(msg, _)| handler.eagerly_translate_to_string(msg.clone(), diag.args()))
+            .collect();
+        let key = (messages, span.with_ctxt(SyntaxContext::root()));
+        match self.macro_expansion_errors.borrow_mut().entry(key) {
+            IndexEntry::Occupied(mut entry) => {
+                entry.get_mut().2 += 1;
+            }
+            IndexEntry::Vacant(entry) => {
+                entry.insert((diag, macro_name, 0));
+                self.set_tainted_by_errors(
+                    self.tcx.sess.delay_span_bug(span, "macro expansion error was not emitted"),
+                );
+            }
+        }
+    }
+
+    /// Emits the errors held back by `emit_once_per_macro_expansion`, noting how often each
+    /// of them was repeated in other expansions of its macro.
+    pub(in super::super) fn emit_macro_expansion_errors(&self) {
+        let handler = self.tcx.sess.diagnostic();
+        for (_, (mut diag, macro_name, repeated)) in self.macro_expansion_errors.take() {
+            if repeated > 0 {
+                diag.note(format!(
+                    "this error was repeated in {repeated} other expansion{} of `{macro_name}!`",
+                    pluralize!(repeated)
+                ));
+            }
+            handler.emit_diagnostic(&mut diag);
+        }
+    }
+
     pub fn write_field_index(&self, hir_id: hir::HirId, index: FieldIdx) {
         self.typeck_results.borrow_mut().field_indices_mut().insert(hir_id, index);
     }
@@ -249,7 +335,7 @@ pub fn apply_adjustments(&self, expr: &hir::Expr<'_>, adj: Vec<Adjustment<'tcx>>
 
         match self.typeck_results.borrow_mut().adjustments_mut().entry(expr.hir_id) {
             Entry::Vacant(entry) => {
-                entry.insert(adj);
+                entry.insert(self.tcx.mk_adjustments(&adj));
             }
             Entry::Occupied(mut entry) => {
                 debug!(" - composing on top of {:?}", entry.get());
@@ -284,7 +370,7 @@ pub fn apply_adjustments(&self, expr: &hir::Expr<'_>, adj: Vec<Adjustment<'tcx>>
                         );
                     }
                 }
-                *entry.get_mut() = adj;
+                *entry.get_mut() = self.tcx.mk_adjustments(&adj);
             }
         }
 
@@ -348,7 +434,7 @@ pub fn require_type_is_sized_deferred(
         code: traits::ObligationCauseCode<'tcx>,
     ) {
         if !ty.references_error() {
-            self.deferred_sized_obligations.borrow_mut().push((ty, span, code));
+            self.deferred.borrow_mut().sized_obligations.push((ty, span, code));
         }
     }
 
@@ -375,13 +461,57 @@ pub fn handle_raw_ty(&self, span: Span, ty: Ty<'tcx>) -> RawTy<'tcx> {
 
     pub fn to_ty(&self, ast_t: &hir::Ty<'_>) -> RawTy<'tcx> {
         let t = self.astconv().ast_ty_to_ty(ast_t);
-        self.register_wf_obligation(t.into(), ast_t.span, traits::WellFormed(None));
+        if self.wf_checked_tys.borrow_mut().insert(t) {
+            self.register_wf_obligation(t.into(), ast_t.span, traits::WellFormed(None));
+        } else {
+            self.skipped_wf_obligations.set(self.skipped_wf_obligations.get() + 1);
+        }
+        self.eval_array_lengths(ast_t);
         self.handle_raw_ty(ast_t.span, t)
     }
 
+    /// Eagerly evaluates the constants named as array lengths in the type annotation `ast_t`,
+    /// so that a length that fails to evaluate is reported while checking the annotation. The
+    /// evaluation error then also points at the array type the length is used in.
+    fn eval_array_lengths(&self, ast_t: &hir::Ty<'_>) {
+        struct ArrayLengths<'hir>(Vec<&'hir hir::AnonConst>);
+
+        impl<'v> Visitor<'v> for ArrayLengths<'v> {
+            fn visit_ty(&mut self, ty: &'v hir::Ty<'v>) {
+                if let hir::TyKind::Array(_, hir::ArrayLen::Body(anon_const)) = &ty.kind {
+                    self.0.push(anon_const);
+                }
+                intravisit::walk_ty(self, ty);
+            }
+        }
+
+        let tcx = self.tcx;
+        // Lengths that depend on generic parameters are left to well-formedness checking.
+        if tcx.features().generic_const_exprs {
+            return;
+        }
+        let mut lengths = ArrayLengths(vec![]);
+        lengths.visit_ty(ast_t);
+        for anon_const in lengths.0 {
+            let ty::ConstKind::Unevaluated(uv) =
+                ty::Const::from_anon_const(tcx, anon_const.def_id).kind()
+            else {
+                continue;
+            };
+            // Other length expressions are already pointed at by the evaluation error itself.
+            let len = tcx.hir().body(anon_const.body).value;
+            if !matches!(len.kind, ExprKind::Path(_)) {
+                continue;
+            }
+            // Any error is reported by the evaluation itself.
+            let _ = tcx.const_eval_resolve_for_typeck(self.param_env, uv, Some(len.span));
+        }
+    }
+
     pub fn to_ty_saving_user_provided_ty(&self, ast_ty: &hir::Ty<'_>) -> Ty<'tcx> {
         let ty = self.to_ty(ast_ty);
         debug!("to_ty_saving_user_provided_ty: ty={:?}", ty);
+        self.record_type_placeholders(ast_ty, ty.raw);
 
         if Self::can_contain_user_lifetime_bounds(ty.raw) {
             let c_ty = self.canonicalize_response(UserType::Ty(ty.raw));
@@ -392,6 +522,25 @@ pub fn to_ty_saving_user_provided_ty(&self, ast_ty: &hir::Ty<'_>) -> Ty<'tcx> {
         ty.normalized
     }
 
+    /// Remembers the inference variables that the `_` placeholders in the user-written type
+    /// `ast_ty` were lowered to, so that an inference failure can point at the placeholder
+    /// that remained unconstrained instead of at the type as a whole.
+    pub(crate) fn record_type_placeholders(&self, ast_ty: &hir::Ty<'_>, ty: Ty<'tcx>) {
+        if !ty.has_non_region_infer() {
+            return;
+        }
+        let mut type_placeholders = self.type_placeholders.borrow_mut();
+        for arg in ty.walk() {
+            if let GenericArgKind::Type(ty) = arg.unpack()
+                && let Some(origin) = self.type_var_origin(ty)
+                && let TypeVariableOriginKind::TypeInference = origin.kind
+                && ast_ty.span.contains(origin.span)
+            {
+                type_placeholders.push((origin.span, ty));
+            }
+        }
+    }
+
     pub(super) fn user_substs_for_adt(ty: RawTy<'tcx>) -> UserSubsts<'tcx> {
         match (ty.raw.kind(), ty.normalized.kind()) {
             (ty::Adt(_, substs), _) => UserSubsts { substs, user_self_ty: None },
@@ -448,13 +597,16 @@ fn can_contain_user_lifetime_bounds<T>(t: T) -> bool
     pub fn node_ty(&self, id: hir::HirId) -> Ty<'tcx> {
         match self.typeck_results.borrow().node_types().get(id) {
             Some(&t) => t,
-            None if let Some(e) = self.tainted_by_errors() => self.tcx.ty_error(e),
             None => {
-                bug!(
+                let msg = format!(
                     "no type for node {} in fcx {}",
                     self.tcx.hir().node_to_string(id),
                     self.tag()
                 );
+                match self.recover_missing_node_type(self.tcx.hir().span(id), &msg) {
+                    Some(guar) => self.tcx.ty_error(guar),
+                    None => bug!("{msg}"),
+                }
             }
         }
     }
@@ -520,11 +672,18 @@ pub(in super::super) fn resolve_generator_interiors(&self, def_id: DefId) {
 
         self.select_obligations_where_possible(|_| {});
 
-        let mut generators = self.deferred_generator_interiors.borrow_mut();
-        for (_, body_id, interior, kind) in generators.drain(..) {
+        // Resolving an interior only makes progress possible for the obligations that were
+        // stalled on its witness (e.g. auto trait obligations), none of which can constrain
+        // the types captured by another generator. So we can resolve all interiors before
+        // selecting again, rather than re-selecting the whole pending set once per generator.
+        let generators = std::mem::take(&mut self.deferred.borrow_mut().generator_interiors);
+        if generators.is_empty() {
+            return;
+        }
+        for (_, body_id, interior, kind) in generators {
             crate::generator_interior::resolve_interior(self, def_id, body_id, interior, kind);
-            self.select_obligations_where_possible(|_| {});
         }
+        self.select_obligations_where_possible(|_| {});
     }
 
     /// Unify the inference variables corresponding to generator witnesses, and save all the
@@ -542,7 +701,7 @@ fn save_generator_interior_predicates(&self, def_id: DefId) {
         // trigger query cycle ICEs, as doing so requires MIR.
         self.select_obligations_where_possible(|_| {});
 
-        let generators = std::mem::take(&mut *self.deferred_generator_interiors.borrow_mut());
+        let generators = std::mem::take(&mut self.deferred.borrow_mut().generator_interiors);
         debug!(?generators);
 
         for &(expr_def_id, body_id, interior, _) in generators.iter() {
@@ -771,7 +930,11 @@ pub(in super::super) fn resolve_lang_item_path(
         hir_id: hir::HirId,
         expr_hir_id: Option<hir::HirId>,
     ) -> (Res, Ty<'tcx>) {
-        let def_id = self.tcx.require_lang_item(lang_item, Some(span));
+        let Some(def_id) = self.tcx.lang_items().get(lang_item) else {
+            let guar = self.report_missing_lang_item(lang_item, span);
+            self.write_resolution(hir_id, Err(guar));
+            return (Res::Err, self.tcx.ty_error(guar));
+        };
         let def_kind = self.tcx.def_kind(def_id);
 
         let item_ty = if let DefKind::Variant = def_kind {
@@ -791,9 +954,12 @@ pub(in super::super) fn resolve_lang_item_path(
             hir::LangItem::IteratorNext | hir::LangItem::IntoIterIntoIter => {
                 Some(ObligationCauseCode::ForLoopIterator)
             }
-            hir::LangItem::TryTraitFromOutput
-            | hir::LangItem::TryTraitFromResidual
-            | hir::LangItem::TryTraitBranch => Some(ObligationCauseCode::QuestionMark),
+            hir::LangItem::TryTraitFromResidual => {
+                Some(ObligationCauseCode::QuestionMark(self.question_mark_return_ty(hir_id)))
+            }
+            hir::LangItem::TryTraitFromOutput | hir::LangItem::TryTraitBranch => {
+                Some(ObligationCauseCode::QuestionMark(None))
+            }
             _ => None,
         };
         if let Some(code) = code {
@@ -805,6 +971,53 @@ pub(in super::super) fn resolve_lang_item_path(
         (Res::Def(def_kind, def_id), ty)
     }
 
+    /// `expr?` is lowered to `return FromResidual::from_residual(residual)` when it returns from
+    /// the enclosing function or closure. Given the `HirId` of the `from_residual` path, find
+    /// the return type written for that function, which the residual is converted into.
+    fn question_mark_return_ty(&self, hir_id: hir::HirId) -> Option<hir::HirId> {
+        let hir = self.tcx.hir();
+        let call_id = hir.parent_id(hir_id);
+        // In a `try` block, the `?` breaks out of the block instead.
+        let Node::Expr(hir::Expr { kind: ExprKind::Ret(_), .. }) = hir.get_parent(call_id) else {
+            return None;
+        };
+        let owner = hir.enclosing_body_owner(call_id);
+        match hir.get_by_def_id(owner).fn_decl()?.output {
+            hir::FnRetTy::Return(ty) => Some(ty.hir_id),
+            hir::FnRetTy::DefaultReturn(_) => None,
+        }
+    }
+
+    /// Reports a lang item that the desugaring of `span` refers to but that isn't defined,
+    /// which can only happen in `#![no_core]` crates.
+    fn report_missing_lang_item(&self, lang_item: hir::LangItem, span: Span) -> ErrorGuaranteed {
+        let construct = match span.desugaring_kind() {
+            Some(DesugaringKind::ForLoop) => "this `for` loop",
+            Some(DesugaringKind::QuestionMark) => "the `?` operator",
+            Some(DesugaringKind::TryBlock) => "this `try` block",
+            Some(DesugaringKind::YeetExpr) => "this `do yeet` expression",
+            Some(DesugaringKind::Await) => "this `.await`",
+            _ => match lang_item {
+                LangItem::Range
+                | LangItem::RangeFrom
+                | LangItem::RangeFull
+                | LangItem::RangeInclusiveNew
+                | LangItem::RangeInclusiveStruct
+                | LangItem::RangeTo
+                | LangItem::RangeToInclusive => "this range expression",
+                _ => "this expression",
+            },
+        };
+        let guar = self.tcx.sess.emit_err(MissingLangItem {
+            span,
+            name: lang_item.name(),
+            construct,
+            target: lang_item.target().name(),
+        });
+        self.set_tainted_by_errors(guar);
+        guar
+    }
+
     /// Resolves an associated value path into a base type and associated constant, or method
     /// resolution. The newly resolved definition is written into `type_dependent_defs`.
     pub fn resolve_ty_and_res_fully_qualified_call(
@@ -895,7 +1108,7 @@ pub fn resolve_ty_and_res_fully_qualified_call(
                 }
 
                 if item_name.name != kw::Empty {
-                    if let Some(mut e) = self.report_method_error(
+                    if let Some(e) = self.report_method_error(
                         span,
                         ty.normalized,
                         item_name,
@@ -905,7 +1118,7 @@ pub fn resolve_ty_and_res_fully_qualified_call(
                         Expectation::NoExpectation,
                         trait_missing_method && span.edition().rust_2021(), // emits missing method for trait only after edition 2021
                     ) {
-                        e.emit();
+                        self.emit_once_per_macro_expansion(e);
                     }
                 }
 
@@ -1229,7 +1442,8 @@ pub fn instantiate_value_path(
                                     "use curly brackets",
                                     "Self { /* fields */ }",
                                     Applicability::HasPlaceholders,
-                                );
+                                )
+                                .suggestion_id("typeck::self_ctor_with_fields");
                             }
                         }
                     }
@@ -1494,9 +1708,20 @@ pub(in super::super) fn with_breakable_ctxt<F: FnOnce() -> R, R>(
         let result = f();
         let ctxt = {
             let mut enclosing_breakables = self.enclosing_breakables.borrow_mut();
-            debug_assert!(enclosing_breakables.stack.len() == index + 1);
-            enclosing_breakables.by_id.remove(&id).expect("missing breakable context");
-            enclosing_breakables.stack.pop().expect("missing breakable context")
+            let len = enclosing_breakables.stack.len();
+            if len <= index {
+                bug!("missing breakable context for {id:?}");
+            }
+            if len != index + 1 {
+                self.tcx.sess.delay_span_bug(
+                    self.tcx.def_span(self.body_id),
+                    format!("expected {} enclosing breakable contexts, found {len}", index + 1),
+                );
+            }
+            // Any contexts above this one were left behind by nested checks and are dropped
+            // along with this one, so that `pop` never hands back the wrong context.
+            enclosing_breakables.by_id.retain(|_, ix| *ix < index);
+            enclosing_breakables.stack.drain(index..).next().unwrap()
         };
         (ctxt, result)
     }
@@ -4,7 +4,9 @@
 use rustc_hir::def_id::DefId;
 use rustc_infer::traits::ObligationCauseCode;
 use rustc_middle::ty::{self, Ty, TyCtxt, TypeSuperVisitable, TypeVisitable, TypeVisitor};
-use rustc_span::{self, symbol::kw, Span};
+use rustc_span::hygiene::{ExpnKind, MacroKind};
+use rustc_span::symbol::{kw, sym, Symbol};
+use rustc_span::{self, Span};
 use rustc_trait_selection::traits;
 
 use std::ops::ControlFlow;
@@ -338,7 +340,7 @@ fn blame_specific_arg_if_possible(
         &self,
         error: &mut traits::FulfillmentError<'tcx>,
         def_id: DefId,
-        param_to_point_at: ty::GenericArg<'tcx>,
+        mut param_to_point_at: ty::GenericArg<'tcx>,
         call_hir_id: hir::HirId,
         callee_span: Span,
         receiver: Option<&'tcx hir::Expr<'tcx>>,
@@ -349,12 +351,25 @@ fn blame_specific_arg_if_possible(
             return false;
         }
         let sig = ty.fn_sig(self.tcx).skip_binder();
-        let args_referencing_param: Vec<_> = sig
-            .inputs()
-            .iter()
-            .enumerate()
-            .filter(|(_, ty)| find_param_in_ty((**ty).into(), param_to_point_at))
-            .collect();
+        let args_referencing = |param| -> Vec<_> {
+            sig.inputs()
+                .iter()
+                .enumerate()
+                .filter(|(_, ty)| find_param_in_ty((**ty).into(), param))
+                .collect()
+        };
+        let mut args_referencing_param = args_referencing(param_to_point_at);
+        // An `impl Trait` in the bounds of an argument-position `impl Trait`, like the
+        // `impl Debug` in `impl Iterator<Item = impl Debug>`, doesn't appear in the
+        // signature itself, so blame the argument passed to the outer one instead.
+        let mut apit_param = None;
+        while args_referencing_param.is_empty()
+            && let Some(outer) = self.enclosing_apit_param(def_id, param_to_point_at)
+        {
+            param_to_point_at = outer;
+            apit_param = self.apit_param_def_id(def_id, outer);
+            args_referencing_param = args_referencing(param_to_point_at);
+        }
         // If there's one field that references the given generic, great!
         if let [(idx, _)] = args_referencing_param.as_slice()
             && let Some(arg) = receiver
@@ -367,10 +382,16 @@ fn blame_specific_arg_if_possible(
                 self.blame_specific_expr_if_possible(error, arg_expr)
             }
 
+            if let Some((macro_name, arg_idx)) = self.builtin_macro_argument(call_hir_id) {
+                error.obligation.cause.map_code(|parent_code| {
+                    ObligationCauseCode::BuiltinMacroArgument { macro_name, arg_idx, parent_code }
+                });
+            }
             error.obligation.cause.map_code(|parent_code| {
                 ObligationCauseCode::FunctionArgumentObligation {
                     arg_hir_id: arg.hir_id,
                     call_hir_id,
+                    apit_param,
                     parent_code,
                 }
             });
@@ -384,6 +405,103 @@ fn blame_specific_arg_if_possible(
         false
     }
 
+    /// Returns the `DefId` of `param` if it is an argument-position `impl Trait`
+    /// parameter of `def_id`.
+    fn apit_param_def_id(&self, def_id: DefId, param: ty::GenericArg<'tcx>) -> Option<DefId> {
+        let ty::GenericArgKind::Type(ty) = param.unpack() else { return None };
+        let ty::Param(param_ty) = *ty.kind() else { return None };
+        let param_def = self.tcx.generics_of(def_id).type_param(&param_ty, self.tcx);
+        param_def.kind.is_synthetic().then_some(param_def.def_id)
+    }
+
+    /// If `param` is an argument-position `impl Trait` parameter that is mentioned in
+    /// the bounds of another one, returns that other parameter.
+    fn enclosing_apit_param(
+        &self,
+        def_id: DefId,
+        param: ty::GenericArg<'tcx>,
+    ) -> Option<ty::GenericArg<'tcx>> {
+        self.apit_param_def_id(def_id, param)?;
+        self.tcx
+            .predicates_of(def_id)
+            .instantiate_identity(self.tcx)
+            .predicates
+            .into_iter()
+            .find_map(|pred| {
+                let (self_ty, mentions_param) = match pred.kind().skip_binder() {
+                    ty::PredicateKind::Clause(ty::Clause::Trait(pred)) => (
+                        pred.self_ty(),
+                        pred.trait_ref
+                            .substs
+                            .iter()
+                            .skip(1)
+                            .any(|arg| find_param_in_ty(arg, param)),
+                    ),
+                    ty::PredicateKind::Clause(ty::Clause::Projection(pred)) => (
+                        pred.projection_ty.self_ty(),
+                        pred.term.ty().is_some_and(|ty| find_param_in_ty(ty.into(), param)),
+                    ),
+                    _ => return None,
+                };
+                let outer = self_ty.into();
+                (mentions_param
+                    && outer != param
+                    && self.apit_param_def_id(def_id, outer).is_some())
+                .then_some(outer)
+            })
+    }
+
+    /// If `call_hir_id` is one of the calls to a `fmt::Argument` constructor that the arguments
+    /// of `format_args!` are lowered into, returns the name of the standard library formatting
+    /// macro the user invoked and the position of the argument among the arguments they passed
+    /// to it, or `None` if it was captured from the format string.
+    fn builtin_macro_argument(&self, call_hir_id: hir::HirId) -> Option<(Symbol, Option<usize>)> {
+        let tcx = self.tcx;
+        // The position is recorded when lowering `format_args!`.
+        let position = tcx.format_argument_position(call_hir_id)?;
+        let call_span = tcx.hir().span(call_hir_id);
+
+        // Blame the outermost macro in the chain of standard library macros that ends in
+        // `format_args!`, like `println!` or `assert_eq!`.
+        let mut outermost = None;
+        for expn_data in call_span.macro_backtrace() {
+            let ExpnKind::Macro(MacroKind::Bang, _) = expn_data.kind else { break };
+            let Some(macro_def_id) = expn_data.macro_def_id else { break };
+            if !matches!(tcx.crate_name(macro_def_id.krate), sym::std | sym::core | sym::alloc) {
+                break;
+            }
+            outermost = Some(macro_def_id);
+        }
+        let macro_def_id = outermost?;
+
+        // The formatting arguments come after the format string and any arguments of the
+        // macro itself. Other macros, like `dbg!`, don't pass their arguments through to
+        // `format_args!` as they are, so there's no argument of theirs to point out.
+        let leading_args = match tcx.get_diagnostic_name(macro_def_id)? {
+            sym::format_args_macro
+            | sym::format_macro
+            | sym::print_macro
+            | sym::println_macro
+            | sym::eprint_macro
+            | sym::eprintln_macro
+            | sym::std_panic_macro
+            | sym::core_panic_macro
+            | sym::unreachable_macro
+            | sym::todo_macro
+            | sym::unimplemented_macro => 1,
+            sym::write_macro
+            | sym::writeln_macro
+            | sym::assert_macro
+            | sym::debug_assert_macro => 2,
+            sym::assert_eq_macro
+            | sym::assert_ne_macro
+            | sym::debug_assert_eq_macro
+            | sym::debug_assert_ne_macro => 3,
+            _ => return None,
+        };
+        Some((tcx.item_name(macro_def_id), position.map(|idx| idx + leading_args)))
+    }
+
     /**
      * Recursively searches for the most-specific blamable expression.
      * For example, if you have a chain of constraints like:
@@ -1,17 +1,18 @@
 use crate::coercion::CoerceMany;
+use crate::fluent_generated as fluent;
 use crate::fn_ctxt::arg_matrix::{ArgMatrix, Compatibility, Error, ExpectedIdx, ProvidedIdx};
 use crate::gather_locals::Declaration;
 use crate::method::MethodCallee;
 use crate::TupleArgumentsFlag::*;
 use crate::{errors, Expectation::*};
 use crate::{
-    struct_span_err, BreakableCtxt, Diverges, Expectation, FnCtxt, LocalTy, Needs, RawTy,
-    TupleArgumentsFlag,
+    struct_span_err, BreakableCtxt, Diverges, Expectation, FnCtxt, LocalTy, LocalTyOrigin, Needs,
+    RawTy, TupleArgumentsFlag, UnreachableKind,
 };
 use rustc_ast as ast;
 use rustc_data_structures::fx::FxIndexSet;
 use rustc_errors::{
-    pluralize, Applicability, Diagnostic, DiagnosticId, ErrorGuaranteed, MultiSpan,
+    error_code, Applicability, Diagnostic, DiagnosticId, ErrorGuaranteed, MultiSpan,
 };
 use rustc_hir as hir;
 use rustc_hir::def::{CtorOf, DefKind, Res};
@@ -19,17 +20,18 @@
 use rustc_hir::{ExprKind, Node, QPath};
 use rustc_hir_analysis::astconv::AstConv;
 use rustc_hir_analysis::check::intrinsicck::InlineAsmCtxt;
-use rustc_hir_analysis::check::potentially_plural_count;
 use rustc_hir_analysis::structured_errors::StructuredDiagnostic;
 use rustc_index::IndexVec;
 use rustc_infer::infer::error_reporting::{FailureCode, ObligationCauseExt};
 use rustc_infer::infer::type_variable::{TypeVariableOrigin, TypeVariableOriginKind};
 use rustc_infer::infer::TypeTrace;
 use rustc_infer::infer::{DefineOpaqueTypes, InferOk};
+use rustc_middle::middle::codegen_fn_attrs::CodegenFnAttrFlags;
 use rustc_middle::ty::adjustment::AllowTwoPhase;
 use rustc_middle::ty::visit::TypeVisitableExt;
 use rustc_middle::ty::{self, IsSuggestable, Ty};
 use rustc_session::Session;
+use rustc_span::hygiene::DesugaringKind;
 use rustc_span::symbol::{kw, Ident};
 use rustc_span::{self, sym, BytePos, Span};
 use rustc_trait_selection::traits::{self, ObligationCauseCode, SelectionContext};
@@ -39,9 +41,9 @@
 
 impl<'a, 'tcx> FnCtxt<'a, 'tcx> {
     pub(in super::super) fn check_casts(&mut self) {
-        // don't hold the borrow to deferred_cast_checks while checking to avoid borrow checker errors
-        // when writing to `self.param_env`.
-        let mut deferred_cast_checks = mem::take(&mut *self.deferred_cast_checks.borrow_mut());
+        // don't hold the borrow to the deferred cast checks while checking to avoid borrow checker
+        // errors when writing to `self.param_env`.
+        let mut deferred_cast_checks = mem::take(&mut self.deferred.borrow_mut().cast_checks);
 
         debug!("FnCtxt::check_casts: {} deferred checks", deferred_cast_checks.len());
         for cast in deferred_cast_checks.drain(..) {
@@ -53,7 +55,7 @@ pub(in super::super) fn check_casts(&mut self) {
             self.param_env = prev_env;
         }
 
-        *self.deferred_cast_checks.borrow_mut() = deferred_cast_checks;
+        self.deferred.borrow_mut().cast_checks = deferred_cast_checks;
     }
 
     pub(in super::super) fn check_transmutes(&self) {
@@ -350,7 +352,7 @@ pub(in super::super) fn check_argument_types(
                 // Closure arguments themselves can't be diverging, but
                 // a previous argument can, e.g., `foo(panic!(), || {})`.
                 if !check_closures {
-                    self.warn_if_unreachable(arg.hir_id, arg.span, "expression");
+                    self.warn_if_unreachable(arg.hir_id, arg.span, UnreachableKind::CallArg);
                 }
 
                 // For C-variadic functions, we don't have a declared type for all of
@@ -469,7 +471,7 @@ fn report_arg_errors(
         call_expr: &'tcx hir::Expr<'tcx>,
     ) {
         // Next, let's construct the error
-        let (error_span, full_call_span, call_name, is_method) = match &call_expr.kind {
+        let (error_span, full_call_span, callee_kind, is_method) = match &call_expr.kind {
             hir::ExprKind::Call(
                 hir::Expr { hir_id, span, kind: hir::ExprKind::Path(qpath), .. },
                 _,
@@ -479,7 +481,7 @@ fn report_arg_errors(
                 {
                     let name = match of {
                         CtorOf::Struct => "struct",
-                        CtorOf::Variant => "enum variant",
+                        CtorOf::Variant => "variant",
                     };
                     (call_span, *span, name, false)
                 } else {
@@ -678,25 +680,19 @@ fn has_error_or_infer<'tcx>(tys: impl IntoIterator<Item = Ty<'tcx>>) -> bool {
                             ),
                             terr,
                         );
-                        err.span_label(
-                            full_call_span,
-                            format!("arguments to this {} are incorrect", call_name),
-                        );
+                        err.span_label(full_call_span, fluent::hir_typeck_args_incorrect);
+                        err.set_arg("callee_kind", callee_kind);
                     } else {
                         err = tcx.sess.struct_span_err_with_code(
                             full_call_span,
-                            format!(
-                                "{call_name} takes {}{} but {} {} supplied",
-                                if c_variadic { "at least " } else { "" },
-                                potentially_plural_count(
-                                    formal_and_expected_inputs.len(),
-                                    "argument"
-                                ),
-                                potentially_plural_count(provided_args.len(), "argument"),
-                                pluralize!("was", provided_args.len())
-                            ),
+                            fluent::hir_typeck_arg_count_mismatch,
                             DiagnosticId::Error(err_code.to_owned()),
                         );
+                        err.set_arg("this", false);
+                        err.set_arg("callee_kind", callee_kind);
+                        err.set_arg("c_variadic", c_variadic);
+                        err.set_arg("expected_count", formal_and_expected_inputs.len());
+                        err.set_arg("provided_count", provided_args.len());
                         err.multipart_suggestion_verbose(
                             "wrap these arguments in parentheses to construct a tuple",
                             vec![
@@ -704,7 +700,8 @@ fn has_error_or_infer<'tcx>(tys: impl IntoIterator<Item = Ty<'tcx>>) -> bool {
                                 (hi.shrink_to_hi(), ")".to_string()),
                             ],
                             Applicability::MachineApplicable,
-                        );
+                        )
+                        .suggestion_id("typeck::wrap_args_in_tuple");
                     };
                     self.label_fn_like(
                         &mut err,
@@ -787,10 +784,8 @@ fn has_error_or_infer<'tcx>(tys: impl IntoIterator<Item = Ty<'tcx>>) -> bool {
                 None,
                 None,
             );
-            err.span_label(
-                full_call_span,
-                format!("arguments to this {} are incorrect", call_name),
-            );
+            err.span_label(full_call_span, fluent::hir_typeck_args_incorrect);
+            err.set_arg("callee_kind", callee_kind);
 
             if let hir::ExprKind::MethodCall(_, rcvr, _, _) = call_expr.kind
                 && provided_idx.as_usize() == expected_idx.as_usize()
@@ -818,27 +813,24 @@ fn has_error_or_infer<'tcx>(tys: impl IntoIterator<Item = Ty<'tcx>>) -> bool {
         }
 
         let mut err = if formal_and_expected_inputs.len() == provided_args.len() {
-            struct_span_err!(
-                tcx.sess,
+            tcx.sess.struct_span_err_with_code(
                 full_call_span,
-                E0308,
-                "arguments to this {} are incorrect",
-                call_name,
+                fluent::hir_typeck_args_incorrect,
+                error_code!(E0308),
             )
         } else {
-            tcx.sess.struct_span_err_with_code(
+            let mut err = tcx.sess.struct_span_err_with_code(
                 full_call_span,
-                format!(
-                    "this {} takes {}{} but {} {} supplied",
-                    call_name,
-                    if c_variadic { "at least " } else { "" },
-                    potentially_plural_count(formal_and_expected_inputs.len(), "argument"),
-                    potentially_plural_count(provided_args.len(), "argument"),
-                    pluralize!("was", provided_args.len())
-                ),
+                fluent::hir_typeck_arg_count_mismatch,
                 DiagnosticId::Error(err_code.to_owned()),
-            )
+            );
+            err.set_arg("this", true);
+            err.set_arg("c_variadic", c_variadic);
+            err.set_arg("expected_count", formal_and_expected_inputs.len());
+            err.set_arg("provided_count", provided_args.len());
+            err
         };
+        err.set_arg("callee_kind", callee_kind);
 
         // As we encounter issues, keep track of what we want to provide for the suggestion
         let mut labels = vec![];
@@ -854,6 +846,24 @@ enum SuggestionText {
         }
         let mut suggestion_text = SuggestionText::None;
 
+        // Types that aren't worth printing in labels are rendered as `NONE`.
+        let render_ty = |ty: Ty<'tcx>| {
+            if has_error_or_infer([ty]) { "NONE".to_string() } else { ty.to_string() }
+        };
+        let missing_label = |span, tys: &[Ty<'tcx>]| {
+            let render = |i: usize| match tys.get(i) {
+                Some(ty) if !has_error_or_infer(tys.iter().copied()) => ty.to_string(),
+                _ => "NONE".to_string(),
+            };
+            errors::ArgumentLabel::Missing {
+                span,
+                missing_count: tys.len(),
+                missing_first: render(0),
+                missing_second: render(1),
+                missing_third: render(2),
+            }
+        };
+
         let ty_to_snippet = |ty: Ty<'tcx>, expected_idx: ExpectedIdx| {
             if ty.is_unit() {
                 "()".to_string()
@@ -873,6 +883,18 @@ enum SuggestionText {
             }
         };
 
+        // The `Location` that `#[track_caller]` functions receive in their ABI isn't one of
+        // their arguments, though users sometimes try to pass it explicitly.
+        let is_track_caller = fn_def_id.is_some_and(|def_id| {
+            tcx.def_kind(def_id).has_codegen_attrs()
+                && tcx.codegen_fn_attrs(def_id).flags.contains(CodegenFnAttrFlags::TRACK_CALLER)
+        });
+        let is_caller_location = |ty: Ty<'tcx>| {
+            matches!(ty.peel_refs().kind(), ty::Adt(adt, _)
+                if tcx.lang_items().panic_location() == Some(adt.did()))
+        };
+        let mut extra_caller_location = false;
+
         let mut errors = errors.into_iter().peekable();
         let mut only_extras_so_far = errors
             .peek()
@@ -913,14 +935,16 @@ enum SuggestionText {
                 }
                 Error::Extra(arg_idx) => {
                     let (provided_ty, provided_span) = provided_arg_tys[arg_idx];
-                    let provided_ty_name = if !has_error_or_infer([provided_ty]) {
-                        // FIXME: not suggestable, use something else
-                        format!(" of type `{}`", provided_ty)
+                    labels.push(if is_track_caller && is_caller_location(provided_ty) {
+                        extra_caller_location = true;
+                        errors::ArgumentLabel::UnexpectedCallerLocation { span: provided_span }
                     } else {
-                        "".to_string()
-                    };
-                    labels
-                        .push((provided_span, format!("unexpected argument{}", provided_ty_name)));
+                        errors::ArgumentLabel::Unexpected {
+                            span: provided_span,
+                            // FIXME: not suggestable, use something else
+                            unexpected_ty: render_ty(provided_ty),
+                        }
+                    });
                     let mut span = provided_span;
                     if span.can_be_used_for_suggestions() {
                         if arg_idx.index() > 0
@@ -992,12 +1016,7 @@ enum SuggestionText {
                             } else {
                                 args_span
                             };
-                            let rendered = if !has_error_or_infer([input_ty]) {
-                                format!(" of type `{}`", input_ty)
-                            } else {
-                                "".to_string()
-                            };
-                            labels.push((span, format!("an argument{} is missing", rendered)));
+                            labels.push(missing_label(span, &[input_ty]));
                             suggestion_text = match suggestion_text {
                                 SuggestionText::None => SuggestionText::Provide(false),
                                 SuggestionText::Provide(_) => SuggestionText::Provide(true),
@@ -1015,16 +1034,10 @@ enum SuggestionText {
                             } else {
                                 args_span
                             };
-                            let rendered =
-                                if !has_error_or_infer([first_expected_ty, second_expected_ty]) {
-                                    format!(
-                                        " of type `{}` and `{}`",
-                                        first_expected_ty, second_expected_ty
-                                    )
-                                } else {
-                                    "".to_string()
-                                };
-                            labels.push((span, format!("two arguments{} are missing", rendered)));
+                            labels.push(missing_label(
+                                span,
+                                &[first_expected_ty, second_expected_ty],
+                            ));
                             suggestion_text = match suggestion_text {
                                 SuggestionText::None | SuggestionText::Provide(_) => {
                                     SuggestionText::Provide(true)
@@ -1044,19 +1057,10 @@ enum SuggestionText {
                             } else {
                                 args_span
                             };
-                            let rendered = if !has_error_or_infer([
-                                first_expected_ty,
-                                second_expected_ty,
-                                third_expected_ty,
-                            ]) {
-                                format!(
-                                    " of type `{}`, `{}`, and `{}`",
-                                    first_expected_ty, second_expected_ty, third_expected_ty
-                                )
-                            } else {
-                                "".to_string()
-                            };
-                            labels.push((span, format!("three arguments{} are missing", rendered)));
+                            labels.push(missing_label(
+                                span,
+                                &[first_expected_ty, second_expected_ty, third_expected_ty],
+                            ));
                             suggestion_text = match suggestion_text {
                                 SuggestionText::None | SuggestionText::Provide(_) => {
                                     SuggestionText::Provide(true)
@@ -1078,7 +1082,7 @@ enum SuggestionText {
                             } else {
                                 args_span
                             };
-                            labels.push((span, "multiple arguments are missing".to_string()));
+                            labels.push(errors::ArgumentLabel::MissingMany { span });
                             suggestion_text = match suggestion_text {
                                 SuggestionText::None | SuggestionText::Provide(_) => {
                                     SuggestionText::Provide(true)
@@ -1096,27 +1100,19 @@ enum SuggestionText {
                 ) => {
                     let (first_provided_ty, first_span) = provided_arg_tys[first_provided_idx];
                     let (_, first_expected_ty) = formal_and_expected_inputs[first_expected_idx];
-                    let first_provided_ty_name = if !has_error_or_infer([first_provided_ty]) {
-                        format!(", found `{}`", first_provided_ty)
-                    } else {
-                        String::new()
-                    };
-                    labels.push((
-                        first_span,
-                        format!("expected `{}`{}", first_expected_ty, first_provided_ty_name),
-                    ));
+                    labels.push(errors::ArgumentLabel::Expected {
+                        span: first_span,
+                        expected_ty: first_expected_ty.to_string(),
+                        found_ty: render_ty(first_provided_ty),
+                    });
 
                     let (second_provided_ty, second_span) = provided_arg_tys[second_provided_idx];
                     let (_, second_expected_ty) = formal_and_expected_inputs[second_expected_idx];
-                    let second_provided_ty_name = if !has_error_or_infer([second_provided_ty]) {
-                        format!(", found `{}`", second_provided_ty)
-                    } else {
-                        String::new()
-                    };
-                    labels.push((
-                        second_span,
-                        format!("expected `{}`{}", second_expected_ty, second_provided_ty_name),
-                    ));
+                    labels.push(errors::ArgumentLabel::Expected {
+                        span: second_span,
+                        expected_ty: second_expected_ty.to_string(),
+                        found_ty: render_ty(second_provided_ty),
+                    });
 
                     suggestion_text = match suggestion_text {
                         SuggestionText::None => SuggestionText::Swap,
@@ -1127,15 +1123,11 @@ enum SuggestionText {
                     for (dst_arg, dest_input) in args {
                         let (_, expected_ty) = formal_and_expected_inputs[dst_arg];
                         let (provided_ty, provided_span) = provided_arg_tys[dest_input];
-                        let provided_ty_name = if !has_error_or_infer([provided_ty]) {
-                            format!(", found `{}`", provided_ty)
-                        } else {
-                            String::new()
-                        };
-                        labels.push((
-                            provided_span,
-                            format!("expected `{}`{}", expected_ty, provided_ty_name),
-                        ));
+                        labels.push(errors::ArgumentLabel::Expected {
+                            span: provided_span,
+                            expected_ty: expected_ty.to_string(),
+                            found_ty: render_ty(provided_ty),
+                        });
                     }
 
                     suggestion_text = match suggestion_text {
@@ -1187,33 +1179,46 @@ enum SuggestionText {
 
         // If we have less than 5 things to say, it would be useful to call out exactly what's wrong
         if labels.len() <= 5 {
-            for (span, label) in labels {
-                err.span_label(span, label);
+            for label in labels {
+                err.eager_subdiagnostic(&tcx.sess.parse_sess.span_diagnostic, label);
             }
         }
 
         // Call out where the function is defined
         self.label_fn_like(&mut err, fn_def_id, callee_ty, None, is_method);
 
+        if extra_caller_location {
+            err.note(fluent::hir_typeck_track_caller_location_note);
+        }
+
         // And add a suggestion block for all of the parameters
         let suggestion_text = match suggestion_text {
             SuggestionText::None => None,
             SuggestionText::Provide(plural) => {
-                Some(format!("provide the argument{}", if plural { "s" } else { "" }))
+                err.set_arg("plural", plural);
+                Some((fluent::hir_typeck_suggest_provide_args, "typeck::provide_args"))
             }
             SuggestionText::Remove(plural) => {
+                err.set_arg("plural", plural);
                 err.multipart_suggestion(
-                    format!("remove the extra argument{}", if plural { "s" } else { "" }),
+                    fluent::hir_typeck_suggest_remove_args,
                     suggestions,
                     Applicability::HasPlaceholders,
-                );
+                )
+                .suggestion_id("typeck::remove_args");
                 None
             }
-            SuggestionText::Swap => Some("swap these arguments".to_string()),
-            SuggestionText::Reorder => Some("reorder these arguments".to_string()),
-            SuggestionText::DidYouMean => Some("did you mean".to_string()),
+            SuggestionText::Swap => {
+                Some((fluent::hir_typeck_suggest_swap_args, "typeck::swap_args"))
+            }
+            SuggestionText::Reorder => {
+                Some((fluent::hir_typeck_suggest_reorder_args, "typeck::reorder_args"))
+            }
+            SuggestionText::DidYouMean => {
+                Some((fluent::hir_typeck_suggest_args_did_you_mean, "typeck::fix_args"))
+            }
         };
-        if let Some(suggestion_text) = suggestion_text {
+        if let Some((suggestion_text, suggestion_id)) = suggestion_text {
             let source_map = self.sess().source_map();
             let (mut suggestion, suggestion_span) =
                 if let Some(call_span) = full_call_span.find_ancestor_inside(error_span) {
@@ -1256,10 +1261,11 @@ enum SuggestionText {
                 suggestion_text,
                 suggestion,
                 Applicability::HasPlaceholders,
-            );
+            )
+            .suggestion_id(suggestion_id);
         }
 
-        err.emit();
+        self.emit_once_per_macro_expansion(err);
     }
 
     // AST fragment checking
@@ -1376,6 +1382,7 @@ pub fn check_decl_initializer(
         &self,
         hir_id: hir::HirId,
         pat: &'tcx hir::Pat<'tcx>,
+        ty: Option<&'tcx hir::Ty<'tcx>>,
         init: &'tcx hir::Expr<'tcx>,
     ) -> Ty<'tcx> {
         // FIXME(tschottdorf): `contains_explicit_ref_binding()` must be removed
@@ -1398,7 +1405,13 @@ pub fn check_decl_initializer(
             self.demand_eqtype(init.span, local_ty, init_ty);
             init_ty
         } else {
-            self.check_expr_coercible_to_type(init, local_ty, None)
+            let init_ty = self.check_expr_with_hint(init, local_ty);
+            self.demand_coerce_with_note(init, init_ty, local_ty, None, AllowTwoPhase::No, |err| {
+                if let Some(ty) = ty {
+                    // Point at `let` assignment type.
+                    err.span_label(ty.span, "expected due to this");
+                }
+            })
         }
     }
 
@@ -1409,9 +1422,18 @@ pub(in super::super) fn check_decl(&self, decl: Declaration<'tcx>) {
 
         // Type check the initializer.
         if let Some(ref init) = decl.init {
-            let init_ty = self.check_decl_initializer(decl.hir_id, decl.pat, &init);
-            self.overwrite_local_ty_if_err(decl.hir_id, decl.pat, init_ty);
+            let init_ty = self.check_decl_initializer(decl.hir_id, decl.pat, decl.ty, &init);
+            // If the type of the local was fully written out, keep it even if the
+            // initializer had errors, so that later uses of the binding are still
+            // checked against the annotation.
+            let fully_annotated = decl.ty.is_some()
+                && !self.resolve_vars_if_possible(decl_ty).has_non_region_infer()
+                && !decl_ty.references_error();
+            if !fully_annotated {
+                self.overwrite_local_ty_if_err(decl.hir_id, decl.pat, init_ty);
+            }
         }
+        self.record_local_ty_origin(&decl);
 
         // Does the expected pattern type originate from an expression and what is the span?
         let (origin_expr, ty_span) = match (decl.ty, decl.init) {
@@ -1452,7 +1474,7 @@ pub fn check_stmt(&self, stmt: &'tcx hir::Stmt<'tcx>, is_last: bool) {
             hir::StmtKind::Local(..) | hir::StmtKind::Expr(..) | hir::StmtKind::Semi(..) => {}
         }
 
-        self.warn_if_unreachable(stmt.hir_id, stmt.span, "statement");
+        self.warn_if_unreachable(stmt.hir_id, stmt.span, UnreachableKind::Statement);
 
         // Hide the outer diverging and `has_errors` flags.
         let old_diverges = self.diverges.replace(Diverges::Maybe);
@@ -1469,6 +1491,7 @@ pub fn check_stmt(&self, stmt: &'tcx hir::Stmt<'tcx>, is_last: bool) {
                     if expr.can_have_side_effects() {
                         self.suggest_semicolon_at_end(expr.span, err);
                     }
+                    self.suggest_statement_match_arm_values(err, expr);
                 });
             }
             hir::StmtKind::Semi(ref expr) => {
@@ -1527,13 +1550,23 @@ pub(in super::super) fn check_block_with_expected(
         };
 
         let prev_diverges = self.diverges.get();
-        let ctxt = BreakableCtxt { coerce: Some(coerce), may_break: false };
+        let ctxt =
+            BreakableCtxt { coerce: Some(coerce), may_break: false, break_value_spans: Vec::new() };
 
         let (ctxt, ()) = self.with_breakable_ctxt(blk.hir_id, ctxt, || {
             for (pos, s) in blk.stmts.iter().enumerate() {
                 self.check_stmt(s, blk.stmts.len() - 1 == pos);
             }
 
+            // Lint the tail here rather than in `check_expr` so that it gets its own
+            // lint. Tails of desugared `try` blocks are autogenerated, leave those to
+            // `check_expr`, which knows when to skip them.
+            if let Some(tail) = blk.expr
+                && !tail.span.is_desugaring(DesugaringKind::TryBlock)
+            {
+                self.warn_if_unreachable(tail.hir_id, tail.span, UnreachableKind::BlockTail);
+            }
+
             // check the tail expression **without** holding the
             // `enclosing_breakables` lock below.
             let tail_expr_ty =
@@ -1556,6 +1589,7 @@ pub(in super::super) fn check_block_with_expected(
                     tail_expr_ty,
                     Some(&mut |diag: &mut Diagnostic| {
                         self.suggest_block_to_brackets(diag, blk, tail_expr_ty, ty_for_diagnostic);
+                        self.note_if_without_else_tail(diag, blk, tail_expr_ty, ty_for_diagnostic);
                     }),
                     false,
                 );
@@ -1682,6 +1716,15 @@ fn parent_item_span(&self, id: hir::HirId) -> Option<Span> {
                 if let ExprKind::Block(block, _) = &body.value.kind {
                     return Some(block.span);
                 }
+                // The body of an `async fn` is a generator whose body ends in
+                // `drop-temps { <user-body> }`, point at the user's block instead.
+                if let ExprKind::Closure(&hir::Closure { body: gen_body_id, .. }) = body.value.kind
+                    && let ExprKind::Block(block, _) = self.tcx.hir().body(gen_body_id).value.kind
+                    && let Some(hir::Expr { kind: ExprKind::DropTemps(user_body), .. }) = block.expr
+                    && let ExprKind::Block(user_block, _) = user_body.kind
+                {
+                    return Some(user_block.span);
+                }
             }
             _ => {}
         }
@@ -1730,6 +1773,30 @@ fn get_expr_coercion_span(&self, expr: &hir::Expr<'_>) -> rustc_span::Span {
         expr.span
     }
 
+    /// Records where the type of a declaration's binding came from, if the pattern is a single
+    /// by-value binding whose type is that of the whole declaration.
+    fn record_local_ty_origin(&self, decl: &Declaration<'tcx>) {
+        let hir::PatKind::Binding(hir::BindingAnnotation(hir::ByRef::No, _), _, _, None) =
+            decl.pat.kind
+        else {
+            return;
+        };
+        let (origin, origin_span) = match (decl.ty, decl.init) {
+            (Some(ty), _) => (LocalTyOrigin::Annotation, ty.span),
+            (None, Some(init)) => (LocalTyOrigin::Initializer, init.span),
+            (None, None) => return,
+        };
+        let mut locals = self.locals.borrow_mut();
+        for hir_id in [decl.hir_id, decl.pat.hir_id] {
+            if let Some(local_ty) = locals.get_mut(&hir_id)
+                && !local_ty.decl_ty.references_error()
+            {
+                local_ty.origin = origin;
+                local_ty.origin_span = origin_span;
+            }
+        }
+    }
+
     fn overwrite_local_ty_if_err(
         &self,
         hir_id: hir::HirId,
@@ -1741,7 +1808,12 @@ fn overwrite_local_ty_if_err(
             let err = self.tcx.ty_error(guar);
             self.write_ty(hir_id, err);
             self.write_ty(pat.hir_id, err);
-            let local_ty = LocalTy { decl_ty: err, revealed_ty: err };
+            let local_ty = LocalTy {
+                decl_ty: err,
+                revealed_ty: err,
+                origin: LocalTyOrigin::Inference,
+                origin_span: pat.span,
+            };
             self.locals.borrow_mut().insert(hir_id, local_ty);
             self.locals.borrow_mut().insert(pat.hir_id, local_ty);
         }
@@ -111,6 +111,18 @@ pub struct FnCtxt<'a, 'tcx> {
     pub(super) inh: &'a Inherited<'tcx>,
 
     pub(super) fallback_has_occurred: Cell<bool>,
+
+    /// While checking the alternatives of an or-pattern, the type mismatches found in them,
+    /// so they can be reported together once all alternatives have been checked.
+    pub(super) or_pat_mismatches: RefCell<Option<crate::pat::OrPatMismatches<'tcx>>>,
+
+    /// How many blocks, `if` and `match` expressions enclose the expression being checked,
+    /// and the span of the outermost one below the body itself. Used to report overly deep
+    /// nesting instead of spending unbounded time and stack unifying their types.
+    pub(super) coercion_nesting: Cell<(usize, Option<Span>)>,
+
+    /// Set once overly deep nesting has been reported, so that it is only reported once.
+    pub(super) coercion_nesting_error: Cell<Option<ErrorGuaranteed>>,
 }
 
 impl<'a, 'tcx> FnCtxt<'a, 'tcx> {
@@ -133,6 +145,9 @@ pub fn new(
             }),
             inh,
             fallback_has_occurred: Cell::new(false),
+            or_pat_mismatches: RefCell::new(None),
+            coercion_nesting: Cell::new((0, None)),
+            coercion_nesting_error: Cell::new(None),
         }
     }
 
@@ -157,6 +172,7 @@ pub fn err_ctxt(&'a self) -> TypeErrCtxt<'a, 'tcx> {
         TypeErrCtxt {
             infcx: &self.infcx,
             typeck_results: Some(self.typeck_results.borrow()),
+            type_placeholders: Some(self.type_placeholders.borrow()),
             fallback_has_occurred: self.fallback_has_occurred.get(),
             normalize_fn_sig: Box::new(|fn_sig| {
                 if fn_sig.has_escaping_bound_vars() {
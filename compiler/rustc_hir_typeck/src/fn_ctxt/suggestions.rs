@@ -6,7 +6,7 @@
 use rustc_ast::util::parser::{ExprPrecedence, PREC_POSTFIX};
 use rustc_errors::{Applicability, Diagnostic, MultiSpan};
 use rustc_hir as hir;
-use rustc_hir::def::{CtorKind, CtorOf, DefKind};
+use rustc_hir::def::{CtorKind, CtorOf, DefKind, Res};
 use rustc_hir::lang_items::LangItem;
 use rustc_hir::{
     AsyncGeneratorKind, Expr, ExprKind, GeneratorKind, GenericBound, HirId, Node, Path, QPath,
@@ -46,7 +46,8 @@ pub(in super::super) fn suggest_semicolon_at_end(&self, span: Span, err: &mut Di
             "consider using a semicolon here",
             ";",
             Applicability::MaybeIncorrect,
-        );
+        )
+        .suggestion_id("typeck::add_semicolon");
     }
 
     /// On implicit return expressions with mismatched types, provides the following suggestions:
@@ -153,7 +154,8 @@ pub(crate) fn suggest_fn_call(
                 format!("use parentheses to {msg}"),
                 sugg,
                 applicability,
-            );
+            )
+            .suggestion_id("typeck::call_fn");
             return true;
         }
         false
@@ -233,7 +235,8 @@ pub fn suggest_two_fn_call(
                 }
             }
 
-            err.multipart_suggestion_verbose("use parentheses to call these", sugg, applicability);
+            err.multipart_suggestion_verbose("use parentheses to call these", sugg, applicability)
+                .suggestion_id("typeck::call_fns");
 
             true
         } else {
@@ -260,7 +263,8 @@ pub fn suggest_remove_last_method_call(
                     "try removing the method call",
                     "",
                     Applicability::MachineApplicable,
-                );
+                )
+                .suggestion_id("typeck::remove_method_call");
                 return true;
             }
         false
@@ -283,6 +287,7 @@ pub fn suggest_deref_ref_or_into(
             } else {
                 err.multipart_suggestion(msg, suggestion, applicability);
             }
+            err.suggestion_id("typeck::borrow_or_deref");
             if annotation {
                 let suggest_annotation = match expr.peel_drop_temps().kind {
                     hir::ExprKind::AddrOf(hir::BorrowKind::Ref, mutbl, _) => mutbl.ref_prefix_str(),
@@ -316,7 +321,8 @@ pub fn suggest_deref_ref_or_into(
                                     "alternatively, consider changing the type annotation",
                                     suggest_annotation,
                                     Applicability::MaybeIncorrect,
-                                );
+                                )
+                                .suggestion_id("typeck::borrow_in_type_annotation");
                             }
                             break;
                         }
@@ -395,7 +401,8 @@ pub fn suggest_deref_ref_or_into(
                         "try using a conversion method",
                         suggestions,
                         Applicability::MaybeIncorrect,
-                    );
+                    )
+                    .suggestion_id("typeck::conversion_method");
                     return true;
                 }
             } else if let ty::Adt(found_adt, found_substs) = found.kind()
@@ -425,7 +432,8 @@ pub fn suggest_deref_ref_or_into(
                         fluent::hir_typeck_convert_to_str,
                         sugg,
                         Applicability::MachineApplicable,
-                    );
+                    )
+                    .suggestion_id("typeck::option_string_as_str");
                     return true;
                 }
             }
@@ -463,7 +471,11 @@ pub(in super::super) fn suggest_boxing_when_appropriate(
                 }
                 _ => SuggestBoxing::Other { start: span.shrink_to_lo(), end: span.shrink_to_hi() },
             };
+            let has_suggestion = !matches!(suggest_boxing, SuggestBoxing::AsyncBody);
             err.subdiagnostic(suggest_boxing);
+            if has_suggestion {
+                err.suggestion_id("typeck::box_value");
+            }
 
             true
         } else {
@@ -547,7 +559,8 @@ pub(in super::super) fn suggest_calling_boxed_future_when_appropriate(
                                     (expr.span.shrink_to_hi(), ")".to_string()),
                                 ],
                                 Applicability::MaybeIncorrect,
-                            );
+                            )
+                            .suggestion_id("typeck::pin_box");
                         }
                     }
                     true
@@ -584,7 +597,8 @@ pub(in super::super) fn suggest_calling_boxed_future_when_appropriate(
                             "use `Box::pin` to pin and box this expression",
                             "Box::pin",
                             Applicability::MachineApplicable,
-                        );
+                        )
+                        .suggestion_id("typeck::use_box_pin");
                         true
                     }
                     _ => false,
@@ -640,14 +654,16 @@ pub fn suggest_missing_semicolon(
                                 (expression.span.shrink_to_hi(), "; }".to_owned()),
                             ],
                             Applicability::MachineApplicable,
-                        );
+                        )
+                        .suggestion_id("typeck::add_semicolon");
                     } else {
                         err.span_suggestion(
                             expression.span.shrink_to_hi(),
                             "consider using a semicolon here",
                             ";",
                             Applicability::MachineApplicable,
-                        );
+                        )
+                        .suggestion_id("typeck::add_semicolon");
                     }
                 }
                 _ => (),
@@ -690,6 +706,7 @@ pub(in super::super) fn suggest_missing_return_type(
             &hir::FnRetTy::DefaultReturn(span) if expected.is_unit() => {
                 if let Some(found) = found.make_suggestable(self.tcx, false) {
                     err.subdiagnostic(AddReturnTypeSuggestion::Add { span, found: found.to_string() });
+                    err.suggestion_id("typeck::add_return_type");
                     return true;
                 } else if let ty::Closure(_, substs) = found.kind()
                     // FIXME(compiler-errors): Get better at printing binders...
@@ -697,10 +714,12 @@ pub(in super::super) fn suggest_missing_return_type(
                     && closure.sig().is_suggestable(self.tcx, false)
                 {
                     err.subdiagnostic(AddReturnTypeSuggestion::Add { span, found: closure.print_as_impl_trait().to_string() });
+                    err.suggestion_id("typeck::add_return_type");
                     return true;
                 } else {
                     // FIXME: if `found` could be `impl Iterator` we should suggest that.
                     err.subdiagnostic(AddReturnTypeSuggestion::MissingHere { span });
+                    err.suggestion_id("typeck::add_return_type");
                     return true
                 }
             }
@@ -723,6 +742,7 @@ pub(in super::super) fn suggest_missing_return_type(
                     if found.is_suggestable(self.tcx, false) {
                         if term.span.is_empty() {
                             err.subdiagnostic(AddReturnTypeSuggestion::Add { span, found: found.to_string() });
+                            err.suggestion_id("typeck::add_return_type");
                             return true;
                         } else {
                             err.subdiagnostic(ExpectedReturnTypeLabel::Other { span, expected });
@@ -855,7 +875,8 @@ fn try_suggest_return_impl_trait(
             "consider using an impl return type",
             format!("impl {}", all_bounds_str),
             Applicability::MaybeIncorrect,
-        );
+        )
+        .suggestion_id("typeck::return_impl_trait");
     }
 
     pub(in super::super) fn suggest_missing_break_or_return_expr(
@@ -891,7 +912,8 @@ pub(in super::super) fn suggest_missing_break_or_return_expr(
                     (expr.span.shrink_to_hi(), ";".to_string()),
                 ],
                 Applicability::MaybeIncorrect,
-            );
+            )
+            .suggestion_id("typeck::break_with_value");
             return;
         }
 
@@ -914,7 +936,8 @@ pub(in super::super) fn suggest_missing_break_or_return_expr(
                         (expr.span.shrink_to_hi(), ";".to_string()),
                     ],
                     Applicability::MaybeIncorrect,
-                );
+                )
+                .suggestion_id("typeck::return_value");
             }
         }
     }
@@ -927,7 +950,8 @@ pub(in super::super) fn suggest_missing_parentheses(
         let sp = self.tcx.sess.source_map().start_point(expr.span).with_parent(None);
         if let Some(sp) = self.tcx.sess.parse_sess.ambiguous_block_expr_parse.borrow().get(&sp) {
             // `{ 42 } &&x` (#61475) or `{ 42 } && if x { 1 } else { 0 }`
-            err.subdiagnostic(ExprParenthesesNeeded::surrounding(*sp));
+            err.subdiagnostic(ExprParenthesesNeeded::surrounding(*sp))
+                .suggestion_id("typeck::parenthesize_block");
             true
         } else {
             false
@@ -993,7 +1017,8 @@ pub(crate) fn suggest_clone_for_ref(
                 "consider using clone here",
                 suggestion,
                 Applicability::MachineApplicable,
-            );
+            )
+            .suggestion_id("typeck::clone_ref");
             return true;
           }
         false
@@ -1027,7 +1052,8 @@ pub(crate) fn suggest_copied_or_cloned(
                         ),
                         ".copied()",
                         Applicability::MachineApplicable,
-                    );
+                    )
+                    .suggestion_id("typeck::copied");
                     return true;
                 } else if let Some(clone_did) = self.tcx.lang_items().clone_trait()
                     && rustc_trait_selection::traits::type_known_to_meet_bound_modulo_regions(
@@ -1044,7 +1070,8 @@ pub(crate) fn suggest_copied_or_cloned(
                         ),
                         ".cloned()",
                         Applicability::MachineApplicable,
-                    );
+                    )
+                    .suggestion_id("typeck::cloned");
                     return true;
                 }
             }
@@ -1111,7 +1138,8 @@ pub(crate) fn suggest_into(
                 format!("call `Into::into` on this expression to convert `{expr_ty}` into `{expected_ty}`"),
                 sugg,
                 Applicability::MaybeIncorrect
-            );
+            )
+            .suggestion_id("typeck::into");
             return true;
         }
 
@@ -1165,7 +1193,8 @@ pub(crate) fn suggest_option_to_bool(
             "use `Option::is_some` to test if the `Option` has a value",
             suggestion,
             Applicability::MachineApplicable,
-        );
+        )
+        .suggestion_id("typeck::option_is_some");
         true
     }
 
@@ -1203,13 +1232,220 @@ pub(crate) fn suggest_block_to_brackets(
                                 ),
                             ],
                             Applicability::MachineApplicable,
-                        );
+                        )
+                        .suggestion_id("typeck::block_to_array");
                     }
                 }
             }
         }
     }
 
+    /// When the tail of the `then` branch of an `if` without `else` has a value, while the
+    /// `if` itself is the tail of a block that is expected to be `()`, point at the missing
+    /// `else`.
+    pub(crate) fn note_if_without_else_tail(
+        &self,
+        diag: &mut Diagnostic,
+        blk: &hir::Block<'_>,
+        blk_ty: Ty<'tcx>,
+        expected_ty: Ty<'tcx>,
+    ) {
+        if !expected_ty.is_unit() || self.resolve_vars_if_possible(blk_ty).is_unit() {
+            return;
+        }
+        let hir = self.tcx.hir();
+        if let hir::Node::Expr(then_expr) = hir.get_parent(blk.hir_id)
+            && let hir::Node::Expr(if_expr @ hir::Expr {
+                kind: hir::ExprKind::If(_, then, None),
+                ..
+            }) = hir.get_parent(then_expr.hir_id)
+            && then.hir_id == then_expr.hir_id
+            && if_expr.span.desugaring_kind().is_none()
+            && let hir::Node::Block(parent_blk) = hir.get_parent(if_expr.hir_id)
+            && parent_blk.expr.is_some_and(|tail| tail.hir_id == if_expr.hir_id)
+        {
+            diag.span_label(
+                then.span.shrink_to_hi(),
+                "`if` expressions without `else` evaluate to `()`",
+            );
+            diag.help(
+                "consider adding an `else` block if this `if` is meant to produce a value, \
+                 or end its tail expression with a `;` to discard the value",
+            );
+        }
+    }
+
+    /// When a `for` or `while` loop is used where a value other than `()` is expected, explain
+    /// that only `loop` can evaluate to a value.
+    pub(crate) fn note_loop_value_is_unit(
+        &self,
+        err: &mut Diagnostic,
+        expr: &hir::Expr<'_>,
+        expr_ty: Ty<'tcx>,
+        expected_ty: Ty<'tcx>,
+    ) {
+        let hir::ExprKind::Loop(_, _, source, _) = expr.kind else { return };
+        let kind = match source {
+            hir::LoopSource::While => "while",
+            hir::LoopSource::ForLoop => "for",
+            hir::LoopSource::Loop => return,
+        };
+        if !self.resolve_vars_if_possible(expr_ty).is_unit() || expected_ty.is_unit() {
+            return;
+        }
+        err.note(format!("`{kind}` loops evaluate to unit type `()`"));
+        // `for` loops are lowered to `DropTemps(match $head { iter => loop { .. } })`.
+        let hir = self.tcx.hir();
+        let loop_id = match source {
+            hir::LoopSource::ForLoop => hir.parent_iter(expr.hir_id).nth(2).map(|(id, _)| id),
+            _ => Some(expr.hir_id),
+        };
+        // At the tail of a block, we already suggest adding a value after the loop.
+        let is_tail = loop_id.is_some_and(|loop_id| {
+            matches!(
+                hir.get_parent(loop_id),
+                hir::Node::Block(blk) if blk.expr.is_some_and(|tail| tail.hir_id == loop_id)
+            )
+        });
+        if !is_tail {
+            err.help(
+                "consider using a `loop` instead, which can evaluate to a value with `break value`",
+            );
+        }
+    }
+
+    /// When a `match` in statement position has arms that produce a value, point at the first
+    /// of those arms and suggest binding the result of the `match`, or making it the tail
+    /// expression of the function body.
+    pub(crate) fn suggest_statement_match_arm_values(
+        &self,
+        err: &mut Diagnostic,
+        expr: &hir::Expr<'_>,
+    ) {
+        let hir::ExprKind::Match(_, arms, hir::MatchSource::Normal) = expr.kind else { return };
+        if expr.span.from_expansion() {
+            return;
+        }
+        let hir = self.tcx.hir();
+        let hir::Node::Stmt(stmt) = hir.get_parent(expr.hir_id) else { return };
+        if !matches!(stmt.kind, hir::StmtKind::Expr(e) if e.hir_id == expr.hir_id) {
+            return;
+        }
+        // In `match () { () => 1 } + ..`, the parser ended the statement after the `match`,
+        // but it was likely meant to be the left-hand side of a binary operation.
+        if self
+            .tcx
+            .sess
+            .parse_sess
+            .ambiguous_block_expr_parse
+            .borrow()
+            .values()
+            .any(|sp| sp.source_equal(expr.span))
+        {
+            return;
+        }
+        // The arms are coerced to a common type, but each arm body keeps the type it was
+        // checked with, which tells us which of them actually produce a value.
+        let value_arms: Vec<_> = {
+            let typeck_results = self.typeck_results.borrow();
+            arms.iter()
+                .filter_map(|arm| {
+                    let ty = self.resolve_vars_if_possible(typeck_results.expr_ty_opt(arm.body)?);
+                    (!ty.is_unit() && !ty.is_never() && !ty.references_error())
+                        .then_some((arm.body, ty))
+                })
+                .collect()
+        };
+        let Some(&(first_body, first_ty)) = value_arms.first() else { return };
+        err.span_label(
+            first_body.span,
+            format!("this arm evaluates to a value of type `{first_ty}`"),
+        );
+
+        err.multipart_suggestion_verbose(
+            "consider binding the result of the `match` to a variable",
+            vec![
+                (expr.span.shrink_to_lo(), "let value = ".to_string()),
+                (expr.span.shrink_to_hi(), ";".to_string()),
+            ],
+            Applicability::HasPlaceholders,
+        )
+        .suggestion_id("typeck::bind_match_result");
+
+        // If the `match` is a statement of a function body without a tail expression and its
+        // arms can be returned, it may have been meant to be the tail expression.
+        if let hir::Node::Block(blk) = hir.get_parent(stmt.hir_id)
+            && blk.expr.is_none()
+            && let Some(body_id) = hir.maybe_body_owned_by(self.body_id)
+            && let hir::ExprKind::Block(body_blk, _) = hir.body(body_id).value.kind
+            && body_blk.hir_id == blk.hir_id
+            && let Some(ret_coercion) = &self.ret_coercion
+        {
+            let ret_ty = self.resolve_vars_if_possible(ret_coercion.borrow().expected_ty());
+            if !ret_ty.is_unit()
+                && !matches!(ret_ty.kind(), ty::Alias(ty::Opaque, ..))
+                && value_arms.iter().all(|&(_, ty)| self.can_coerce(ty, ret_ty))
+            {
+                err.help(format!(
+                    "if the `match` is meant to return a value of type `{ret_ty}`, move it to \
+                     the end of the function body to make it the tail expression"
+                ));
+            }
+        }
+    }
+
+    /// When `-Zmethod-chain-types` is enabled and a chain of method calls ends in a type
+    /// mismatch, list the receiver and output types of each call in the chain, so that it is
+    /// visible where the chain started producing something other than what was expected.
+    pub(crate) fn note_method_chain_types(&self, err: &mut Diagnostic, expr: &hir::Expr<'_>) {
+        const MAX_CALLS: usize = 8;
+
+        if !self.tcx.sess.opts.unstable_opts.method_chain_types {
+            return;
+        }
+        let mut calls = vec![];
+        let mut current = expr;
+        while let hir::ExprKind::MethodCall(segment, receiver, _, _) = current.kind {
+            calls.push((segment, receiver, current));
+            current = receiver;
+        }
+        if calls.len() < 2 {
+            return;
+        }
+        calls.reverse();
+
+        let typeck_results = self.typeck_results.borrow();
+        let mut ty_file = None;
+        let mut short_ty_string = |hir_id| {
+            let ty = typeck_results.node_type_opt(hir_id)?;
+            let ty = self.resolve_vars_if_possible(ty);
+            if ty.references_error() {
+                return None;
+            }
+            let (ty_str, file) = self.tcx.short_ty_string(ty);
+            if file.is_some() {
+                ty_file = file;
+            }
+            Some(ty_str)
+        };
+        let mut lines = vec![];
+        for (segment, receiver, call) in calls.iter().take(MAX_CALLS) {
+            let (Some(rcvr_ty), Some(output_ty)) =
+                (short_ty_string(receiver.hir_id), short_ty_string(call.hir_id))
+            else {
+                return;
+            };
+            lines.push(format!("`.{}()`: `{rcvr_ty}` -> `{output_ty}`", segment.ident));
+        }
+        if calls.len() > MAX_CALLS {
+            lines.push(format!("and {} more", calls.len() - MAX_CALLS));
+        }
+        err.note(format!("the types along this method chain are:\n{}", lines.join("\n")));
+        if let Some(file) = ty_file {
+            err.note(format!("the full type name has been written to '{}'", file.display()));
+        }
+    }
+
     #[instrument(skip(self, err))]
     pub(crate) fn suggest_floating_point_literal(
         &self,
@@ -1227,7 +1463,8 @@ pub(crate) fn suggest_floating_point_literal(
                     "remove the unnecessary `.` operator for a floating point literal",
                     '.',
                     Applicability::MaybeIncorrect,
-                );
+                )
+                .suggestion_id("typeck::float_literal_remove_dot");
                 true
             }
             ExprKind::Struct(QPath::LangItem(LangItem::RangeFrom, ..), [start], _) => {
@@ -1236,7 +1473,8 @@ pub(crate) fn suggest_floating_point_literal(
                     "remove the unnecessary `.` operator for a floating point literal",
                     '.',
                     Applicability::MaybeIncorrect,
-                );
+                )
+                .suggestion_id("typeck::float_literal_remove_dot");
                 true
             }
             ExprKind::Struct(QPath::LangItem(LangItem::RangeTo, ..), [end], _) => {
@@ -1245,7 +1483,8 @@ pub(crate) fn suggest_floating_point_literal(
                     "remove the unnecessary `.` operator and add an integer part for a floating point literal",
                     "0.",
                     Applicability::MaybeIncorrect,
-                );
+                )
+                .suggestion_id("typeck::float_literal_remove_dot");
                 true
             }
             ExprKind::Lit(Spanned {
@@ -1270,7 +1509,8 @@ pub(crate) fn suggest_floating_point_literal(
                     "rewrite this as a decimal floating point literal, or use `as` to turn a hex literal into a float",
                     [format!("0x{value:X} as {suffix}"), format!("{value}_{suffix}")],
                     Applicability::MaybeIncorrect,
-                );
+                )
+                .suggestion_id("typeck::hex_float_literal");
                 true
             }
             _ => false,
@@ -1315,7 +1555,8 @@ pub(crate) fn suggest_null_ptr_for_literal_zero_given_to_ptr_arg(
             format!("if you meant to create a null pointer, use `{null_path_str}()`"),
             null_path_str + "()",
             Applicability::MachineApplicable,
-        );
+        )
+        .suggestion_id("typeck::null_ptr");
 
         true
     }
@@ -1393,7 +1634,8 @@ pub(crate) fn suggest_associated_const(
                 format!("try referring to the associated const `{capitalized_name}` instead",),
                 capitalized_name,
                 Applicability::MachineApplicable,
-            );
+            )
+            .suggestion_id("typeck::associated_const");
             true
         } else {
             false
@@ -1470,6 +1712,64 @@ pub(crate) fn note_type_is_not_clone(
         }
     }
 
+    /// Point out that a binding has a reference type because of default binding modes, i.e.
+    /// because the pattern introducing it matched on a reference without an explicit `&`, and
+    /// suggest matching on that reference explicitly so the binding is bound by value.
+    pub(crate) fn note_implicit_ref_binding(
+        &self,
+        diag: &mut Diagnostic,
+        expr: &hir::Expr<'_>,
+        found_ty: Ty<'tcx>,
+        expected_ty: Ty<'tcx>,
+    ) {
+        let ty::Ref(_, pointee_ty, _) = *found_ty.kind() else { return };
+        if !self.can_eq(self.param_env, pointee_ty, expected_ty) {
+            return;
+        }
+        let ExprKind::Path(QPath::Resolved(None, Path { res: Res::Local(binding_id), .. })) =
+            expr.kind else { return };
+        let hir = self.tcx.hir();
+        let Some(Node::Pat(binding)) = hir.find(*binding_id) else { return };
+        // Only bindings without an explicit `ref` get their binding mode from the pattern.
+        let hir::PatKind::Binding(hir::BindingAnnotation(hir::ByRef::No, _), _, ident, _) =
+            binding.kind else { return };
+        let results = self.typeck_results.borrow();
+        let Some(ty::BindByReference(_)) = results.pat_binding_modes().get(binding.hir_id) else {
+            return;
+        };
+        // The innermost enclosing pattern that was implicitly dereferenced is the one that
+        // switched the default binding mode to `ref`.
+        let Some((ref_pat, peeled)) = hir
+            .parent_iter(binding.hir_id)
+            .map_while(|(_, node)| if let Node::Pat(pat) = node { Some(pat) } else { None })
+            .find_map(|pat| {
+                let peeled = results.pat_adjustments().get(pat.hir_id)?;
+                (!peeled.is_empty()).then_some((pat, peeled))
+            })
+        else {
+            return;
+        };
+
+        diag.span_note(
+            binding.span,
+            format!("`{ident}` is bound by reference because its pattern matches on a reference"),
+        );
+        let prefix: String = peeled
+            .iter()
+            .map(|ty| match ty.kind() {
+                ty::Ref(_, _, hir::Mutability::Mut) => "&mut ",
+                _ => "&",
+            })
+            .collect();
+        diag.span_suggestion_verbose(
+            ref_pat.span.shrink_to_lo(),
+            format!("consider matching on the reference explicitly to bind `{ident}` by value"),
+            prefix,
+            Applicability::MaybeIncorrect,
+        )
+        .suggestion_id("typeck::match_on_reference");
+    }
+
     /// A common error is to add an extra semicolon:
     ///
     /// ```compile_fail,E0308
@@ -1494,14 +1794,16 @@ pub(crate) fn consider_removing_semicolon(
                     "consider removing this semicolon and boxing the expression",
                     "",
                     Applicability::HasPlaceholders,
-                );
+                )
+                .suggestion_id("typeck::remove_semicolon_and_box");
             } else {
                 err.span_suggestion_short(
                     span_semi,
                     "remove this semicolon to return this value",
                     "",
                     Applicability::MachineApplicable,
-                );
+                )
+                .suggestion_id("typeck::remove_semicolon");
             }
             true
         } else {
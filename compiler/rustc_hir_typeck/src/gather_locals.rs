@@ -1,4 +1,4 @@
-use crate::{FnCtxt, LocalTy};
+use crate::{FnCtxt, LocalTy, LocalTyOrigin};
 use rustc_hir as hir;
 use rustc_hir::intravisit::{self, Visitor};
 use rustc_hir::PatKind;
@@ -56,10 +56,15 @@ fn assign(&mut self, span: Span, nid: hir::HirId, ty_opt: Option<LocalTy<'tcx>>)
                     kind: TypeVariableOriginKind::TypeInference,
                     span,
                 });
-                self.fcx
-                    .locals
-                    .borrow_mut()
-                    .insert(nid, LocalTy { decl_ty: var_ty, revealed_ty: var_ty });
+                self.fcx.locals.borrow_mut().insert(
+                    nid,
+                    LocalTy {
+                        decl_ty: var_ty,
+                        revealed_ty: var_ty,
+                        origin: LocalTyOrigin::Inference,
+                        origin_span: span,
+                    },
+                );
                 var_ty
             }
             Some(typ) => {
@@ -77,6 +82,7 @@ fn declare(&mut self, decl: Declaration<'tcx>) {
         let local_ty = match decl.ty {
             Some(ref ty) => {
                 let o_ty = self.fcx.to_ty(&ty);
+                self.fcx.record_type_placeholders(ty, o_ty.raw);
 
                 let c_ty =
                     self.fcx.inh.infcx.canonicalize_user_type_annotation(UserType::Ty(o_ty.raw));
@@ -87,7 +93,12 @@ fn declare(&mut self, decl: Declaration<'tcx>) {
                     .user_provided_types_mut()
                     .insert(ty.hir_id, c_ty);
 
-                Some(LocalTy { decl_ty: o_ty.normalized, revealed_ty: o_ty.normalized })
+                Some(LocalTy {
+                    decl_ty: o_ty.normalized,
+                    revealed_ty: o_ty.normalized,
+                    origin: LocalTyOrigin::Annotation,
+                    origin_span: ty.span,
+                })
             }
             None => None,
         };
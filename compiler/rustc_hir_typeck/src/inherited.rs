@@ -1,6 +1,7 @@
 use super::callee::DeferredCallResolution;
 
-use rustc_data_structures::fx::{FxHashMap, FxHashSet};
+use rustc_data_structures::fx::{FxHashMap, FxHashSet, FxIndexMap};
+use rustc_errors::Diagnostic;
 use rustc_hir as hir;
 use rustc_hir::def_id::LocalDefId;
 use rustc_hir::HirIdMap;
@@ -8,11 +9,11 @@
 use rustc_middle::ty::visit::TypeVisitableExt;
 use rustc_middle::ty::{self, Ty, TyCtxt};
 use rustc_span::def_id::LocalDefIdMap;
-use rustc_span::{self, Span};
+use rustc_span::{self, Span, Symbol};
 use rustc_trait_selection::traits::query::evaluate_obligation::InferCtxtExt;
 use rustc_trait_selection::traits::{self, PredicateObligation, TraitEngine, TraitEngineExt as _};
 
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::ops::Deref;
 
 /// Closures defined within the function. For example:
@@ -33,11 +34,55 @@ pub struct Inherited<'tcx> {
 
     pub(super) fulfillment_cx: RefCell<Box<dyn TraitEngine<'tcx>>>,
 
+    /// Work that has to wait until the rest of the body has been type-checked.
+    pub(super) deferred: RefCell<DeferredWork<'tcx>>,
+
+    pub(super) deferred_transmute_checks: RefCell<Vec<(Ty<'tcx>, Ty<'tcx>, hir::HirId)>>,
+
+    pub(super) deferred_asm_checks: RefCell<Vec<(&'tcx hir::InlineAsm<'tcx>, hir::HirId)>>,
+
+    /// Whenever we introduce an adjustment from `!` into a type variable,
+    /// we record that type variable here. This is later used to inform
+    /// fallback. See the `fallback` module for details.
+    pub(super) diverging_type_vars: RefCell<FxHashSet<Ty<'tcx>>>,
+
+    pub(super) infer_var_info: RefCell<FxHashMap<ty::TyVid, ty::InferVarInfo>>,
+
+    /// The `_` placeholders in type annotations written by the user (e.g. `let x: Vec<_>`),
+    /// along with the inference variables they were lowered to, so that inference failures
+    /// can point at the placeholder that remained unconstrained. See `FnCtxt::err_ctxt`.
+    pub(super) type_placeholders: RefCell<Vec<(Span, Ty<'tcx>)>>,
+
+    /// User-written types that a WF obligation has already been registered for, so that
+    /// repeated annotations (e.g. the same turbofish in every expansion of a macro) don't
+    /// register the same obligation again. See `FnCtxt::to_ty`.
+    pub(super) wf_checked_tys: RefCell<FxHashSet<Ty<'tcx>>>,
+
+    /// The number of WF obligations that were skipped thanks to `wf_checked_tys`,
+    /// logged at writeback.
+    pub(super) skipped_wf_obligations: Cell<u64>,
+
+    /// Errors held back because they point into the expansion of a bang macro, keyed by
+    /// their rendered message and where they point in the macro definition, together with
+    /// the name of the macro and how often they were repeated in other expansions.
+    /// See `FnCtxt::emit_once_per_macro_expansion`.
+    pub(super) macro_expansion_errors:
+        RefCell<FxIndexMap<(Vec<String>, Span), (Diagnostic, Symbol, usize)>>,
+}
+
+/// The queues of work deferred until later in typeck of a body.
+///
+/// The queues are processed at different points: cast checks after fallback,
+/// call resolutions of a closure once upvar analysis has decided its kind,
+/// sized obligations after closure analysis, and generator interiors last.
+/// Each queue is taken out wholesale when it is processed, so no borrow is
+/// held while the deferred work itself runs and may push more work.
+#[derive(Default)]
+pub(super) struct DeferredWork<'tcx> {
     /// Some additional `Sized` obligations badly affect type inference.
     /// These obligations are added in a later stage of typeck.
     /// Removing these may also cause additional complications, see #101066.
-    pub(super) deferred_sized_obligations:
-        RefCell<Vec<(Ty<'tcx>, Span, traits::ObligationCauseCode<'tcx>)>>,
+    pub(super) sized_obligations: Vec<(Ty<'tcx>, Span, traits::ObligationCauseCode<'tcx>)>,
 
     /// When we process a call like `c()` where `c` is a closure type,
     /// we may not have decided yet whether `c` is a `Fn`, `FnMut`, or
@@ -46,23 +91,11 @@ pub struct Inherited<'tcx> {
     /// decision. We keep these deferred resolutions grouped by the
     /// def-id of the closure, so that once we decide, we can easily go
     /// back and process them.
-    pub(super) deferred_call_resolutions: RefCell<LocalDefIdMap<Vec<DeferredCallResolution<'tcx>>>>,
-
-    pub(super) deferred_cast_checks: RefCell<Vec<super::cast::CastCheck<'tcx>>>,
-
-    pub(super) deferred_transmute_checks: RefCell<Vec<(Ty<'tcx>, Ty<'tcx>, hir::HirId)>>,
-
-    pub(super) deferred_asm_checks: RefCell<Vec<(&'tcx hir::InlineAsm<'tcx>, hir::HirId)>>,
+    pub(super) call_resolutions: LocalDefIdMap<Vec<DeferredCallResolution<'tcx>>>,
 
-    pub(super) deferred_generator_interiors:
-        RefCell<Vec<(LocalDefId, hir::BodyId, Ty<'tcx>, hir::GeneratorKind)>>,
+    pub(super) cast_checks: Vec<super::cast::CastCheck<'tcx>>,
 
-    /// Whenever we introduce an adjustment from `!` into a type variable,
-    /// we record that type variable here. This is later used to inform
-    /// fallback. See the `fallback` module for details.
-    pub(super) diverging_type_vars: RefCell<FxHashSet<Ty<'tcx>>>,
-
-    pub(super) infer_var_info: RefCell<FxHashMap<ty::TyVid, ty::InferVarInfo>>,
+    pub(super) generator_interiors: Vec<(LocalDefId, hir::BodyId, Ty<'tcx>, hir::GeneratorKind)>,
 }
 
 impl<'tcx> Deref for Inherited<'tcx> {
@@ -88,14 +121,15 @@ pub fn new(tcx: TyCtxt<'tcx>, def_id: LocalDefId) -> Self {
             infcx,
             fulfillment_cx: RefCell::new(<dyn TraitEngine<'_>>::new(tcx)),
             locals: RefCell::new(Default::default()),
-            deferred_sized_obligations: RefCell::new(Vec::new()),
-            deferred_call_resolutions: RefCell::new(Default::default()),
-            deferred_cast_checks: RefCell::new(Vec::new()),
+            deferred: RefCell::new(Default::default()),
             deferred_transmute_checks: RefCell::new(Vec::new()),
             deferred_asm_checks: RefCell::new(Vec::new()),
-            deferred_generator_interiors: RefCell::new(Vec::new()),
             diverging_type_vars: RefCell::new(Default::default()),
             infer_var_info: RefCell::new(Default::default()),
+            type_placeholders: RefCell::new(Vec::new()),
+            wf_checked_tys: RefCell::new(Default::default()),
+            skipped_wf_obligations: Cell::new(0),
+            macro_expansion_errors: RefCell::new(Default::default()),
         }
     }
 
@@ -51,7 +51,7 @@
 
 use crate::check::check_fn;
 use crate::coercion::DynamicCoerceMany;
-use crate::diverges::Diverges;
+use crate::diverges::{Diverges, UnreachableKind};
 use crate::expectation::Expectation;
 use crate::fn_ctxt::RawTy;
 use crate::gather_locals::GatherLocalsVisitor;
@@ -95,6 +95,22 @@ macro_rules! type_error_struct {
 pub struct LocalTy<'tcx> {
     decl_ty: Ty<'tcx>,
     revealed_ty: Ty<'tcx>,
+    /// Where `decl_ty` came from.
+    origin: LocalTyOrigin,
+    /// The span of the annotation or initializer the type came from, or of the declaration
+    /// itself if it was inferred from the uses of the binding.
+    origin_span: Span,
+}
+
+/// Where the type of a local binding came from, used to explain mismatches on its uses.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LocalTyOrigin {
+    /// `let x: T`
+    Annotation,
+    /// `let x = init`
+    Initializer,
+    /// `let x;`, or a binding in a larger pattern.
+    Inference,
 }
 
 /// If this `DefId` is a "primary tables entry", returns
@@ -263,6 +279,8 @@ fn typeck_with_fallback<'tcx>(
         fcx.write_ty(id, expected_type);
     };
 
+    fcx.emit_macro_expansion_errors();
+
     fcx.type_inference_fallback();
 
     // Even though coercion casts provide type hints, we check casts after fallback for
@@ -277,12 +295,13 @@ fn typeck_with_fallback<'tcx>(
     fcx.param_env = fcx.param_env.without_const();
     fcx.closure_analyze(body);
     fcx.param_env = fcx.param_env.with_constness(prev_constness);
-    assert!(fcx.deferred_call_resolutions.borrow().is_empty());
+    assert!(fcx.deferred.borrow().call_resolutions.is_empty());
     // Before the generator analysis, temporary scopes shall be marked to provide more
     // precise information on types to be captured.
     fcx.resolve_rvalue_scopes(def_id.to_def_id());
 
-    for (ty, span, code) in fcx.deferred_sized_obligations.borrow_mut().drain(..) {
+    let sized_obligations = std::mem::take(&mut fcx.deferred.borrow_mut().sized_obligations);
+    for (ty, span, code) in sized_obligations {
         let ty = fcx.normalize(span, ty);
         fcx.require_type_is_sized(ty, span, code);
     }
@@ -361,6 +380,10 @@ pub struct BreakableCtxt<'tcx> {
     // this is `null` for loops where break with a value is illegal,
     // such as `while`, `for`, and `while let`
     coerce: Option<DynamicCoerceMany<'tcx>>,
+
+    // the spans of the values of the `break`s that were coerced into `coerce`
+    // so far, to point at them when a later `break` has a mismatched type
+    break_value_spans: Vec<Span>,
 }
 
 pub struct EnclosingBreakables<'tcx> {
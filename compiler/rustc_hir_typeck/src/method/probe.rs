@@ -1789,24 +1789,23 @@ fn probe_for_similar_candidate(&mut self) -> Result<Option<ty::AssocItem>, Metho
             if applicable_close_candidates.is_empty() {
                 Ok(None)
             } else {
-                let best_name = {
-                    let names = applicable_close_candidates
-                        .iter()
-                        .map(|cand| cand.name)
-                        .collect::<Vec<Symbol>>();
-                    find_best_match_for_name_with_substrings(
-                        &names,
-                        self.method_name.unwrap().name,
-                        None,
-                    )
-                }
-                .or_else(|| {
-                    applicable_close_candidates
-                        .iter()
-                        .find(|cand| self.matches_by_doc_alias(cand.def_id))
-                        .map(|cand| cand.name)
-                })
-                .unwrap();
+                // An exact doc alias match is a stronger hint than a similar name.
+                let best_name = applicable_close_candidates
+                    .iter()
+                    .find(|cand| self.matches_by_doc_alias(cand.def_id))
+                    .map(|cand| cand.name)
+                    .or_else(|| {
+                        let names = applicable_close_candidates
+                            .iter()
+                            .map(|cand| cand.name)
+                            .collect::<Vec<Symbol>>();
+                        find_best_match_for_name_with_substrings(
+                            &names,
+                            self.method_name.unwrap().name,
+                            None,
+                        )
+                    })
+                    .unwrap();
                 Ok(applicable_close_candidates.into_iter().find(|method| method.name == best_name))
             }
         })
@@ -1940,32 +1939,7 @@ fn is_relevant_kind_for_mode(&self, kind: ty::AssocKind) -> bool {
     /// the desired name via a doc alias.
     fn matches_by_doc_alias(&self, def_id: DefId) -> bool {
         let Some(name) = self.method_name else { return false; };
-        let Some(local_def_id) = def_id.as_local() else { return false; };
-        let hir_id = self.fcx.tcx.hir().local_def_id_to_hir_id(local_def_id);
-        let attrs = self.fcx.tcx.hir().attrs(hir_id);
-        for attr in attrs {
-            let sym::doc = attr.name_or_empty() else { continue; };
-            let Some(values) = attr.meta_item_list() else { continue; };
-            for v in values {
-                if v.name_or_empty() != sym::alias {
-                    continue;
-                }
-                if let Some(nested) = v.meta_item_list() {
-                    // #[doc(alias("foo", "bar"))]
-                    for n in nested {
-                        if let Some(lit) = n.lit() && name.as_str() == lit.symbol.as_str() {
-                            return true;
-                        }
-                    }
-                } else if let Some(meta) = v.meta_item()
-                    && let Some(lit) = meta.name_value_literal()
-                    && name.as_str() == lit.symbol.as_str() {
-                        // #[doc(alias = "foo")]
-                        return true;
-                }
-            }
-        }
-        false
+        has_doc_alias(self.tcx, def_id, name.name)
     }
 
     /// Finds the method with the appropriate name (or return type, as the case may be). If
@@ -2048,3 +2022,31 @@ fn to_unadjusted_pick(
         }
     }
 }
+
+/// Determine if the item with the given DefId has `name` as a doc alias, either
+/// as `#[doc(alias = "name")]` or as `#[doc(alias("name", ..))]`. Doc attributes
+/// are kept in crate metadata, so this also works for items of other crates.
+pub(crate) fn has_doc_alias(tcx: TyCtxt<'_>, def_id: DefId, name: Symbol) -> bool {
+    for attr in tcx.get_attrs(def_id, sym::doc) {
+        let Some(values) = attr.meta_item_list() else { continue; };
+        for v in values {
+            if v.name_or_empty() != sym::alias {
+                continue;
+            }
+            if let Some(nested) = v.meta_item_list() {
+                // #[doc(alias("foo", "bar"))]
+                for n in nested {
+                    if let Some(lit) = n.lit() && name == lit.symbol {
+                        return true;
+                    }
+                }
+            } else if let Some(meta) = v.meta_item()
+                && let Some(lit) = meta.name_value_literal()
+                && name == lit.symbol {
+                    // #[doc(alias = "foo")]
+                    return true;
+            }
+        }
+    }
+    false
+}
@@ -14,7 +14,7 @@
     MultiSpan,
 };
 use rustc_hir as hir;
-use rustc_hir::def::DefKind;
+use rustc_hir::def::{CtorKind, DefKind};
 use rustc_hir::def_id::DefId;
 use rustc_hir::lang_items::LangItem;
 use rustc_hir::PatKind::Binding;
@@ -42,7 +42,7 @@
 };
 use std::borrow::Cow;
 
-use super::probe::{AutorefOrPtrAdjustment, IsSuggestion, Mode, ProbeScope};
+use super::probe::{has_doc_alias, AutorefOrPtrAdjustment, IsSuggestion, Mode, ProbeScope};
 use super::{CandidateSource, MethodError, NoMatchData};
 use rustc_hir::intravisit::Visitor;
 use std::cmp::{self, Ordering};
@@ -435,6 +435,9 @@ pub fn report_no_match_method_error(
                 "using `<*const T>::as_ref()` on a pointer which is unaligned or points \
                  to invalid or uninitialized memory is undefined behavior",
             );
+            if let Mode::MethodCall = mode && let SelfSource::MethodCall(rcvr_expr) = source {
+                self.suggest_deref_raw_ptr_receiver(&mut err, item_name, rcvr_ty, rcvr_expr);
+            }
         }
 
         let ty_span = match rcvr_ty.kind() {
@@ -1065,6 +1068,9 @@ trait bound{s}",
         }
 
         self.suggest_unwrapping_inner_self(&mut err, source, rcvr_ty, item_name);
+        if unsatisfied_predicates.is_empty() {
+            self.suggest_matching_on_enum_variants(&mut err, source, rcvr_ty, item_name);
+        }
 
         bound_spans.sort();
         bound_spans.dedup();
@@ -1130,21 +1136,35 @@ trait bound{s}",
                 // which represents the instance of the struct the method is being called on
                 // Associated functions don’t take self as a parameter and
                 // they are not methods because they don’t have an instance of the struct to work with.
-                if def_kind == DefKind::AssocFn && similar_candidate.fn_has_self_parameter {
+                let (article, descr) =
+                    if def_kind == DefKind::AssocFn && similar_candidate.fn_has_self_parameter {
+                        ("a", "method")
+                    } else {
+                        (
+                            self.tcx.def_kind_descr_article(def_kind, similar_candidate.def_id),
+                            self.tcx.def_kind_descr(def_kind, similar_candidate.def_id),
+                        )
+                    };
+                if let Some(renamed) =
+                    self.renamed_replacement(similar_candidate, rcvr_ty, source, mode, span)
+                {
+                    err.span_suggestion(
+                        span,
+                        format!("{descr} `{}` has been renamed", similar_candidate.name),
+                        renamed.name,
+                        Applicability::MaybeIncorrect,
+                    );
+                } else if has_doc_alias(self.tcx, similar_candidate.def_id, item_name.name) {
                     err.span_suggestion(
                         span,
-                        "there is a method with a similar name",
+                        format!("there is {article} {descr} with the doc alias `{item_name}`"),
                         similar_candidate.name,
                         Applicability::MaybeIncorrect,
                     );
                 } else {
                     err.span_suggestion(
                         span,
-                        format!(
-                            "there is {} {} with a similar name",
-                            self.tcx.def_kind_descr_article(def_kind, similar_candidate.def_id),
-                            self.tcx.def_kind_descr(def_kind, similar_candidate.def_id)
-                        ),
+                        format!("there is {article} {descr} with a similar name"),
                         similar_candidate.name,
                         Applicability::MaybeIncorrect,
                     );
@@ -1156,6 +1176,36 @@ trait bound{s}",
         return Some(err);
     }
 
+    /// If `item` is deprecated with `#[deprecated(suggestion = "new_name")]` and an
+    /// associated item of that name can be used on `rcvr_ty`, return it.
+    fn renamed_replacement(
+        &self,
+        item: ty::AssocItem,
+        rcvr_ty: Ty<'tcx>,
+        source: SelfSource<'tcx>,
+        mode: Mode,
+        span: Span,
+    ) -> Option<ty::AssocItem> {
+        let deprecation = self.tcx.lookup_deprecation(item.def_id)?;
+        let new_name = deprecation.suggestion?;
+        let scope_expr_id = match source {
+            SelfSource::QPath(ty) => ty.hir_id,
+            SelfSource::MethodCall(rcvr) => rcvr.hir_id,
+        };
+        let pick = self
+            .probe_for_name(
+                mode,
+                Ident::new(new_name, span),
+                None,
+                IsSuggestion(true),
+                rcvr_ty,
+                scope_expr_id,
+                ProbeScope::TraitsInScope,
+            )
+            .ok()?;
+        (pick.item.def_id != item.def_id && pick.item.kind == item.kind).then_some(pick.item)
+    }
+
     fn note_candidates_on_method_error(
         &self,
         rcvr_ty: Ty<'tcx>,
@@ -2195,6 +2245,161 @@ pub fn suggest_derive(
         }
     }
 
+    /// When a method is called on an enum that doesn't have it, but the data of every
+    /// variant has a method or a field with that name, point at the data of each variant
+    /// and suggest matching on the enum to reach it.
+    fn suggest_matching_on_enum_variants(
+        &self,
+        err: &mut Diagnostic,
+        source: SelfSource<'tcx>,
+        rcvr_ty: Ty<'tcx>,
+        item_name: Ident,
+    ) {
+        let tcx = self.tcx;
+        let SelfSource::MethodCall(rcvr_expr) = source else { return };
+        let call_expr = tcx.hir().expect_expr(tcx.hir().parent_id(rcvr_expr.hir_id));
+        let hir::ExprKind::MethodCall(_, _, args, _) = call_expr.kind else { return };
+        let ty::Adt(adt, substs) = *rcvr_ty.peel_refs().kind() else { return };
+        if !adt.is_enum()
+            || adt.variants().is_empty()
+            || tcx.is_diagnostic_item(sym::Option, adt.did())
+            || tcx.is_diagnostic_item(sym::Result, adt.did())
+        {
+            return;
+        }
+
+        // The data of every variant must be a single field with the method, or a
+        // field with the name of the method.
+        let methods: Option<Vec<_>> = adt
+            .variants()
+            .iter()
+            .map(|variant| {
+                let [field] = &variant.fields.raw[..] else { return None };
+                let field_ty = self.resolve_vars_if_possible(field.ty(tcx, substs));
+                if field_ty.is_ty_var() {
+                    return None;
+                }
+                self.lookup_probe_for_diagnostic(
+                    item_name,
+                    field_ty,
+                    call_expr,
+                    ProbeScope::TraitsInScope,
+                    None,
+                )
+                .ok()
+                .map(|pick| (variant, field, pick))
+            })
+            .collect();
+        let fields: Option<Vec<_>> = adt
+            .variants()
+            .iter()
+            .map(|variant| {
+                let index = tcx.find_field_index(item_name, variant)?;
+                Some((variant, &variant.fields[index]))
+            })
+            .collect();
+
+        let methods = methods.filter(|methods| methods.len() > 1);
+        let (msg, data) = if let Some(methods) = &methods {
+            (
+                format!(
+                    "the method `{item_name}` exists on the data of every variant of `{}`",
+                    tcx.def_path_str(adt.did())
+                ),
+                methods.iter().map(|&(variant, field, _)| (variant, field)).collect::<Vec<_>>(),
+            )
+        } else if let Some(fields) = fields {
+            (
+                format!(
+                    "every variant of `{}` has a field named `{item_name}`",
+                    tcx.def_path_str(adt.did())
+                ),
+                fields,
+            )
+        } else {
+            return;
+        };
+        let is_method = methods.is_some();
+        err.span_note(
+            data.iter().map(|&(_, field)| tcx.def_span(field.did)).collect::<Vec<_>>(),
+            msg,
+        );
+
+        // A field can't be called, so only suggest reading it if there are no arguments.
+        if (!is_method && !args.is_empty())
+            || rcvr_expr.span.from_expansion()
+            || call_expr.span.from_expansion()
+        {
+            return;
+        }
+        let source_map = tcx.sess.source_map();
+        let (Ok(rcvr), Some(indent)) = (
+            source_map.span_to_snippet(rcvr_expr.span),
+            source_map.indentation_before(call_expr.span),
+        ) else {
+            return;
+        };
+        let call = if is_method {
+            let Some(Ok(call)) = call_expr
+                .span
+                .trim_start(rcvr_expr.span)
+                .map(|span| source_map.span_to_snippet(span))
+            else {
+                return;
+            };
+            call
+        } else {
+            String::new()
+        };
+        // Borrow the enum like the method call would have borrowed its data, unless
+        // the receiver is a reference already.
+        let borrow = match &methods {
+            Some(methods) if !rcvr_ty.is_ref() => {
+                let mutbl = methods.iter().filter_map(|(_, _, pick)| {
+                    match pick.autoref_or_ptr_adjustment {
+                        Some(AutorefOrPtrAdjustment::Autoref { mutbl, .. }) => Some(mutbl),
+                        _ => None,
+                    }
+                });
+                match mutbl.max() {
+                    Some(Mutability::Mut) => "&mut ",
+                    Some(Mutability::Not) => "&",
+                    None => "",
+                }
+            }
+            _ => "",
+        };
+        let arms: String = data
+            .iter()
+            .map(|&(variant, field)| {
+                let path = tcx.def_path_str(variant.def_id);
+                let pat = match variant.ctor_kind() {
+                    Some(CtorKind::Fn) => format!("{path}(inner)"),
+                    _ if is_method => format!("{path} {{ {}: inner }}", field.name),
+                    _ => format!("{path} {{ {item_name}, .. }}"),
+                };
+                let value = if is_method { format!("inner{call}") } else { item_name.to_string() };
+                format!("{indent}    {pat} => {value},\n")
+            })
+            .collect();
+        err.span_suggestion_verbose(
+            call_expr.span,
+            if is_method {
+                format!(
+                    "consider matching on the enum to call `{item_name}` \
+                     on the data of each variant"
+                )
+            } else {
+                format!(
+                    "consider matching on the enum to read the `{item_name}` field \
+                     of each variant"
+                )
+            },
+            format!("match {borrow}{rcvr} {{\n{arms}{indent}}}"),
+            Applicability::MaybeIncorrect,
+        );
+    }
+
     fn note_derefed_ty_has_method(
         &self,
         err: &mut Diagnostic,
@@ -2292,6 +2497,76 @@ fn suggest_await_before_method(
         }
     }
 
+    /// Suggests dereferencing a raw pointer receiver if the method exists on the pointee.
+    fn suggest_deref_raw_ptr_receiver(
+        &self,
+        err: &mut Diagnostic,
+        item_name: Ident,
+        rcvr_ty: Ty<'tcx>,
+        rcvr_expr: &hir::Expr<'_>,
+    ) {
+        let ty::RawPtr(ty::TypeAndMut { ty: pointee, .. }) = *rcvr_ty.kind() else { return };
+        let call_expr = self.tcx.hir().expect_expr(self.tcx.hir().parent_id(rcvr_expr.hir_id));
+        if rcvr_expr.span.from_expansion()
+            || call_expr.span.from_expansion()
+            || self
+                .lookup_probe_for_diagnostic(
+                    item_name,
+                    pointee,
+                    call_expr,
+                    ProbeScope::TraitsInScope,
+                    None,
+                )
+                .is_err()
+        {
+            return;
+        }
+
+        let ptr = match self.tcx.sess.source_map().span_to_snippet(rcvr_expr.span) {
+            Ok(snippet) if !snippet.contains('\n') => format!("`{snippet}`"),
+            _ => "the receiver".to_string(),
+        };
+        let mut sugg = vec![
+            (rcvr_expr.span.shrink_to_lo(), "(*".to_string()),
+            (rcvr_expr.span.shrink_to_hi(), ")".to_string()),
+        ];
+        let msg = if self.is_in_unsafe_context(call_expr.hir_id) {
+            format!(
+                "{ptr} is a raw pointer; consider dereferencing it \
+                 to call `{item_name}` on `{pointee}`"
+            )
+        } else {
+            // The receiver is the start of the call, so both insertions go into one part.
+            sugg[0].1 = "unsafe { (*".to_string();
+            sugg.push((call_expr.span.shrink_to_hi(), " }".to_string()));
+            format!(
+                "{ptr} is a raw pointer; consider dereferencing it in an `unsafe` block \
+                 to call `{item_name}` on `{pointee}`"
+            )
+        };
+        err.multipart_suggestion_verbose(msg, sugg, Applicability::MaybeIncorrect);
+    }
+
+    /// Whether `hir_id` is inside an `unsafe` block or an `unsafe fn`.
+    fn is_in_unsafe_context(&self, hir_id: hir::HirId) -> bool {
+        for (_, node) in self.tcx.hir().parent_iter(hir_id) {
+            match node {
+                hir::Node::Block(block)
+                    if matches!(block.rules, hir::BlockCheckMode::UnsafeBlock(_)) =>
+                {
+                    return true;
+                }
+                hir::Node::Item(_) | hir::Node::TraitItem(_) | hir::Node::ImplItem(_) => {
+                    return node
+                        .fn_sig()
+                        .map_or(false, |sig| sig.header.unsafety == hir::Unsafety::Unsafe);
+                }
+                _ => {}
+            }
+        }
+        false
+    }
+
     fn suggest_use_candidates(&self, err: &mut Diagnostic, msg: String, candidates: Vec<DefId>) {
         let parent_map = self.tcx.visible_parent_map(());
 
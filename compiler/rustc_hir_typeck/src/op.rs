@@ -4,13 +4,18 @@
 use super::{has_expected_num_generic_args, FnCtxt};
 use crate::Expectation;
 use rustc_ast as ast;
+use rustc_ast::util::parser::ExprPrecedence;
 use rustc_errors::{self, struct_span_err, Applicability, Diagnostic};
 use rustc_hir as hir;
+use rustc_hir::def::Res;
+use rustc_hir::def_id::DefId;
 use rustc_infer::infer::type_variable::{TypeVariableOrigin, TypeVariableOriginKind};
+use rustc_infer::infer::DefineOpaqueTypes;
 use rustc_infer::traits::ObligationCauseCode;
 use rustc_middle::ty::adjustment::{
     Adjust, Adjustment, AllowTwoPhase, AutoBorrow, AutoBorrowMutability,
 };
+use rustc_middle::ty::fast_reject::{simplify_type, TreatParams};
 use rustc_middle::ty::print::with_no_trimmed_paths;
 use rustc_middle::ty::{self, IsSuggestable, Ty, TyCtxt, TypeVisitableExt};
 use rustc_session::errors::ExprParenthesesNeeded;
@@ -283,12 +288,13 @@ fn check_overloaded_binop(
                         // some cases applied on the RHS, on top of which we need
                         // to autoref, which is not allowed by apply_adjustments.
                         // self.apply_adjustments(rhs_expr, vec![autoref]);
-                        self.typeck_results
-                            .borrow_mut()
-                            .adjustments_mut()
-                            .entry(rhs_expr.hir_id)
-                            .or_default()
-                            .push(autoref);
+                        let mut typeck_results = self.typeck_results.borrow_mut();
+                        let mut adjustments = typeck_results.adjustments_mut();
+                        let rhs_adjustments =
+                            adjustments.entry(rhs_expr.hir_id).or_insert(ty::List::empty());
+                        *rhs_adjustments = self
+                            .tcx
+                            .mk_adjustments_from_iter(rhs_adjustments.iter().chain([autoref]));
                     }
                 }
                 self.write_method_call(expr.hir_id, method);
@@ -319,6 +325,9 @@ fn check_overloaded_binop(
                             format!("cannot use `{}=` on type `{}`", op.node.as_str(), lhs_ty),
                         );
                         self.note_unmet_impls_on_type(&mut err, errors);
+                        self.suggest_binop_for_assign_op(
+                            &mut err, expr, lhs_expr, lhs_ty, rhs_expr, rhs_ty, op, expected,
+                        );
                         (err, None)
                     }
                     IsAssign::No => {
@@ -380,6 +389,7 @@ fn check_overloaded_binop(
                     }
                 };
 
+                let mut suggested_deref = false;
                 let mut suggest_deref_binop = |lhs_deref_ty: Ty<'tcx>| {
                     if self
                         .lookup_op_method(
@@ -405,6 +415,7 @@ fn check_overloaded_binop(
                             "*",
                             rustc_errors::Applicability::MachineApplicable,
                         );
+                        suggested_deref = true;
                     }
                 };
 
@@ -454,12 +465,14 @@ fn check_overloaded_binop(
                     // Cool
                 }
 
+                let mut is_str_addition = false;
                 if let Some(missing_trait) = missing_trait {
                     if op.node == hir::BinOpKind::Add
                         && self.check_str_addition(
                             lhs_expr, rhs_expr, lhs_ty, rhs_ty, &mut err, is_assign, op,
                         )
                     {
+                        is_str_addition = true;
                         // This has nothing here because it means we did string
                         // concatenation (e.g., "Hello " + "World!"). This means
                         // we don't want the note in the else clause to be emitted
@@ -521,6 +534,19 @@ fn check_overloaded_binop(
                         }
                     }
                 }
+                if !is_str_addition
+                    && !suggested_deref
+                    && let Some(trait_def_id) = trait_def_id
+                {
+                    self.note_near_miss_binop_impls(
+                        &mut err,
+                        trait_def_id,
+                        lhs_expr,
+                        lhs_ty,
+                        rhs_expr,
+                        rhs_ty,
+                    );
+                }
                 let reported = err.emit();
                 self.tcx.ty_error(reported)
             }
@@ -529,6 +555,182 @@ fn check_overloaded_binop(
         (lhs_ty, rhs_ty, return_ty)
     }
 
+    /// For `a op= b` where only the non-assigning operator is implemented (e.g. `Add` but
+    /// not `AddAssign`), suggests `a = a op b` instead.
+    fn suggest_binop_for_assign_op(
+        &self,
+        err: &mut Diagnostic,
+        expr: &'tcx hir::Expr<'tcx>,
+        lhs_expr: &'tcx hir::Expr<'tcx>,
+        lhs_ty: Ty<'tcx>,
+        rhs_expr: &'tcx hir::Expr<'tcx>,
+        rhs_ty: Ty<'tcx>,
+        op: hir::BinOp,
+        expected: Expectation<'tcx>,
+    ) {
+        if !expr.span.can_be_used_for_suggestions() {
+            return;
+        }
+        let Ok(method) = self.lookup_op_method(
+            lhs_ty,
+            Some((rhs_expr, rhs_ty)),
+            Op::Binary(op, IsAssign::No),
+            expected,
+        ) else {
+            return;
+        };
+        if !self.can_eq(self.param_env, method.sig.output(), lhs_ty) {
+            return;
+        }
+        let Ok(lhs_snippet) = self.tcx.sess.source_map().span_to_snippet(lhs_expr.span) else {
+            return;
+        };
+        let tcx = self.tcx;
+        let (_, Some(assign_trait)) = lang_item_for_op(tcx, Op::Binary(op, IsAssign::Yes), op.span)
+        else {
+            return;
+        };
+        let (_, Some(binop_trait)) = lang_item_for_op(tcx, Op::Binary(op, IsAssign::No), op.span)
+        else {
+            return;
+        };
+        // Only a local variable is certain to have no side effects when evaluated twice,
+        // and to be allowed to be moved out of and reassigned.
+        let is_local = matches!(
+            lhs_expr.kind,
+            hir::ExprKind::Path(hir::QPath::Resolved(None, hir::Path { res: Res::Local(_), .. }))
+        );
+        let mut sugg = vec![(op.span, format!("= {lhs_snippet} {}", op.node.as_str()))];
+        // `a -= b + c` has to become `a = a - (b + c)`.
+        if rhs_expr.precedence().order() <= ExprPrecedence::Binary(op.node.into()).order() {
+            sugg.push((rhs_expr.span.shrink_to_lo(), "(".to_string()));
+            sugg.push((rhs_expr.span.shrink_to_hi(), ")".to_string()));
+        }
+        err.multipart_suggestion_verbose(
+            format!(
+                "`{lhs_ty}` implements `{}` but not `{}`, consider assigning the result of `{}`",
+                tcx.item_name(binop_trait),
+                tcx.item_name(assign_trait),
+                op.node.as_str(),
+            ),
+            sugg,
+            if is_local { Applicability::MachineApplicable } else { Applicability::MaybeIncorrect },
+        );
+    }
+
+    /// Lists the impls of the operator trait that would have applied if one of the
+    /// operands had a different type: impls for `lhs_ty` that take a different
+    /// right-hand side, and impls taking `rhs_ty` for a left-hand side that only
+    /// differs from `lhs_ty` in its references or generic arguments. If there is a single such impl,
+    /// suggests converting the mismatched operand.
+    ///
+    /// Nothing is listed if some impl matches both operand types, since the
+    /// error is then caused by that impl's where-clauses instead.
+    fn note_near_miss_binop_impls(
+        &self,
+        err: &mut Diagnostic,
+        trait_def_id: DefId,
+        lhs_expr: &'tcx hir::Expr<'tcx>,
+        lhs_ty: Ty<'tcx>,
+        rhs_expr: &'tcx hir::Expr<'tcx>,
+        rhs_ty: Ty<'tcx>,
+    ) {
+        let tcx = self.tcx;
+        if !has_expected_num_generic_args(tcx, trait_def_id, 1) {
+            return;
+        }
+        let simplify = |ty| simplify_type(tcx, ty, TreatParams::AsCandidateKey);
+        let Some(lhs_simp) = simplify(lhs_ty.peel_refs()) else { return };
+
+        enum NearMiss<'tcx> {
+            /// The impl is for `lhs_ty`, but takes this right-hand side instead.
+            Rhs(Ty<'tcx>),
+            /// The impl takes `rhs_ty`, but is for this left-hand side instead.
+            Lhs(Ty<'tcx>),
+        }
+
+        let cause = self.misc(lhs_expr.span);
+        let mut near_misses = vec![];
+        for impl_def_id in tcx.all_impls(trait_def_id) {
+            if tcx.impl_polarity(impl_def_id) == ty::ImplPolarity::Negative {
+                continue;
+            }
+            let Some(impl_trait_ref) = tcx.impl_trait_ref(impl_def_id) else { continue };
+            let impl_trait_ref = impl_trait_ref.subst_identity();
+
+            // Relate the operand types to a fresh instantiation of the impl, keeping the
+            // instantiated types around to report them.
+            let instantiate = || {
+                let substs = self.fresh_substs_for_item(lhs_expr.span, impl_def_id);
+                tcx.impl_trait_ref(impl_def_id).unwrap().subst(tcx, substs)
+            };
+            let eq = |a, b| self.at(&cause, self.param_env).eq(DefineOpaqueTypes::No, a, b).is_ok();
+            let (self_matches, both_match) = self.probe(|_| {
+                let trait_ref = instantiate();
+                let self_matches = eq(trait_ref.self_ty(), lhs_ty);
+                (self_matches, self_matches && eq(trait_ref.substs.type_at(1), rhs_ty))
+            });
+            if both_match {
+                return;
+            }
+            // Blanket impls and impls for unrelated types would match too much to be helpful.
+            let impl_self_ty = impl_trait_ref.self_ty();
+            if simplify(impl_self_ty).is_none()
+                || simplify(impl_self_ty.peel_refs()) != Some(lhs_simp)
+            {
+                continue;
+            }
+            let near_miss = if self_matches {
+                NearMiss::Rhs(impl_trait_ref.substs.type_at(1))
+            } else if self.probe(|_| eq(instantiate().substs.type_at(1), rhs_ty)) {
+                NearMiss::Lhs(impl_trait_ref.self_ty())
+            } else {
+                continue;
+            };
+            near_misses.push((impl_trait_ref, near_miss));
+        }
+
+        if near_misses.is_empty() {
+            return;
+        }
+        let limit = if near_misses.len() == 9 { 9 } else { 8 };
+        let mut msg = format!(
+            "the following implementations of `{}` differ from this operation in the type of \
+             one operand:",
+            tcx.item_name(trait_def_id),
+        );
+        for (trait_ref, _) in near_misses.iter().take(limit) {
+            msg.push_str(&format!("\n  {trait_ref}"));
+        }
+        if near_misses.len() > limit {
+            msg.push_str(&format!("\nand {} others", near_misses.len() - limit));
+        }
+        err.help(msg);
+
+        let [(_, near_miss)] = &near_misses[..] else { return };
+        let (expr, found, expected) = match *near_miss {
+            NearMiss::Rhs(expected) => (rhs_expr, rhs_ty, expected),
+            NearMiss::Lhs(expected) => (lhs_expr, lhs_ty, expected),
+        };
+        if expected.has_non_region_param() {
+            return;
+        }
+        if let ty::Ref(_, inner, mutbl) = *expected.kind()
+            && self.can_eq(self.param_env, inner, found)
+        {
+            err.span_suggestion_verbose(
+                expr.span.shrink_to_lo(),
+                "consider borrowing here",
+                mutbl.ref_prefix_str(),
+                Applicability::MaybeIncorrect,
+            );
+        } else if let NearMiss::Rhs(_) = near_miss {
+            // Converting the left-hand side with `Into::into` would leave its
+            // type to be inferred from the operator, which it can't be.
+            self.suggest_into(err, expr, found, expected);
+        }
+    }
+
     /// Provide actionable suggestions when trying to add two strings with incorrect types,
     /// like `&str + &str`, `String + String` and `&str + &String`.
     ///
@@ -108,12 +108,41 @@ fn demand_eqtype_pat(
         actual: Ty<'tcx>,
         ti: TopInfo<'tcx>,
     ) {
-        if let Some(mut err) = self.demand_eqtype_pat_diag(cause_span, expected, actual, ti) {
+        if let Some(err) = self.demand_eqtype_pat_diag(cause_span, expected, actual, ti) {
+            if let Some(or_pat) = &mut *self.or_pat_mismatches.borrow_mut()
+                && or_pat.alternatives.contains(&cause_span)
+            {
+                // Reported once all alternatives of the or-pattern have been checked.
+                match or_pat.err {
+                    None => or_pat.err = Some(err),
+                    Some(ref mut first) => {
+                        err.cancel();
+                        let expected = self.resolve_vars_if_possible(expected);
+                        let actual = self.resolve_vars_if_possible(actual);
+                        first.span_label(
+                            cause_span,
+                            format!("expected `{expected}`, found `{actual}`"),
+                        );
+                        or_pat.labeled_others = true;
+                    }
+                }
+                return;
+            }
             err.emit();
         }
     }
 }
 
+/// The type mismatches of the alternatives of the or-pattern being checked.
+pub(crate) struct OrPatMismatches<'tcx> {
+    /// The spans of the alternatives. Mismatches in their subpatterns are reported as usual.
+    alternatives: Vec<Span>,
+    /// The error for the first alternative that didn't have the expected type.
+    err: Option<DiagnosticBuilder<'tcx, ErrorGuaranteed>>,
+    /// Whether other alternatives were pointed at in `err`.
+    labeled_others: bool,
+}
+
 const INITIAL_BM: BindingMode = BindingMode::BindByValue(hir::Mutability::Not);
 
 /// Mode for adjusting the expected type and binding mode.
@@ -185,9 +214,7 @@ fn check_pat(
                 self.check_pat_struct(pat, qpath, fields, has_rest_pat, expected, def_bm, ti)
             }
             PatKind::Or(pats) => {
-                for pat in pats {
-                    self.check_pat(pat, expected, def_bm, ti);
-                }
+                self.check_pat_or(pats, expected, def_bm, ti);
                 expected
             }
             PatKind::Tuple(elements, ddpos) => {
@@ -1276,6 +1303,38 @@ fn e0023(
         err.emit()
     }
 
+    /// Checks each alternative of an or-pattern against the same expected type. If several
+    /// alternatives don't have that type, a single error is reported for the first one, which
+    /// also points at the others, rather than one error per alternative.
+    fn check_pat_or(
+        &self,
+        pats: &'tcx [Pat<'tcx>],
+        expected: Ty<'tcx>,
+        def_bm: BindingMode,
+        ti: TopInfo<'tcx>,
+    ) {
+        let alternatives = pats.iter().map(|pat| pat.span).collect();
+        let outer = self.or_pat_mismatches.replace(Some(OrPatMismatches {
+            alternatives,
+            err: None,
+            labeled_others: false,
+        }));
+        for pat in pats {
+            self.check_pat(pat, expected, def_bm, ti);
+        }
+        let mismatches = self
+            .or_pat_mismatches
+            .replace(outer)
+            .expect("or-pattern mismatches were taken while checking its alternatives");
+
+        if let Some(mut err) = mismatches.err {
+            if mismatches.labeled_others {
+                err.note("all alternatives of an or-pattern must have the same type");
+            }
+            err.emit();
+        }
+    }
+
     fn check_pat_tuple(
         &self,
         span: Span,
@@ -1920,6 +1979,29 @@ fn error_unmentioned_fields(
             ),
             Applicability::MachineApplicable,
         );
+        // Tuple fields are already suggested as `0: _` above.
+        if unmentioned_fields.iter().any(|(_, name)| !is_number(&name.to_string())) {
+            err.span_suggestion(
+                sp,
+                format!(
+                    "if you don't need the value{s}, match {these} field{s} with `_`",
+                    these = pluralize!("this", len),
+                    s = pluralize!(len),
+                ),
+                format!(
+                    "{}{}{}{}",
+                    prefix,
+                    unmentioned_fields
+                        .iter()
+                        .map(|(_, name)| format!("{}: _", name))
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                    if have_inaccessible_fields { ", .." } else { "" },
+                    postfix,
+                ),
+                Applicability::MachineApplicable,
+            );
+        }
         err.span_suggestion(
             sp,
             format!(
@@ -1,5 +1,5 @@
 use crate::method::MethodCallee;
-use crate::{has_expected_num_generic_args, FnCtxt, PlaceOp};
+use crate::{has_expected_num_generic_args, FnCtxt, Needs, PlaceOp};
 use rustc_ast as ast;
 use rustc_errors::Applicability;
 use rustc_hir as hir;
@@ -19,18 +19,23 @@ pub(super) fn lookup_derefing(
         expr: &hir::Expr<'_>,
         oprnd_expr: &'tcx hir::Expr<'tcx>,
         oprnd_ty: Ty<'tcx>,
+        needs: Needs,
     ) -> Option<Ty<'tcx>> {
         if let Some(mt) = oprnd_ty.builtin_deref(true) {
             return Some(mt.ty);
         }
 
-        let ok = self.try_overloaded_deref(expr.span, oprnd_ty)?;
+        let ok =
+            self.try_overloaded_place_op_for(expr.span, oprnd_ty, &[], PlaceOp::Deref, needs)?;
         let method = self.register_infer_ok_obligations(ok);
-        if let ty::Ref(region, _, hir::Mutability::Not) = method.sig.inputs()[0].kind() {
+        if let ty::Ref(region, _, mutbl) = method.sig.inputs()[0].kind() {
             self.apply_adjustments(
                 oprnd_expr,
                 vec![Adjustment {
-                    kind: Adjust::Borrow(AutoBorrow::Ref(*region, AutoBorrowMutability::Not)),
+                    kind: Adjust::Borrow(AutoBorrow::Ref(
+                        *region,
+                        AutoBorrowMutability::new(*mutbl, AllowTwoPhase::No),
+                    )),
                     target: method.sig.inputs()[0],
                 }],
             );
@@ -50,6 +55,7 @@ pub(super) fn lookup_indexing(
         base_ty: Ty<'tcx>,
         index_expr: &'tcx hir::Expr<'tcx>,
         idx_ty: Ty<'tcx>,
+        needs: Needs,
     ) -> Option<(/*index type*/ Ty<'tcx>, /*element type*/ Ty<'tcx>)> {
         // FIXME(#18741) -- this is almost but not quite the same as the
         // autoderef that normal method probing does. They could likely be
@@ -58,7 +64,7 @@ pub(super) fn lookup_indexing(
         let mut autoderef = self.autoderef(base_expr.span, base_ty);
         let mut result = None;
         while result.is_none() && autoderef.next().is_some() {
-            result = self.try_index_step(expr, base_expr, &autoderef, idx_ty, index_expr);
+            result = self.try_index_step(expr, base_expr, &autoderef, idx_ty, index_expr, needs);
         }
         self.register_predicates(autoderef.into_obligations());
         result
@@ -105,6 +111,7 @@ fn try_index_step(
         autoderef: &Autoderef<'a, 'tcx>,
         index_ty: Ty<'tcx>,
         index_expr: &hir::Expr<'_>,
+        needs: Needs,
     ) -> Option<(/*index type*/ Ty<'tcx>, /*element type*/ Ty<'tcx>)> {
         let adjusted_ty =
             self.structurally_resolved_type(autoderef.span(), autoderef.final_ty(false));
@@ -151,21 +158,28 @@ fn try_index_step(
                 kind: TypeVariableOriginKind::AutoDeref,
                 span: base_expr.span,
             });
-            let method =
-                self.try_overloaded_place_op(expr.span, self_ty, &[input_ty], PlaceOp::Index);
+            let method = self.try_overloaded_place_op_for(
+                expr.span,
+                self_ty,
+                &[input_ty],
+                PlaceOp::Index,
+                needs,
+            );
 
             if let Some(result) = method {
                 debug!("try_index_step: success, using overloaded indexing");
                 let method = self.register_infer_ok_obligations(result);
 
                 let mut adjustments = self.adjust_steps(autoderef);
-                if let ty::Ref(region, _, hir::Mutability::Not) = method.sig.inputs()[0].kind() {
+                if let ty::Ref(region, _, mutbl) = method.sig.inputs()[0].kind() {
                     adjustments.push(Adjustment {
-                        kind: Adjust::Borrow(AutoBorrow::Ref(*region, AutoBorrowMutability::Not)),
-                        target: self.tcx.mk_ref(
+                        kind: Adjust::Borrow(AutoBorrow::Ref(
                             *region,
-                            ty::TypeAndMut { mutbl: hir::Mutability::Not, ty: adjusted_ty },
-                        ),
+                            AutoBorrowMutability::new(*mutbl, AllowTwoPhase::No),
+                        )),
+                        target: self
+                            .tcx
+                            .mk_ref(*region, ty::TypeAndMut { mutbl: *mutbl, ty: adjusted_ty }),
                     });
                 } else {
                     span_bug!(expr.span, "input to index is not a ref?");
@@ -187,9 +201,29 @@ fn try_index_step(
         None
     }
 
+    /// Try to resolve an overloaded place op for a place used as described by `needs`:
+    /// the mutable variant (DerefMut/IndexMut) if a mutable place is needed and it
+    /// exists, and the immutable variant (Deref/Index) otherwise.
+    fn try_overloaded_place_op_for(
+        &self,
+        span: Span,
+        base_ty: Ty<'tcx>,
+        arg_tys: &[Ty<'tcx>],
+        op: PlaceOp,
+        needs: Needs,
+    ) -> Option<InferOk<'tcx, MethodCallee<'tcx>>> {
+        match needs {
+            Needs::MutPlace => self
+                .try_mutable_overloaded_place_op(span, base_ty, arg_tys, op)
+                .or_else(|| self.try_overloaded_place_op(span, base_ty, arg_tys, op)),
+            Needs::None => self.try_overloaded_place_op(span, base_ty, arg_tys, op),
+        }
+    }
+
     /// Try to resolve an overloaded place op. We only deal with the immutable
     /// variant here (Deref/Index). In some contexts we would need the mutable
-    /// variant (DerefMut/IndexMut); those would be later converted by
+    /// variant (DerefMut/IndexMut); those are either selected upfront through
+    /// `try_overloaded_place_op_for`, or later converted by
     /// `convert_place_derefs_to_mutable`.
     pub(super) fn try_overloaded_place_op(
         &self,
@@ -312,7 +346,8 @@ pub fn convert_place_derefs_to_mutable(&self, expr: &hir::Expr<'_>) {
             // typeck results borrowed during (`deref_mut`) method resolution.
             let previous_adjustments =
                 self.typeck_results.borrow_mut().adjustments_mut().remove(expr.hir_id);
-            if let Some(mut adjustments) = previous_adjustments {
+            if let Some(previous_adjustments) = previous_adjustments {
+                let mut adjustments = previous_adjustments.to_vec();
                 for adjustment in &mut adjustments {
                     if let Adjust::Deref(Some(ref mut deref)) = adjustment.kind
                         && let Some(ok) = self.try_mutable_overloaded_place_op(
@@ -344,6 +379,7 @@ pub fn convert_place_derefs_to_mutable(&self, expr: &hir::Expr<'_>) {
                     }
                     source = adjustment.target;
                 }
+                let adjustments = self.tcx.mk_adjustments(&adjustments);
                 self.typeck_results.borrow_mut().adjustments_mut().insert(expr.hir_id, adjustments);
             }
 
@@ -370,6 +406,15 @@ fn convert_place_op_to_mutable(
             debug!("convert_place_op_to_mutable - builtin, nothing to do");
             return;
         }
+        let mut_tr = match op {
+            PlaceOp::Deref => self.tcx.lang_items().deref_mut_trait(),
+            PlaceOp::Index => self.tcx.lang_items().index_mut_trait(),
+        };
+        let method_def_id = self.typeck_results.borrow().type_dependent_def_id(expr.hir_id);
+        if mut_tr.is_some() && method_def_id.and_then(|did| self.tcx.trait_of_item(did)) == mut_tr {
+            debug!("convert_place_op_to_mutable - already mutable, nothing to do");
+            return;
+        }
 
         // Need to deref because overloaded place ops take self by-reference.
         let base_ty = self
@@ -415,8 +460,9 @@ fn convert_place_op_to_mutable(
         if let Some(adjustments) =
             self.typeck_results.borrow_mut().adjustments_mut().get_mut(base_expr.hir_id)
         {
+            let mut new_adjustments = adjustments.to_vec();
             let mut source = base_expr_ty;
-            for adjustment in &mut adjustments[..] {
+            for adjustment in &mut new_adjustments {
                 if let Adjust::Borrow(AutoBorrow::Ref(..)) = adjustment.kind {
                     debug!("convert_place_op_to_mutable: converting autoref {:?}", adjustment);
                     let mutbl = AutoBorrowMutability::Mut {
@@ -439,10 +485,11 @@ fn convert_place_op_to_mutable(
                 ..,
                 Adjustment { kind: Adjust::Borrow(AutoBorrow::Ref(..)), .. },
                 Adjustment { kind: Adjust::Pointer(PointerCast::Unsize), ref mut target },
-            ] = adjustments[..]
+            ] = new_adjustments[..]
             {
                 *target = method.sig.inputs()[0];
             }
+            *adjustments = self.tcx.mk_adjustments(&new_adjustments);
         }
     }
 }
@@ -75,7 +75,7 @@ pub fn closure_analyze(&self, body: &'tcx hir::Body<'tcx>) {
         InferBorrowKindVisitor { fcx: self }.visit_body(body);
 
         // it's our job to process these.
-        assert!(self.deferred_call_resolutions.borrow().is_empty());
+        assert!(self.deferred.borrow().call_resolutions.is_empty());
     }
 }
 
@@ -323,8 +323,12 @@ fn analyze_closure(
         // If we are also inferred the closure kind here,
         // process any deferred resolutions.
         let deferred_call_resolutions = self.remove_deferred_call_resolutions(closure_def_id);
-        for deferred_call_resolution in deferred_call_resolutions {
-            deferred_call_resolution.resolve(self);
+        let unresolved_calls: Vec<_> = deferred_call_resolutions
+            .into_iter()
+            .filter_map(|deferred_call_resolution| deferred_call_resolution.resolve(self).err())
+            .collect();
+        if !unresolved_calls.is_empty() {
+            self.report_unresolved_closure_calls(closure_def_id, unresolved_calls);
         }
     }
 
@@ -5,15 +5,18 @@
 use crate::FnCtxt;
 use hir::def_id::LocalDefId;
 use rustc_data_structures::fx::FxHashMap;
+use rustc_data_structures::unord::UnordSet;
 use rustc_errors::ErrorGuaranteed;
 use rustc_hir as hir;
 use rustc_hir::intravisit::{self, Visitor};
 use rustc_infer::infer::error_reporting::TypeAnnotationNeeded::E0282;
 use rustc_infer::infer::InferCtxt;
+use rustc_middle::hir::nested_filter;
 use rustc_middle::hir::place::Place as HirPlace;
 use rustc_middle::mir::FakeReadCause;
-use rustc_middle::ty::adjustment::{Adjust, Adjustment, PointerCast};
+use rustc_middle::ty::adjustment::{Adjust, Adjustment, AutoBorrow, PointerCast};
 use rustc_middle::ty::fold::{TypeFoldable, TypeFolder, TypeSuperFoldable};
+use rustc_middle::ty::print::with_no_trimmed_paths;
 use rustc_middle::ty::visit::{TypeSuperVisitable, TypeVisitable, TypeVisitableExt};
 use rustc_middle::ty::TypeckResults;
 use rustc_middle::ty::{self, ClosureSizeProfileData, Ty, TyCtxt};
@@ -88,6 +91,40 @@ pub fn resolve_type_vars_in_body(
         }
 
         debug!("writeback: typeck results for {:?} are {:#?}", item_def_id, wbcx.typeck_results);
+        debug!(
+            "writeback: skipped {} repeated WF obligations in {:?}",
+            self.skipped_wf_obligations.get(),
+            item_def_id
+        );
+
+        if self.tcx.prof.enabled() {
+            // Record how much memory the adjustments of this body take up, counting
+            // the table entries and each distinct interned adjustment list once.
+            let adjustments = wbcx.typeck_results.adjustments();
+            let mut lists = UnordSet::new();
+            lists.extend_unord(adjustments.items().map(|(_, &list)| list));
+            let size = adjustments.items().count() * mem::size_of::<&ty::List<Adjustment<'_>>>()
+                + lists
+                    .items()
+                    .map(|list| {
+                        mem::size_of::<usize>() + list.len() * mem::size_of::<Adjustment<'_>>()
+                    })
+                    .sum::<usize>();
+            self.tcx.prof.artifact_size(
+                "typeck_adjustments",
+                self.tcx.def_path_str(item_def_id.to_def_id()),
+                size as u64,
+            );
+        }
+
+        if let Some(path) = &self.tcx.sess.opts.unstable_opts.print_adjustments
+            && with_no_trimmed_paths!(self.tcx.def_path_str(item_def_id.to_def_id())) == *path
+        {
+            println!("adjustments in `{path}`:");
+            let mut printer =
+                AdjustmentPrinter { tcx: self.tcx, typeck_results: &wbcx.typeck_results };
+            with_no_trimmed_paths!(printer.visit_body(body));
+        }
 
         self.tcx.arena.alloc(wbcx.typeck_results)
     }
@@ -169,18 +206,24 @@ fn fix_scalar_builtin_expr(&mut self, e: &hir::Expr<'_>) {
                         hir::ExprKind::Binary(..) => {
                             if !op.node.is_by_value() {
                                 let mut adjustments = typeck_results.adjustments_mut();
-                                if let Some(a) = adjustments.get_mut(lhs.hir_id) {
-                                    a.pop();
+                                if let Some(a) = adjustments.get_mut(lhs.hir_id)
+                                    && let Some((_, rest)) = a.split_last()
+                                {
+                                    *a = self.fcx.tcx.mk_adjustments(rest);
                                 }
-                                if let Some(a) = adjustments.get_mut(rhs.hir_id) {
-                                    a.pop();
+                                if let Some(a) = adjustments.get_mut(rhs.hir_id)
+                                    && let Some((_, rest)) = a.split_last()
+                                {
+                                    *a = self.fcx.tcx.mk_adjustments(rest);
                                 }
                             }
                         }
                         hir::ExprKind::AssignOp(..)
                             if let Some(a) = typeck_results.adjustments_mut().get_mut(lhs.hir_id) =>
                         {
-                            a.pop();
+                            if let Some((_, rest)) = a.split_last() {
+                                *a = self.fcx.tcx.mk_adjustments(rest);
+                            }
                         }
                         _ => {}
                     }
@@ -254,14 +297,14 @@ fn fix_index_builtin_expr(&mut self, e: &hir::Expr<'_>) {
                         // Extra adjustment made when indexing causes a drop
                         // of size information - we need to get rid of it
                         // Since this is "after" the other adjustment to be
-                        // discarded, we do an extra `pop()`
-                        if let Some(Adjustment {
-                            kind: Adjust::Pointer(PointerCast::Unsize), ..
-                        }) = a.pop()
-                        {
-                            // So the borrow discard actually happens here
-                            a.pop();
-                        }
+                        // discarded, we discard an extra adjustment
+                        let discarded = match a.last() {
+                            Some(Adjustment {
+                                kind: Adjust::Pointer(PointerCast::Unsize), ..
+                            }) => 2,
+                            _ => 1,
+                        };
+                        *a = self.fcx.tcx.mk_adjustments(&a[..a.len().saturating_sub(discarded)]);
                     }
                 }
             }
@@ -457,6 +500,13 @@ fn visit_closures(&mut self) {
             let place = self.resolve(origin.1.clone(), &place_span);
             self.typeck_results.closure_kind_origins_mut().insert(hir_id, (place_span, place));
         }
+
+        for (local_id, &origin_span) in
+            fcx_typeck_results.closure_sig_origins().items_in_stable_order()
+        {
+            let hir_id = hir::HirId { owner: common_hir_owner, local_id };
+            self.typeck_results.closure_sig_origins_mut().insert(hir_id, origin_span);
+        }
     }
 
     fn visit_coercion_casts(&mut self) {
@@ -849,3 +899,55 @@ fn fold_const(&mut self, ct: ty::Const<'tcx>) -> ty::Const<'tcx> {
 // promises. It is expected that we will have already reported any
 // errors that may be encountered, so if the promises store an error,
 // a dummy result is returned.
+
+///////////////////////////////////////////////////////////////////////////
+// `-Zprint-adjustments`: prints the final type and adjustments of every
+// expression in a body, including the bodies of closures nested in it.
+
+struct AdjustmentPrinter<'a, 'tcx> {
+    tcx: TyCtxt<'tcx>,
+    typeck_results: &'a TypeckResults<'tcx>,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for AdjustmentPrinter<'a, 'tcx> {
+    type NestedFilter = nested_filter::OnlyBodies;
+
+    fn nested_visit_map(&mut self) -> Self::Map {
+        self.tcx.hir()
+    }
+
+    fn visit_expr(&mut self, expr: &'tcx hir::Expr<'tcx>) {
+        // Expressions of nested constants, like array lengths, are type checked separately.
+        if let Some(ty) = self.typeck_results.node_type_opt(expr.hir_id) {
+            let source_map = self.tcx.sess.source_map();
+            let mut location = source_map.span_to_embeddable_string(expr.span);
+            if let Ok(snippet) = source_map.span_to_snippet(expr.span)
+                && !snippet.contains('\n')
+            {
+                location = format!("{location} (`{snippet}`)");
+            }
+            println!("{location}: `{ty}`");
+            for adjustment in self.typeck_results.expr_adjustments(expr) {
+                let kind = match adjustment.kind {
+                    Adjust::NeverToAny => "never to any".to_string(),
+                    Adjust::Deref(None) => "deref".to_string(),
+                    Adjust::Deref(Some(_)) => "overloaded deref".to_string(),
+                    Adjust::Borrow(AutoBorrow::Ref(_, mutbl)) => {
+                        let prefix = hir::Mutability::from(mutbl).ref_prefix_str();
+                        format!("borrow (`{}`)", prefix.trim_end())
+                    }
+                    Adjust::Borrow(AutoBorrow::RawPtr(hir::Mutability::Not)) => {
+                        "borrow (`*const`)".to_string()
+                    }
+                    Adjust::Borrow(AutoBorrow::RawPtr(hir::Mutability::Mut)) => {
+                        "borrow (`*mut`)".to_string()
+                    }
+                    Adjust::Pointer(cast) => format!("pointer cast ({cast:?})"),
+                    Adjust::DynStar => "dyn* coercion".to_string(),
+                };
+                println!("    {kind} -> `{}`", adjustment.target);
+            }
+        }
+        intravisit::walk_expr(self, expr);
+    }
+}
@@ -55,6 +55,9 @@ infer_source_kind_subdiag_generic_suggestion =
     *[other] arguments
     }
 
+infer_source_kind_subdiag_placeholder_label =
+    cannot infer the type of this placeholder `_`
+
 infer_source_kind_fully_qualified =
     try using a fully qualified path to specify the expected types
 
@@ -357,6 +360,11 @@ infer_fps_cast_both = consider casting both fn items to fn pointers using `as {$
 infer_fn_uniq_types = different fn items have unique types, even if their signatures are the same
 infer_fn_consider_casting = consider casting the fn item to a fn pointer: `{$casting}`
 
+infer_closure_uniq_types = different closures have unique types, even if their signatures are the same
+infer_closure_coerce_to_fn_ptr = closures that don't capture any variables can be coerced to a fn pointer
+infer_closure_coerce_to_fn_ptr_help = closures that don't capture any variables can be coerced to a fn pointer of type `{$fn_ptr}`
+infer_closure_box = consider boxing the closures with `Box::new` and using `{$boxed}` as their type
+
 infer_sarwa_option = you can convert from `&Option<T>` to `Option<&T>` using `.as_ref()`
 infer_sarwa_result = you can convert from `&Result<T, E>` to `Result<&T, &E>` using `.as_ref()`
 
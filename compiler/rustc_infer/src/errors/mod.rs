@@ -152,6 +152,11 @@ pub enum SourceKindSubdiag<'a> {
         arg_count: usize,
         args: String,
     },
+    #[label(infer_source_kind_subdiag_placeholder_label)]
+    PlaceholderLabel {
+        #[primary_span]
+        span: Span,
+    },
 }
 
 #[derive(Subdiagnostic)]
@@ -1246,6 +1251,40 @@ pub struct FnConsiderCasting {
     pub casting: String,
 }
 
+#[derive(Subdiagnostic)]
+#[note(infer_closure_uniq_types)]
+pub struct ClosureUniqTypes;
+
+#[derive(Subdiagnostic)]
+pub enum ClosureTypeSuggestion {
+    #[suggestion(
+        infer_closure_coerce_to_fn_ptr,
+        code = ": {fn_ptr}",
+        style = "verbose",
+        applicability = "maybe-incorrect"
+    )]
+    AnnotateFnPtr {
+        #[primary_span]
+        span: Span,
+        fn_ptr: String,
+    },
+    #[suggestion(
+        infer_closure_coerce_to_fn_ptr,
+        code = "{fn_ptr}",
+        style = "verbose",
+        applicability = "maybe-incorrect"
+    )]
+    ReplaceAnnotationWithFnPtr {
+        #[primary_span]
+        span: Span,
+        fn_ptr: String,
+    },
+    #[help(infer_closure_coerce_to_fn_ptr_help)]
+    FnPtr { fn_ptr: String },
+    #[help(infer_closure_box)]
+    Box { boxed: String },
+}
+
 #[derive(Subdiagnostic)]
 pub enum SuggestAsRefWhereAppropriate<'a> {
     #[suggestion(
@@ -121,6 +121,9 @@ fn escape_literal(s: &str) -> String {
 pub struct TypeErrCtxt<'a, 'tcx> {
     pub infcx: &'a InferCtxt<'tcx>,
     pub typeck_results: Option<std::cell::Ref<'a, ty::TypeckResults<'tcx>>>,
+    /// The `_` placeholders in the user-written types of the body being type checked, along
+    /// with the inference variables they were lowered to.
+    pub type_placeholders: Option<std::cell::Ref<'a, Vec<(Span, Ty<'tcx>)>>>,
     pub fallback_has_occurred: bool,
 
     pub normalize_fn_sig: Box<dyn Fn(ty::PolyFnSig<'tcx>) -> ty::PolyFnSig<'tcx> + 'a>,
@@ -340,11 +340,16 @@ fn bad_inference_failure_err(
         span: Span,
         arg_data: InferenceDiagnosticsData,
         error_code: TypeAnnotationNeeded,
+        placeholder_span: Option<Span>,
     ) -> DiagnosticBuilder<'tcx, ErrorGuaranteed> {
         let source_kind = "other";
         let source_name = "";
         let failure_span = None;
-        let infer_subdiags = Vec::new();
+        let infer_subdiags = placeholder_span
+            .filter(|placeholder_span| !placeholder_span.overlaps(span))
+            .map(|span| SourceKindSubdiag::PlaceholderLabel { span })
+            .into_iter()
+            .collect();
         let multi_suggestions = Vec::new();
         let bad_label = Some(arg_data.make_bad_error(span));
         match error_code {
@@ -399,8 +404,9 @@ pub fn emit_inference_failure_err(
             // If we don't have any typeck results we're outside
             // of a body, so we won't be able to get better info
             // here.
-            return self.bad_inference_failure_err(failure_span, arg_data, error_code);
+            return self.bad_inference_failure_err(failure_span, arg_data, error_code, None);
         };
+        let placeholder_span = self.unconstrained_type_placeholder(arg);
 
         let mut local_visitor = FindInferSourceVisitor::new(&self, typeck_results, arg);
         if let Some(body_id) = self.tcx.hir().maybe_body_owned_by(
@@ -411,7 +417,12 @@ pub fn emit_inference_failure_err(
         }
 
         let Some(InferSource { span, kind }) = local_visitor.infer_source else {
-            return self.bad_inference_failure_err(failure_span, arg_data, error_code)
+            return self.bad_inference_failure_err(
+                failure_span,
+                arg_data,
+                error_code,
+                placeholder_span,
+            );
         };
 
         let (source_kind, name) = kind.ty_localized_msg(self);
@@ -508,7 +519,8 @@ pub fn emit_inference_failure_err(
                         .into_buffer()
                 };
 
-                if !have_turbofish {
+                // If the type was given with a `_` placeholder, we point at it instead.
+                if !have_turbofish && placeholder_span.is_none() {
                     infer_subdiags.push(SourceKindSubdiag::GenericSuggestion {
                         span: insert_span,
                         arg_count: generic_args.len(),
@@ -552,6 +564,9 @@ pub fn emit_inference_failure_err(
                 ));
             }
         }
+        if let Some(placeholder_span) = placeholder_span && !placeholder_span.overlaps(span) {
+            infer_subdiags.push(SourceKindSubdiag::PlaceholderLabel { span: placeholder_span });
+        }
         match error_code {
             TypeAnnotationNeeded::E0282 => AnnotationRequired {
                 span,
@@ -585,6 +600,20 @@ pub fn emit_inference_failure_err(
             .into_diagnostic(&self.tcx.sess.parse_sess.span_diagnostic),
         }
     }
+
+    /// Finds the `_` placeholder in a type annotation written by the user that was lowered
+    /// to the inference variable `arg`, if it was never constrained.
+    fn unconstrained_type_placeholder(&self, arg: GenericArg<'tcx>) -> Option<Span> {
+        let GenericArgKind::Type(ty) = arg.unpack() else { return None };
+        let ty::Infer(ty::TyVar(target_vid)) = *ty.kind() else { return None };
+        let target_vid = self.root_var(target_vid);
+        self.type_placeholders.as_ref()?.iter().find_map(|&(span, ty)| {
+            match *self.resolve_vars_if_possible(ty).kind() {
+                ty::Infer(ty::TyVar(vid)) if self.root_var(vid) == target_vid => Some(span),
+                _ => None,
+            }
+        })
+    }
 }
 
 impl<'tcx> InferCtxt<'tcx> {
@@ -29,7 +29,10 @@ pub fn note_and_explain_type_err(
         match err {
             ArgumentSorts(values, _) | Sorts(values) => {
                 match (values.expected.kind(), values.found.kind()) {
-                    (ty::Closure(..), ty::Closure(..)) => {
+                    // Closures with the same signature are handled in `suggest_function_pointers`.
+                    (ty::Closure(..), ty::Closure(..))
+                        if self.identical_closure_sig(values.expected, values.found).is_none() =>
+                    {
                         diag.note("no two closures, even if identical, have the same type");
                         diag.help("consider boxing your closure and/or using it as a trait object");
                     }
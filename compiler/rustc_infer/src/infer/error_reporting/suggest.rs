@@ -9,13 +9,14 @@
 };
 use rustc_middle::ty::print::with_no_trimmed_paths;
 use rustc_middle::ty::{self as ty, GenericArgKind, IsSuggestable, Ty, TypeVisitableExt};
-use rustc_span::{sym, BytePos, Span};
+use rustc_span::{def_id::DefId, sym, BytePos, Span};
 
 use crate::errors::{
-    ConsiderAddingAwait, FnConsiderCasting, FnItemsAreDistinct, FnUniqTypes,
-    FunctionPointerSuggestion, SuggestAccessingField, SuggestAsRefWhereAppropriate,
-    SuggestBoxingForReturnImplTrait, SuggestRemoveSemiOrReturnBinding, SuggestTuplePatternMany,
-    SuggestTuplePatternOne, TypeErrorAdditionalDiags,
+    ClosureTypeSuggestion, ClosureUniqTypes, ConsiderAddingAwait, FnConsiderCasting,
+    FnItemsAreDistinct, FnUniqTypes, FunctionPointerSuggestion, SuggestAccessingField,
+    SuggestAsRefWhereAppropriate, SuggestBoxingForReturnImplTrait,
+    SuggestRemoveSemiOrReturnBinding, SuggestTuplePatternMany, SuggestTuplePatternOne,
+    TypeErrorAdditionalDiags,
 };
 
 use super::TypeErrCtxt;
@@ -319,6 +320,59 @@ pub(super) fn suggest_function_pointers(
     ) {
         debug!("suggest_function_pointers(cause={:?}, exp_found={:?})", cause, exp_found);
         let ty::error::ExpectedFound { expected, found } = exp_found;
+        if let (
+            ty::Closure(expected_def_id, expected_substs),
+            ty::Closure(found_def_id, found_substs),
+        ) = (expected.kind(), found.kind())
+        {
+            let Some(sig) = self.identical_closure_sig(*expected, *found) else { return };
+            diag.subdiagnostic(ClosureUniqTypes);
+            let captures = |def_id: DefId| {
+                def_id.as_local().map_or(true, |_| self.tcx.upvars_mentioned(def_id).is_some())
+            };
+            let sugg = if !captures(*expected_def_id) && !captures(*found_def_id) {
+                let fn_ptr = self.tcx.mk_fn_ptr(sig).to_string();
+                match self.binding_of_value(*expected_def_id) {
+                    Some(hir::Local { ty: Some(ty), .. }) => {
+                        ClosureTypeSuggestion::ReplaceAnnotationWithFnPtr { span: ty.span, fn_ptr }
+                    }
+                    Some(hir::Local { pat, ty: None, .. }) => {
+                        ClosureTypeSuggestion::AnnotateFnPtr {
+                            span: pat.span.shrink_to_hi(),
+                            fn_ptr,
+                        }
+                    }
+                    None => ClosureTypeSuggestion::FnPtr { fn_ptr },
+                }
+            } else {
+                // The closure kinds may not be known yet, assume the closures are `Fn`.
+                let kind = [expected_substs, found_substs]
+                    .into_iter()
+                    .filter_map(|substs| {
+                        self.resolve_vars_if_possible(substs.as_closure().kind_ty())
+                            .to_opt_closure_kind()
+                    })
+                    .max()
+                    .unwrap_or(ty::ClosureKind::Fn);
+                let kind = match kind {
+                    ty::ClosureKind::Fn => "Fn",
+                    ty::ClosureKind::FnMut => "FnMut",
+                    ty::ClosureKind::FnOnce => "FnOnce",
+                };
+                let sig = sig.skip_binder();
+                let inputs =
+                    sig.inputs().iter().map(|ty| ty.to_string()).collect::<Vec<_>>().join(", ");
+                let output = if sig.output().is_unit() {
+                    String::new()
+                } else {
+                    format!(" -> {}", sig.output())
+                };
+                ClosureTypeSuggestion::Box { boxed: format!("Box<dyn {kind}({inputs}){output}>") }
+            };
+            diag.subdiagnostic(sugg);
+            return;
+        }
+
         let expected_inner = expected.peel_refs();
         let found_inner = found.peel_refs();
         if !expected_inner.is_fn() || !found_inner.is_fn() {
@@ -416,6 +470,57 @@ pub(super) fn suggest_function_pointers(
         };
     }
 
+    /// If `expected` and `found` are closures with the same signature, and that signature
+    /// can be written out in a suggestion, returns it as the signature of a fn pointer.
+    pub(super) fn identical_closure_sig(
+        &self,
+        expected: Ty<'tcx>,
+        found: Ty<'tcx>,
+    ) -> Option<ty::PolyFnSig<'tcx>> {
+        let (ty::Closure(_, expected_substs), ty::Closure(_, found_substs)) =
+            (expected.kind(), found.kind())
+        else {
+            return None;
+        };
+        let sig = |substs: ty::SubstsRef<'tcx>| {
+            let sig = substs.as_closure().sig();
+            self.resolve_vars_if_possible(self.tcx.signature_unclosure(sig, hir::Unsafety::Normal))
+        };
+        let expected_sig = sig(expected_substs);
+        (expected_sig == sig(found_substs)
+            && expected_sig.bound_vars().is_empty()
+            && expected_sig.is_suggestable(self.tcx, false))
+        .then_some(expected_sig)
+    }
+
+    /// Returns the `let` statement whose initializer evaluates to the closure
+    /// `def_id`, possibly as the value of a block, `if` or `match`.
+    fn binding_of_value(&self, def_id: DefId) -> Option<&'tcx hir::Local<'tcx>> {
+        let hir = self.tcx.hir();
+        let mut child = hir.local_def_id_to_hir_id(def_id.as_local()?);
+        for (parent, node) in hir.parent_iter(child) {
+            match node {
+                hir::Node::Local(local) => {
+                    return local.init.filter(|init| init.hir_id == child).map(|_| local);
+                }
+                hir::Node::Block(hir::Block { expr: Some(expr), .. }) if expr.hir_id == child => {}
+                hir::Node::Arm(arm) if arm.body.hir_id == child => {}
+                hir::Node::Expr(expr) => match expr.kind {
+                    hir::ExprKind::Block(..) => {}
+                    hir::ExprKind::If(_, then, els)
+                        if then.hir_id == child || els.map_or(false, |els| els.hir_id == child) => {
+                    }
+                    hir::ExprKind::Match(_, arms, _)
+                        if arms.iter().any(|arm| arm.hir_id == child) => {}
+                    _ => return None,
+                },
+                _ => return None,
+            }
+            child = parent;
+        }
+        None
+    }
+
     pub fn should_suggest_as_ref_kind(
         &self,
         expected: Ty<'tcx>,
@@ -37,6 +37,12 @@ lint_non_binding_let_on_sync_lock =
 lint_non_binding_let_on_drop_type =
     non-binding let on a type that implements `Drop`
 
+lint_non_binding_let_on_must_use =
+    value of type `{$ty}` that must be used is discarded by a wildcard pattern
+    .label = the value is discarded here
+    .note = `{$def}` is marked `#[must_use]`
+    .reason = {$reason}
+
 lint_non_binding_let_suggestion =
     consider binding to an unused variable to avoid immediately dropping the value
 
@@ -210,6 +216,15 @@ lint_range_endpoint_out_of_range = range endpoint is out of range for `{$ty}`
 
 lint_range_use_inclusive_range = use an inclusive range instead
 
+lint_range_pat_endpoint_out_of_range = range pattern endpoint is out of range for `{$ty}`
+    .label = this value doesn't fit in `{$ty}`
+    .note = `{$ty}` ranges from `{$min}` to `{$max}`
+
+lint_range_pat_endpoint_cap = consider capping the range at the {$bound ->
+        [max] maximum
+        *[min] minimum
+    } value of `{$ty}`
+
 
 lint_overflowing_bin_hex = literal out of range for `{$ty}`
     .negative_note = the literal `{$lit}` (decimal `{$dec}`) does not fit into the type `{$ty}`
@@ -1,5 +1,6 @@
 use crate::{
-    lints::{NonBindingLet, NonBindingLetSub},
+    lints::{NonBindingLet, NonBindingLetMustUse, NonBindingLetMustUseReason, NonBindingLetSub},
+    unused::{is_ty_must_use, MustUsePath},
     LateContext, LateLintPass, LintContext,
 };
 use rustc_errors::MultiSpan;
@@ -95,7 +96,45 @@
     "non-binding let on a synchronization lock"
 }
 
-declare_lint_pass!(LetUnderscore => [LET_UNDERSCORE_DROP, LET_UNDERSCORE_LOCK]);
+declare_lint! {
+    /// The `let_underscore_must_use` lint checks for values of a type marked
+    /// `#[must_use]` that are discarded by a wildcard pattern, either in a
+    /// `let _ = ...` statement or in a `match` whose only arms are `_`.
+    ///
+    /// ### Example
+    ///
+    /// ```rust
+    /// #![warn(let_underscore_must_use)]
+    /// fn parse(s: &str) -> Result<u32, std::num::ParseIntError> {
+    ///     s.parse()
+    /// }
+    ///
+    /// fn main() {
+    ///     let _ = parse("12");
+    /// }
+    /// ```
+    ///
+    /// {{produces}}
+    ///
+    /// ### Explanation
+    ///
+    /// Binding a value to `_` silences the [`unused_must_use`] lint, which
+    /// makes it easy to accidentally ignore errors or other values that are
+    /// meant to be inspected. This lint is "allow" by default because
+    /// `let _ = ...` is also the usual way to discard such a value on
+    /// purpose.
+    ///
+    /// [`unused_must_use`]: warn-by-default.html#unused-must-use
+    pub LET_UNDERSCORE_MUST_USE,
+    Allow,
+    "non-binding let on a value of a type marked `#[must_use]`"
+}
+
+declare_lint_pass!(LetUnderscore => [
+    LET_UNDERSCORE_DROP,
+    LET_UNDERSCORE_LOCK,
+    LET_UNDERSCORE_MUST_USE
+]);
 
 const SYNC_GUARD_SYMBOLS: [Symbol; 3] = [
     rustc_span::sym::MutexGuard,
@@ -110,17 +149,22 @@ fn check_local(&mut self, cx: &LateContext<'_>, local: &hir::Local<'_>) {
         }
         if let Some(init) = local.init {
             let init_ty = cx.typeck_results().expr_ty(init);
-            // If the type has a trivial Drop implementation, then it doesn't
-            // matter that we drop the value immediately.
-            if !init_ty.needs_drop(cx.tcx, cx.param_env) {
-                return;
-            }
             let is_sync_lock = match init_ty.kind() {
                 ty::Adt(adt, _) => SYNC_GUARD_SYMBOLS
                     .iter()
                     .any(|guard_symbol| cx.tcx.is_diagnostic_item(*guard_symbol, adt.did())),
                 _ => false,
             };
+            // Locks are already covered by `let_underscore_lock`, which explains
+            // why dropping them immediately is wrong.
+            if !is_sync_lock {
+                check_must_use_discarded(cx, init, local.pat);
+            }
+            // If the type has a trivial Drop implementation, then it doesn't
+            // matter that we drop the value immediately.
+            if !init_ty.needs_drop(cx.tcx, cx.param_env) {
+                return;
+            }
 
             let sub = NonBindingLetSub {
                 suggestion: local.pat.span,
@@ -147,4 +191,38 @@ fn check_local(&mut self, cx: &LateContext<'_>, local: &hir::Local<'_>) {
             }
         }
     }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'tcx>) {
+        if let hir::ExprKind::Match(scrutinee, arms, hir::MatchSource::Normal) = expr.kind
+            && let [first, ..] = arms
+            && arms.iter().all(|arm| matches!(arm.pat.kind, hir::PatKind::Wild))
+        {
+            check_must_use_discarded(cx, scrutinee, first.pat);
+        }
+    }
+}
+
+/// Lints a value of a `#[must_use]` type that is discarded by the wildcard pattern `pat`.
+fn check_must_use_discarded(cx: &LateContext<'_>, expr: &hir::Expr<'_>, pat: &hir::Pat<'_>) {
+    // A wildcard pattern doesn't move out of a place, so e.g. `let _ = r;` leaves `r`
+    // to be used later. Only a temporary is actually discarded.
+    if expr.is_place_expr(|_| false) {
+        return;
+    }
+    let ty = cx.typeck_results().expr_ty(expr);
+    let Some((def_id, reason)) =
+        is_ty_must_use(cx, ty, expr, expr.span).as_ref().and_then(MustUsePath::root_def)
+    else {
+        return;
+    };
+    cx.emit_spanned_lint(
+        LET_UNDERSCORE_MUST_USE,
+        expr.span,
+        NonBindingLetMustUse {
+            ty,
+            def: cx.tcx.def_path_str(def_id),
+            pat: pat.span,
+            reason: reason.map(|reason| NonBindingLetMustUseReason { reason }),
+        },
+    );
 }
@@ -289,7 +289,11 @@ macro_rules! add_lint_group {
         UNUSED_ASSIGNMENTS,
         DEAD_CODE,
         UNUSED_MUT,
-        UNREACHABLE_CODE,
+        UNREACHABLE_STATEMENTS,
+        UNREACHABLE_ARMS,
+        UNREACHABLE_CALL_ARGS,
+        UNREACHABLE_BLOCK_TAILS,
+        UNREACHABLE_EXPRESSIONS,
         UNREACHABLE_PATTERNS,
         UNUSED_MUST_USE,
         UNUSED_UNSAFE,
@@ -308,7 +312,21 @@ macro_rules! add_lint_group {
         MAP_UNIT_FN
     );
 
-    add_lint_group!("let_underscore", LET_UNDERSCORE_DROP, LET_UNDERSCORE_LOCK);
+    add_lint_group!(
+        "unreachable_code",
+        UNREACHABLE_STATEMENTS,
+        UNREACHABLE_ARMS,
+        UNREACHABLE_CALL_ARGS,
+        UNREACHABLE_BLOCK_TAILS,
+        UNREACHABLE_EXPRESSIONS
+    );
+
+    add_lint_group!(
+        "let_underscore",
+        LET_UNDERSCORE_DROP,
+        LET_UNDERSCORE_LOCK,
+        LET_UNDERSCORE_MUST_USE
+    );
 
     add_lint_group!(
         "rust_2018_idioms",
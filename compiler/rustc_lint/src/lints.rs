@@ -882,6 +882,24 @@ pub enum NonBindingLet {
     },
 }
 
+#[derive(LintDiagnostic)]
+#[diag(lint_non_binding_let_on_must_use)]
+#[note]
+pub struct NonBindingLetMustUse<'a> {
+    pub ty: Ty<'a>,
+    pub def: String,
+    #[label]
+    pub pat: Span,
+    #[subdiagnostic]
+    pub reason: Option<NonBindingLetMustUseReason>,
+}
+
+#[derive(Subdiagnostic)]
+#[note(lint_reason)]
+pub struct NonBindingLetMustUseReason {
+    pub reason: Symbol,
+}
+
 pub struct NonBindingLetSub {
     pub suggestion: Span,
     pub multi_suggestion_start: Span,
@@ -1288,6 +1306,28 @@ pub enum UseInclusiveRange<'a> {
     },
 }
 
+#[derive(LintDiagnostic)]
+#[diag(lint_range_pat_endpoint_out_of_range)]
+#[note]
+pub struct RangePatEndpointOutOfRange<'a> {
+    #[label]
+    pub span: Span,
+    pub ty: &'a str,
+    pub min: i128,
+    pub max: u128,
+    #[subdiagnostic]
+    pub cap: Option<RangePatEndpointCap<'a>>,
+}
+
+#[derive(Subdiagnostic)]
+#[suggestion(lint_range_pat_endpoint_cap, code = "{code}", applicability = "maybe-incorrect")]
+pub struct RangePatEndpointCap<'a> {
+    #[primary_span]
+    pub span: Span,
+    pub code: String,
+    pub bound: &'a str,
+}
+
 #[derive(LintDiagnostic)]
 #[diag(lint_overflowing_bin_hex)]
 pub struct OverflowingBinHex<'a> {
@@ -4,8 +4,8 @@
         AtomicOrderingFence, AtomicOrderingLoad, AtomicOrderingStore, ImproperCTypes,
         InvalidAtomicOrderingDiag, OnlyCastu8ToChar, OverflowingBinHex, OverflowingBinHexSign,
         OverflowingBinHexSub, OverflowingInt, OverflowingIntHelp, OverflowingLiteral,
-        OverflowingUInt, RangeEndpointOutOfRange, UnusedComparisons, UseInclusiveRange,
-        VariantSizeDifferencesDiag,
+        OverflowingUInt, RangeEndpointOutOfRange, RangePatEndpointCap, RangePatEndpointOutOfRange,
+        UnusedComparisons, UseInclusiveRange, VariantSizeDifferencesDiag,
     },
 };
 use crate::{LateContext, LateLintPass, LintContext};
@@ -201,6 +201,70 @@ fn lint_overflowing_range_endpoint<'tcx>(
     true
 }
 
+/// Special-cases the overflowing literal lint for the endpoints of range patterns, pointing out
+/// the range of the scrutinee type and suggesting to cap the range at its bounds where that makes
+/// sense. Returns `true` iff the lint was emitted.
+fn lint_overflowing_range_pat_endpoint<'tcx>(
+    cx: &LateContext<'tcx>,
+    lit: &hir::Lit,
+    e: &'tcx hir::Expr<'tcx>,
+    negative: bool,
+    min: i128,
+    max: u128,
+    ty: &str,
+) -> bool {
+    // A negative endpoint is the negation of the literal.
+    let endpoint = match cx.tcx.hir().get_parent(e.hir_id) {
+        Node::Expr(neg_expr) if negative => neg_expr,
+        _ => e,
+    };
+    let Node::Pat(pat) = cx.tcx.hir().get_parent(endpoint.hir_id) else { return false };
+    let hir::PatKind::Range(lo, hi, end) = pat.kind else { return false };
+
+    use rustc_ast::{LitIntType, LitKind};
+    let suffix = match lit.node {
+        LitKind::Int(_, LitIntType::Unsuffixed) => "",
+        _ => ty,
+    };
+    let is_hi = hi.map_or(false, |hi| hi.hir_id == endpoint.hir_id);
+    // Capping the end of a range whose start is out of range too would make it empty.
+    let lo_above_max = lo.map_or(false, |lo| match lo.kind {
+        ExprKind::Lit(lo_lit) => matches!(lo_lit.node, LitKind::Int(v, _) if v > max),
+        _ => false,
+    });
+    let cap = if !is_hi {
+        negative.then(|| RangePatEndpointCap {
+            span: endpoint.span,
+            code: format!("{min}{suffix}"),
+            bound: "min",
+        })
+    } else if !negative && !lo_above_max {
+        // Capping an exclusive range requires making it inclusive, so that the maximum value is
+        // still matched.
+        Some(match end {
+            hir::RangeEnd::Included => RangePatEndpointCap {
+                span: endpoint.span,
+                code: format!("{max}{suffix}"),
+                bound: "max",
+            },
+            hir::RangeEnd::Excluded => RangePatEndpointCap {
+                span: lo.map_or(pat.span, |lo| lo.span.shrink_to_hi()).to(endpoint.span),
+                code: format!("..={max}{suffix}"),
+                bound: "max",
+            },
+        })
+    } else {
+        None
+    };
+
+    cx.emit_spanned_lint(
+        OVERFLOWING_LITERALS,
+        endpoint.span,
+        RangePatEndpointOutOfRange { span: endpoint.span, ty, min, max, cap },
+    );
+    true
+}
+
 // For `isize` & `usize`, be conservative with the warnings, so that the
 // warnings are consistent between 32- and 64-bit platforms.
 fn int_ty_range(int_ty: ty::IntTy) -> (i128, i128) {
@@ -363,6 +427,10 @@ fn lint_int_literal<'tcx>(
             return;
         }
 
+        if lint_overflowing_range_pat_endpoint(cx, lit, e, negative, min, max, t.name_str()) {
+            return;
+        }
+
         let lit = cx
             .sess()
             .source_map()
@@ -414,6 +482,9 @@ fn lint_uint_literal<'tcx>(
             // The overflowing literal lint was emitted by `lint_overflowing_range_endpoint`.
             return;
         }
+        if lint_overflowing_range_pat_endpoint(cx, lit, e, false, 0, max, t.name_str()) {
+            return;
+        }
         if let Some(repr_str) = get_bin_hex_repr(cx, lit) {
             report_bin_hex_error(
                 cx,
@@ -627,7 +627,8 @@
 }
 
 declare_lint! {
-    /// The `unreachable_code` lint detects unreachable code paths.
+    /// The `unreachable_statements` lint detects statements that can never
+    /// be executed because a preceding statement or expression diverges.
     ///
     /// ### Example
     ///
@@ -643,9 +644,111 @@
     ///
     /// Unreachable code may signal a mistake or unfinished code. If the code
     /// is no longer in use, consider removing it.
-    pub UNREACHABLE_CODE,
+    ///
+    /// This lint is part of the `unreachable_code` lint group.
+    pub UNREACHABLE_STATEMENTS,
+    Warn,
+    "detects statements that can never be executed",
+    report_in_external_macro
+}
+
+declare_lint! {
+    /// The `unreachable_arms` lint detects `match` arms that can never be
+    /// executed because the scrutinee diverges.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,no_run
+    /// match panic!() {
+    ///     () => {}
+    /// }
+    /// ```
+    ///
+    /// {{produces}}
+    ///
+    /// ### Explanation
+    ///
+    /// If evaluating the scrutinee never completes, none of the arms can be
+    /// reached. This lint is part of the `unreachable_code` lint group.
+    pub UNREACHABLE_ARMS,
+    Warn,
+    "detects match arms that can never be executed",
+    report_in_external_macro
+}
+
+declare_lint! {
+    /// The `unreachable_call_args` lint detects call arguments that can never
+    /// be evaluated because a preceding argument diverges.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,no_run
+    /// fn f(_: u32, _: u32) {}
+    ///
+    /// f(todo!(), 5);
+    /// ```
+    ///
+    /// {{produces}}
+    ///
+    /// ### Explanation
+    ///
+    /// This is commonly caused by placeholders such as `todo!()` while code
+    /// is still being written. Allowing this lint silences that case without
+    /// hiding other unreachable code. This lint is part of the
+    /// `unreachable_code` lint group.
+    pub UNREACHABLE_CALL_ARGS,
+    Warn,
+    "detects call arguments that can never be evaluated",
+    report_in_external_macro
+}
+
+declare_lint! {
+    /// The `unreachable_block_tails` lint detects trailing expressions of
+    /// blocks that can never be evaluated because a statement of the block
+    /// diverges.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,no_run
+    /// fn f() -> u32 {
+    ///     return 1;
+    ///     2
+    /// }
+    /// ```
+    ///
+    /// {{produces}}
+    ///
+    /// ### Explanation
+    ///
+    /// Unreachable code may signal a mistake or unfinished code. If the code
+    /// is no longer in use, consider removing it. This lint is part of the
+    /// `unreachable_code` lint group.
+    pub UNREACHABLE_BLOCK_TAILS,
     Warn,
-    "detects unreachable code paths",
+    "detects block tail expressions that can never be evaluated",
+    report_in_external_macro
+}
+
+declare_lint! {
+    /// The `unreachable_expressions` lint detects unreachable expressions
+    /// that are not covered by a more specific `unreachable_*` lint.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,no_run
+    /// let x = (return, 5);
+    /// ```
+    ///
+    /// {{produces}}
+    ///
+    /// ### Explanation
+    ///
+    /// Unreachable code may signal a mistake or unfinished code. If the code
+    /// is no longer in use, consider removing it. This lint is part of the
+    /// `unreachable_code` lint group.
+    pub UNREACHABLE_EXPRESSIONS,
+    Warn,
+    "detects unreachable expressions",
     report_in_external_macro
 }
 
@@ -2749,6 +2852,97 @@
     @feature_gate = sym::strict_provenance;
 }
 
+declare_lint! {
+    /// The `lossy_numeric_casts` lint detects `as` casts between numeric types
+    /// that may lose information: integer casts to a narrower integer type, and
+    /// float to integer casts.
+    ///
+    /// ### Example
+    ///
+    /// ```rust
+    /// #![warn(lossy_numeric_casts)]
+    ///
+    /// fn main() {
+    ///     let x: u32 = 300;
+    ///     let _y = x as u8;
+    /// }
+    /// ```
+    ///
+    /// {{produces}}
+    ///
+    /// ### Explanation
+    ///
+    /// An `as` cast to a narrower integer type silently truncates the value,
+    /// and a float to integer cast drops the fractional part and saturates at
+    /// the bounds of the integer type. When the value is not known to fit,
+    /// [`TryFrom`] makes the failure case explicit.
+    ///
+    /// [`TryFrom`]: https://doc.rust-lang.org/std/convert/trait.TryFrom.html
+    pub LOSSY_NUMERIC_CASTS,
+    Allow,
+    "a numeric cast that may lose information is used"
+}
+
+declare_lint! {
+    /// The `numeric_fallback` lint detects integer and float literals whose
+    /// type is not constrained by anything and falls back to `i32` or `f64`.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,compile_fail
+    /// #![deny(numeric_fallback)]
+    ///
+    /// fn main() {
+    ///     let x = 1;
+    ///     println!("{}", x);
+    /// }
+    /// ```
+    ///
+    /// {{produces}}
+    ///
+    /// ### Explanation
+    ///
+    /// When the type of a numeric literal is not otherwise inferred, the
+    /// compiler picks `i32` for integers and `f64` for floats. Code where
+    /// the width of every value matters, such as cryptographic or embedded
+    /// code, may want to deny this and require the type to be written out,
+    /// with a suffix like `1u64` or a type annotation.
+    pub NUMERIC_FALLBACK,
+    Allow,
+    "the type of a numeric literal falls back to `i32` or `f64`"
+}
+
+declare_lint! {
+    /// The `pointer_integer_casts` lint detects `as` casts from a pointer to
+    /// an integer and from an integer to a pointer.
+    ///
+    /// ### Example
+    ///
+    /// ```rust
+    /// #![warn(pointer_integer_casts)]
+    ///
+    /// fn main() {
+    ///     let x: u8 = 37;
+    ///     let addr = &x as *const u8 as usize;
+    ///     let _ptr = addr as *const u8;
+    /// }
+    /// ```
+    ///
+    /// {{produces}}
+    ///
+    /// ### Explanation
+    ///
+    /// Casting a pointer to an integer exposes its provenance, and casting an
+    /// integer back to a pointer has to guess which provenance to use. Code
+    /// that tracks provenance carefully, or that is meant to be portable to
+    /// targets with different pointer widths, may want to deny such casts.
+    /// Unlike `lossy_provenance_casts` and `fuzzy_provenance_casts`, this lint
+    /// does not require the `strict_provenance` feature.
+    pub POINTER_INTEGER_CASTS,
+    Allow,
+    "a cast between a pointer and an integer is used"
+}
+
 declare_lint! {
     /// The `const_evaluatable_unchecked` lint detects a generic constant used
     /// in a type.
@@ -3319,6 +3513,7 @@
         LARGE_ASSIGNMENTS,
         LATE_BOUND_LIFETIME_ARGUMENTS,
         LEGACY_DERIVE_HELPERS,
+        LOSSY_NUMERIC_CASTS,
         LOSSY_PROVENANCE_CASTS,
         MACRO_EXPANDED_MACRO_EXPORTS_ACCESSED_BY_ABSOLUTE_PATHS,
         MACRO_USE_EXTERN_CRATE,
@@ -3329,9 +3524,11 @@
         NAMED_ARGUMENTS_USED_POSITIONALLY,
         NON_EXHAUSTIVE_OMITTED_PATTERNS,
         NONTRIVIAL_STRUCTURAL_MATCH,
+        NUMERIC_FALLBACK,
         ORDER_DEPENDENT_TRAIT_OBJECTS,
         OVERLAPPING_RANGE_ENDPOINTS,
         PATTERNS_IN_FNS_WITHOUT_BODY,
+        POINTER_INTEGER_CASTS,
         POINTER_STRUCTURAL_MATCH,
         PRIVATE_IN_PUBLIC,
         PROC_MACRO_BACK_COMPAT,
@@ -3360,8 +3557,12 @@
         UNINHABITED_STATIC,
         UNKNOWN_CRATE_TYPES,
         UNKNOWN_LINTS,
-        UNREACHABLE_CODE,
+        UNREACHABLE_ARMS,
+        UNREACHABLE_BLOCK_TAILS,
+        UNREACHABLE_CALL_ARGS,
+        UNREACHABLE_EXPRESSIONS,
         UNREACHABLE_PATTERNS,
+        UNREACHABLE_STATEMENTS,
         UNSAFE_OP_IN_UNSAFE_FN,
         UNSTABLE_NAME_COLLISIONS,
         UNSTABLE_SYNTAX_PRE_EXPANSION,
@@ -190,4 +190,9 @@ pub fn provide(providers: &mut Providers) {
     providers.in_scope_traits_map = |tcx, id| {
         tcx.hir_crate(()).owners[id.def_id].as_owner().map(|owner_info| &owner_info.trait_map)
     };
+    providers.format_arguments_map = |tcx, id| {
+        tcx.hir_crate(()).owners[id.def_id]
+            .as_owner()
+            .map(|owner_info| &owner_info.format_arguments)
+    };
 }
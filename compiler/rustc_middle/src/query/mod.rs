@@ -1482,6 +1482,10 @@
         -> Option<&'tcx FxHashMap<ItemLocalId, Box<[TraitCandidate]>>> {
         desc { "getting traits in scope at a block" }
     }
+    query format_arguments_map(_: hir::OwnerId)
+        -> Option<&'tcx FxHashMap<ItemLocalId, Option<usize>>> {
+        desc { "getting the arguments of `format_args!` invocations" }
+    }
 
     query impl_defaultness(def_id: DefId) -> hir::Defaultness {
         desc { |tcx| "looking up whether `{}` is a default impl", tcx.def_path_str(def_id) }
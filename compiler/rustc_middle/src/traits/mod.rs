@@ -294,6 +294,12 @@ pub enum ObligationCauseCode<'tcx> {
     TupleInitializerSized,
     /// `S { ... }` must be `Sized`.
     StructInitializerSized,
+    /// The value of the last field in `S { ... }` must be `Sized`, if that field may be
+    /// dynamically sized.
+    StructInitializerFieldSized {
+        /// The field's definition.
+        field: DefId,
+    },
     /// Type of each variable must be `Sized`.
     VariableType(hir::HirId),
     /// Argument type must be `Sized`.
@@ -309,6 +315,8 @@ pub enum ObligationCauseCode<'tcx> {
         /// If element is a `const fn` we display a help message suggesting to move the
         /// function call to a new `const` item while saying that `T` doesn't implement `Copy`.
         is_const_fn: bool,
+        /// The `[expr; N]` expression, used to suggest alternatives to it.
+        repeat_hir_id: hir::HirId,
     },
 
     /// Types of fields (other than the last, except for packed structs) in a struct must be sized.
@@ -335,10 +343,26 @@ pub enum ObligationCauseCode<'tcx> {
         arg_hir_id: hir::HirId,
         /// The node of the function call.
         call_hir_id: hir::HirId,
+        /// The argument-position `impl Trait` parameter that the argument is passed to,
+        /// if the obligation comes from an `impl Trait` nested in its bounds.
+        apit_param: Option<DefId>,
         /// The obligation introduced by this argument.
         parent_code: InternedObligationCauseCode<'tcx>,
     },
 
+    /// An argument of a builtin macro like `format_args!` must satisfy a bound of the code
+    /// the macro desugars into.
+    BuiltinMacroArgument {
+        /// The name of the macro the user invoked, which may be one expanding to the builtin
+        /// macro, like `println`.
+        macro_name: Symbol,
+        /// The position of the argument in the user's invocation, or `None` if the argument
+        /// was implicitly captured, like `x` in `format!("{x}")`.
+        arg_idx: Option<usize>,
+        /// The obligation introduced by the desugared code.
+        parent_code: InternedObligationCauseCode<'tcx>,
+    },
+
     /// Error derived when matching traits/impls; see ObligationCause for more details
     CompareImplItemObligation {
         impl_item_def_id: LocalDefId,
@@ -419,7 +443,9 @@ pub enum ObligationCauseCode<'tcx> {
 
     ForLoopIterator,
 
-    QuestionMark,
+    /// The `?` operator. Carries the `HirId` of the return type of the enclosing function when
+    /// the `?` returns from it, rather than from a `try` block.
+    QuestionMark(Option<hir::HirId>),
 
     /// Well-formed checking. If a `WellFormedLoc` is provided,
     /// then it will be used to perform HIR-based wf checking
@@ -495,7 +521,8 @@ pub fn peel_derives(&self) -> &Self {
 
     pub fn parent(&self) -> Option<(&Self, Option<ty::PolyTraitPredicate<'tcx>>)> {
         match self {
-            FunctionArgumentObligation { parent_code, .. } => Some((parent_code, None)),
+            FunctionArgumentObligation { parent_code, .. }
+            | BuiltinMacroArgument { parent_code, .. } => Some((parent_code, None)),
             BuiltinDerivedObligation(derived)
             | DerivedObligation(derived)
             | ImplDerivedObligation(box ImplDerivedObligationCause { derived, .. }) => {
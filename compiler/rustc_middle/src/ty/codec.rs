@@ -411,6 +411,17 @@ fn decode(decoder: &mut D) -> &'tcx Self {
     }
 }
 
+impl<'tcx, D: TyDecoder<I = TyCtxt<'tcx>>> RefDecodable<'tcx, D>
+    for ty::List<ty::adjustment::Adjustment<'tcx>>
+{
+    fn decode(decoder: &mut D) -> &'tcx Self {
+        let len = decoder.read_usize();
+        decoder.interner().mk_adjustments_from_iter(
+            (0..len).map::<ty::adjustment::Adjustment<'tcx>, _>(|_| Decodable::decode(decoder)),
+        )
+    }
+}
+
 impl_decodable_via_ref! {
     &'tcx ty::TypeckResults<'tcx>,
     &'tcx ty::List<Ty<'tcx>>,
@@ -423,6 +434,7 @@ fn decode(decoder: &mut D) -> &'tcx Self {
     &'tcx ty::List<ty::BoundVariableKind>,
     &'tcx ty::List<ty::Predicate<'tcx>>,
     &'tcx ty::List<FieldIdx>,
+    &'tcx ty::List<ty::adjustment::Adjustment<'tcx>>,
 }
 
 #[macro_export]
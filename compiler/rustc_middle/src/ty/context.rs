@@ -22,6 +22,7 @@
 use crate::traits;
 use crate::traits::solve;
 use crate::traits::solve::{ExternalConstraints, ExternalConstraintsData};
+use crate::ty::adjustment::Adjustment;
 use crate::ty::{
     self, AdtDef, AdtDefData, AdtKind, Binder, Const, ConstData, FloatTy, FloatVar, FloatVid,
     GenericParamDefKind, ImplPolarity, InferTy, IntTy, IntVar, IntVid, List, ParamConst, ParamTy,
@@ -141,6 +142,7 @@ pub struct CtxtInterners<'tcx> {
     adt_def: InternedSet<'tcx, AdtDefData>,
     external_constraints: InternedSet<'tcx, ExternalConstraintsData<'tcx>>,
     fields: InternedSet<'tcx, List<FieldIdx>>,
+    adjustments: InternedSet<'tcx, List<Adjustment<'tcx>>>,
 }
 
 impl<'tcx> CtxtInterners<'tcx> {
@@ -165,6 +167,7 @@ fn new(arena: &'tcx WorkerLocal<Arena<'tcx>>) -> CtxtInterners<'tcx> {
             adt_def: Default::default(),
             external_constraints: Default::default(),
             fields: Default::default(),
+            adjustments: Default::default(),
         }
     }
 
@@ -1552,6 +1555,7 @@ impl<'tcx> TyCtxt<'tcx> {
     place_elems: pub mk_place_elems(PlaceElem<'tcx>),
     bound_variable_kinds: pub mk_bound_variable_kinds(ty::BoundVariableKind),
     fields: pub mk_fields(FieldIdx),
+    adjustments: pub mk_adjustments(Adjustment<'tcx>),
 );
 
 impl<'tcx> TyCtxt<'tcx> {
@@ -2238,6 +2242,14 @@ pub fn mk_fields_from_iter<I, T>(self, iter: I) -> T::Output
         T::collect_and_apply(iter, |xs| self.mk_fields(xs))
     }
 
+    pub fn mk_adjustments_from_iter<I, T>(self, iter: I) -> T::Output
+    where
+        I: Iterator<Item = T>,
+        T: CollectAndApply<Adjustment<'tcx>, &'tcx List<Adjustment<'tcx>>>,
+    {
+        T::collect_and_apply(iter, |xs| self.mk_adjustments(xs))
+    }
+
     pub fn mk_substs_trait(
         self,
         self_ty: Ty<'tcx>,
@@ -2335,6 +2347,14 @@ pub fn in_scope_traits(self, id: HirId) -> Option<&'tcx [TraitCandidate]> {
         Some(candidates)
     }
 
+    /// If `id` is a call to a `fmt::Argument` constructor that `format_args!` was lowered into,
+    /// returns the position of the formatted argument in the invocation, or `Some(None)` if the
+    /// argument was captured from the format string.
+    pub fn format_argument_position(self, id: HirId) -> Option<Option<usize>> {
+        let map = self.format_arguments_map(id.owner)?;
+        map.get(&id.local_id).copied()
+    }
+
     pub fn named_bound_var(self, id: HirId) -> Option<resolve_bound_vars::ResolvedArg> {
         debug!(?id, "named_region");
         self.named_variable_map(id.owner).and_then(|map| map.get(&id.local_id).cloned())
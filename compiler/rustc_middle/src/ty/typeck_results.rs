@@ -71,7 +71,7 @@ pub struct TypeckResults<'tcx> {
     /// `AscribeUserType` statement in MIR.
     pub user_provided_sigs: LocalDefIdMap<CanonicalPolyFnSig<'tcx>>,
 
-    adjustments: ItemLocalMap<Vec<ty::adjustment::Adjustment<'tcx>>>,
+    adjustments: ItemLocalMap<&'tcx ty::List<ty::adjustment::Adjustment<'tcx>>>,
 
     /// Stores the actual binding mode for all instances of hir::BindingAnnotation.
     pat_binding_modes: ItemLocalMap<BindingMode>,
@@ -95,6 +95,10 @@ pub struct TypeckResults<'tcx> {
     /// not all closures are present in the map.
     closure_kind_origins: ItemLocalMap<(Span, HirPlace<'tcx>)>,
 
+    /// Records the span of the expectation that the signature of each closure
+    /// was deduced from; not all closures are present in the map.
+    closure_sig_origins: ItemLocalMap<Span>,
+
     /// For each fn, records the "liberated" types of its arguments
     /// and return type. Liberated means that all bound regions
     /// (including late-bound regions) are replaced with free
@@ -253,7 +257,7 @@ pub struct GeneratorDiagnosticData<'tcx> {
     pub generator_interior_types: ty::Binder<'tcx, Vec<GeneratorInteriorTypeCause<'tcx>>>,
     pub hir_owner: DefId,
     pub nodes_types: ItemLocalMap<Ty<'tcx>>,
-    pub adjustments: ItemLocalMap<Vec<ty::adjustment::Adjustment<'tcx>>>,
+    pub adjustments: ItemLocalMap<&'tcx ty::List<ty::adjustment::Adjustment<'tcx>>>,
 }
 
 impl<'tcx> TypeckResults<'tcx> {
@@ -270,6 +274,7 @@ pub fn new(hir_owner: OwnerId) -> TypeckResults<'tcx> {
             pat_binding_modes: Default::default(),
             pat_adjustments: Default::default(),
             closure_kind_origins: Default::default(),
+            closure_sig_origins: Default::default(),
             liberated_fn_sigs: Default::default(),
             fru_field_types: Default::default(),
             coercion_casts: Default::default(),
@@ -425,13 +430,15 @@ pub fn expr_ty_opt(&self, expr: &hir::Expr<'_>) -> Option<Ty<'tcx>> {
         self.node_type_opt(expr.hir_id)
     }
 
-    pub fn adjustments(&self) -> LocalTableInContext<'_, Vec<ty::adjustment::Adjustment<'tcx>>> {
+    pub fn adjustments(
+        &self,
+    ) -> LocalTableInContext<'_, &'tcx ty::List<ty::adjustment::Adjustment<'tcx>>> {
         LocalTableInContext { hir_owner: self.hir_owner, data: &self.adjustments }
     }
 
     pub fn adjustments_mut(
         &mut self,
-    ) -> LocalTableInContextMut<'_, Vec<ty::adjustment::Adjustment<'tcx>>> {
+    ) -> LocalTableInContextMut<'_, &'tcx ty::List<ty::adjustment::Adjustment<'tcx>>> {
         LocalTableInContextMut { hir_owner: self.hir_owner, data: &mut self.adjustments }
     }
 
@@ -506,6 +513,14 @@ pub fn closure_kind_origins_mut(
         LocalTableInContextMut { hir_owner: self.hir_owner, data: &mut self.closure_kind_origins }
     }
 
+    pub fn closure_sig_origins(&self) -> LocalTableInContext<'_, Span> {
+        LocalTableInContext { hir_owner: self.hir_owner, data: &self.closure_sig_origins }
+    }
+
+    pub fn closure_sig_origins_mut(&mut self) -> LocalTableInContextMut<'_, Span> {
+        LocalTableInContextMut { hir_owner: self.hir_owner, data: &mut self.closure_sig_origins }
+    }
+
     pub fn liberated_fn_sigs(&self) -> LocalTableInContext<'_, ty::FnSig<'tcx>> {
         LocalTableInContext { hir_owner: self.hir_owner, data: &self.liberated_fn_sigs }
     }
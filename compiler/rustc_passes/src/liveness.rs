@@ -1317,7 +1317,7 @@ fn warn_about_unreachable<'desc>(
             // is indeed `!`.
 
             self.ir.tcx.emit_spanned_lint(
-                lint::builtin::UNREACHABLE_CODE,
+                lint::builtin::UNREACHABLE_EXPRESSIONS,
                 expr_id,
                 expr_span,
                 errors::UnreachableDueToUninhabited {
@@ -597,6 +597,9 @@ struct DiagnosticMetadata<'ast> {
     /// Only used for better errors on `let <pat>: <expr, not type>;`.
     current_let_binding: Option<(Span, Option<Span>, Option<Span>)>,
 
+    /// Only used for better errors on `let x = f(&x);`, where `x` isn't in scope yet.
+    in_let_initializer: Option<&'ast Local>,
+
     /// Used to detect possible `if let` written without `let` and to provide structured suggestion.
     in_if_condition: Option<&'ast Expr>,
 
@@ -3062,7 +3065,9 @@ fn resolve_local(&mut self, local: &'ast Local) {
 
         // Resolve the initializer.
         if let Some((init, els)) = local.kind.init_else_opt() {
+            let old = self.diagnostic_metadata.in_let_initializer.replace(local);
             self.visit_expr(init);
+            self.diagnostic_metadata.in_let_initializer = old;
 
             // Resolve the `else` block
             if let Some(els) = els {
@@ -334,6 +334,8 @@ pub(crate) fn smart_resolve_report_errors(
 
         self.suggest_bare_struct_literal(&mut err);
 
+        self.suggest_splitting_self_referential_let(&mut err, source, path);
+
         if self.suggest_pattern_match_with_let(&mut err, source, span) {
             // Fallback label.
             err.span_label(base_error.span, base_error.fallback_label);
@@ -369,6 +371,43 @@ pub(crate) fn smart_resolve_report_errors(
         (err, candidates)
     }
 
+    /// Given `let x = f(&x);` where no other `x` is in scope, explains that the binding can't be
+    /// used in its own initializer, and suggests declaring it beforehand.
+    fn suggest_splitting_self_referential_let(
+        &mut self,
+        err: &mut Diagnostic,
+        source: PathSource<'_>,
+        path: &[Segment],
+    ) {
+        let Some(local) = self.diagnostic_metadata.in_let_initializer else { return };
+        let ([segment], ValueNS) = (path, source.namespace()) else { return };
+        let ident = segment.ident.normalize_to_macros_2_0();
+        let mut binding = None;
+        local.pat.walk(&mut |pat| {
+            if let ast::PatKind::Ident(_, pat_ident, _) = pat.kind
+                && pat_ident.normalize_to_macros_2_0() == ident
+            {
+                binding = Some(pat_ident);
+            }
+            binding.is_none()
+        });
+        let Some(binding) = binding else { return };
+
+        err.span_note(
+            binding.span,
+            format!("`{binding}` is only in scope after the `let` statement declaring it"),
+        );
+        let sm = self.r.tcx.sess.source_map();
+        if let Some(indent) = sm.indentation_before(local.span) {
+            err.span_suggestion_verbose(
+                local.span.shrink_to_lo(),
+                format!("if `{binding}` should start from a previous value, declare it first"),
+                format!("let {binding} = /* value */;\n{indent}"),
+                Applicability::HasPlaceholders,
+            );
+        }
+    }
+
     fn detect_assoct_type_constraint_meant_as_path(
         &self,
         err: &mut Diagnostic,
@@ -612,6 +612,15 @@ pub enum TraitSolver {
     Next,
 }
 
+/// How typeck handles a node that has no type recorded for it.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum MissingNodeTypes {
+    /// Always ICE, even if errors were reported that could have caused the type to be missing.
+    Ice,
+    /// Recover with an error type and a delayed bug if errors were reported for the body.
+    Error,
+}
+
 pub enum Input {
     /// Load source code from a file.
     File(PathBuf),
@@ -2892,7 +2901,7 @@ pub(crate) mod dep_tracking {
     use super::{
         BranchProtection, CFGuard, CFProtection, CrateType, DebugInfo, ErrorOutputType,
         InstrumentCoverage, InstrumentXRay, LdImpl, LinkerPluginLto, LocationDetail, LtoCli,
-        OomStrategy, OptLevel, OutputType, OutputTypes, Passes, ResolveDocLinks,
+        MissingNodeTypes, OomStrategy, OptLevel, OutputType, OutputTypes, Passes, ResolveDocLinks,
         SourceFileHashAlgorithm, SplitDwarfKind, SwitchWithOptPath, SymbolManglingVersion,
         TraitSolver, TrimmedDefPaths,
     };
@@ -2997,6 +3006,7 @@ fn hash(
         OomStrategy,
         LanguageIdentifier,
         TraitSolver,
+        MissingNodeTypes,
     );
 
     impl<T1, T2> DepTrackingHash for (T1, T2)
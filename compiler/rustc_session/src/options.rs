@@ -390,6 +390,7 @@ mod desc {
     pub const parse_treat_err_as_bug: &str = "either no value or a number bigger than 0";
     pub const parse_trait_solver: &str =
         "one of the supported solver modes (`classic`, `chalk`, or `next`)";
+    pub const parse_missing_node_types: &str = "either `ice` or `error`";
     pub const parse_lto: &str =
         "either a boolean (`yes`, `no`, `on`, `off`, etc), `thin`, `fat`, or omitted";
     pub const parse_linker_plugin_lto: &str =
@@ -992,6 +993,15 @@ pub(crate) fn parse_trait_solver(slot: &mut TraitSolver, v: Option<&str>) -> boo
         true
     }
 
+    pub(crate) fn parse_missing_node_types(slot: &mut MissingNodeTypes, v: Option<&str>) -> bool {
+        match v {
+            Some("ice") => *slot = MissingNodeTypes::Ice,
+            Some("error") => *slot = MissingNodeTypes::Error,
+            _ => return false,
+        }
+        true
+    }
+
     pub(crate) fn parse_lto(slot: &mut LtoCli, v: Option<&str>) -> bool {
         if v.is_some() {
             let mut bool_arg = None;
@@ -1375,6 +1385,12 @@ pub(crate) fn parse_proc_macro_execution_strategy(
         "the codegen unit partitioning strategy to use"),
     codegen_backend: Option<String> = (None, parse_opt_string, [TRACKED],
         "the backend to use"),
+    coercion_nesting_limit: Option<usize> = (None, parse_opt_number, [TRACKED],
+        "the maximum nesting depth of blocks, `if` and `match` expressions in a function body \
+        whose types are checked (default: no limit)"),
+    coercion_sites_limit: Option<usize> = (None, parse_opt_number, [TRACKED],
+        "the maximum number of expressions whose types are unified into one, such as the arms \
+        of a `match` (default: no limit)"),
     combine_cgu: bool = (false, parse_bool, [TRACKED],
         "combine CGUs into a single one"),
     crate_attr: Vec<String> = (Vec::new(), parse_string_push, [TRACKED],
@@ -1551,6 +1567,9 @@ pub(crate) fn parse_proc_macro_execution_strategy(
         the same values as the target option of the same name"),
     meta_stats: bool = (false, parse_bool, [UNTRACKED],
         "gather metadata statistics (default: no)"),
+    method_chain_types: bool = (false, parse_bool, [UNTRACKED],
+        "when a method call chain ends in a type mismatch, list the receiver and output \
+        types of each call in the chain (default: no)"),
     mir_emit_retag: bool = (false, parse_bool, [TRACKED],
         "emit Retagging MIR statements, interpreted e.g., by miri; implies -Zmir-opt-level=0 \
         (default: no)"),
@@ -1624,6 +1643,9 @@ pub(crate) fn parse_proc_macro_execution_strategy(
         "use a more precise version of drop elaboration for matches on enums (default: yes). \
         This results in better codegen, but has caused miscompilations on some tier 2 platforms. \
         See #77382 and #74551."),
+    print_adjustments: Option<String> = (None, parse_opt_string, [UNTRACKED],
+        "print the type and adjustments of every expression in the item with the given \
+        path (e.g. `-Z print-adjustments=my_mod::my_fn`) after type checking"),
     print_fuel: Option<String> = (None, parse_opt_string, [TRACKED],
         "make rustc print the total optimization fuel used by a crate"),
     print_llvm_passes: bool = (false, parse_bool, [UNTRACKED],
@@ -1782,6 +1804,9 @@ pub(crate) fn parse_proc_macro_execution_strategy(
         "generate trap instructions for unreachable intrinsics (default: use target setting, usually yes)"),
     treat_err_as_bug: Option<NonZeroUsize> = (None, parse_treat_err_as_bug, [TRACKED],
         "treat error number `val` that occurs as bug"),
+    treat_missing_node_types: MissingNodeTypes = (MissingNodeTypes::Error, parse_missing_node_types, [TRACKED],
+        "how typeck handles nodes with no recorded type: `ice`, or `error` to recover if errors \
+        were already reported (default: error)"),
     trim_diagnostic_paths: bool = (true, parse_bool, [UNTRACKED],
         "in diagnostics, use heuristics to shorten paths referring to items"),
     tune_cpu: Option<String> = (None, parse_opt_string, [TRACKED],
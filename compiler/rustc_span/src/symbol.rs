@@ -493,6 +493,7 @@
         cmp_min,
         cmpxchg16b_target_feature,
         cmse_nonsecure_entry,
+        coerce_borrowed_pointers,
         coerce_unsized,
         cold,
         collapse_debuginfo,
@@ -28,7 +28,7 @@
 use rustc_infer::infer::error_reporting::TypeErrCtxt;
 use rustc_infer::infer::{InferOk, TypeTrace};
 use rustc_middle::traits::select::OverflowError;
-use rustc_middle::traits::SelectionOutputTypeParameterMismatch;
+use rustc_middle::traits::{SelectionOutputTypeParameterMismatch, StatementAsExpression};
 use rustc_middle::ty::abstract_const::NotConstEvaluatable;
 use rustc_middle::ty::error::{ExpectedFound, TypeError};
 use rustc_middle::ty::fold::{TypeFolder, TypeSuperFoldable};
@@ -41,7 +41,7 @@
 use rustc_session::Limit;
 use rustc_span::def_id::LOCAL_CRATE;
 use rustc_span::symbol::sym;
-use rustc_span::{ExpnKind, Span, DUMMY_SP};
+use rustc_span::{DesugaringKind, ExpnKind, Span, DUMMY_SP};
 use std::fmt;
 use std::iter;
 use std::ops::ControlFlow;
@@ -698,7 +698,8 @@ fn report_selection_error(
                         let (message, note, append_const_msg) = if is_try_conversion {
                             (
                                 Some(format!(
-                                    "`?` couldn't convert the error to `{}`",
+                                    "`?` couldn't convert the error from `{}` to `{}`",
+                                    trait_ref.skip_binder().substs.type_at(1),
                                     trait_ref.skip_binder().self_ty(),
                                 )),
                                 Some(
@@ -735,14 +736,15 @@ fn report_selection_error(
 
                         let mut err = struct_span_err!(self.tcx.sess, span, E0277, "{}", err_msg);
 
-                        if is_try_conversion && let Some(ret_span) = self.return_type_span(&obligation) {
+                        if is_try_conversion && let Some(ret_ty) = self.question_mark_return_ty(&obligation) {
                             err.span_label(
-                                ret_span,
+                                ret_ty.span,
                                 format!(
                                     "expected `{}` because of this",
                                     trait_ref.skip_binder().self_ty()
                                 ),
                             );
+                            self.suggest_question_mark_error_types(&mut err, &obligation, trait_ref);
                         }
 
                         if Some(trait_ref.def_id()) == tcx.lang_items().tuple_trait() {
@@ -957,7 +959,8 @@ fn report_selection_error(
                         // Return early if the trait is Debug or Display and the invocation
                         // originates within a standard library macro, because the output
                         // is otherwise overwhelming and unhelpful (see #85844 for an
-                        // example).
+                        // example). Arguments of builtin macros are exempt, as their notes
+                        // only point out which argument of the macro is at fault.
 
                         let in_std_macro =
                             match obligation.cause.span.ctxt().outer_expn_data().macro_def_id {
@@ -968,7 +971,16 @@ fn report_selection_error(
                                 None => false,
                             };
 
+                        let is_builtin_macro_arg = std::iter::successors(
+                            Some(obligation.cause.code()),
+                            |code| code.parent().map(|(parent, _)| parent),
+                        )
+                        .any(|code| {
+                            matches!(code, ObligationCauseCode::BuiltinMacroArgument { .. })
+                        });
+
                         if in_std_macro
+                            && !is_builtin_macro_arg
                             && matches!(
                                 self.tcx.get_diagnostic_name(trait_ref.def_id()),
                                 Some(sym::Debug | sym::Display)
@@ -1179,6 +1191,7 @@ fn report_projection_error(
 
     fn maybe_detailed_projection_msg(
         &self,
+        span: Span,
         pred: ty::ProjectionPredicate<'tcx>,
         normalized_ty: ty::Term<'tcx>,
         expected_ty: ty::Term<'tcx>,
@@ -1337,6 +1350,13 @@ fn report_closure_error(
         kind: ty::ClosureKind,
     ) -> DiagnosticBuilder<'tcx, ErrorGuaranteed>;
 
+    fn suggest_remove_semicolon_in_async_block(
+        &self,
+        err: &mut Diagnostic,
+        pred: ty::ProjectionPredicate<'tcx>,
+        expected_term: ty::Term<'tcx>,
+    );
+
     fn report_type_parameter_mismatch_cyclic_type_error(
         &self,
         obligation: &PredicateObligation<'tcx>,
@@ -1546,7 +1566,12 @@ fn report_projection_error(
 
             let msg = values
                 .and_then(|(predicate, _, normalized_term, expected_term)| {
-                    self.maybe_detailed_projection_msg(predicate, normalized_term, expected_term)
+                    self.maybe_detailed_projection_msg(
+                        obligation.cause.span,
+                        predicate,
+                        normalized_term,
+                        expected_term,
+                    )
                 })
                 .unwrap_or_else(|| {
                     with_forced_trimmed_paths!(format!(
@@ -1633,6 +1658,9 @@ fn report_projection_error(
                 true,
                 false,
             );
+            if let Some((pred, _, _, expected_term)) = values {
+                self.suggest_remove_semicolon_in_async_block(&mut diag, pred, expected_term);
+            }
             self.note_obligation_cause(&mut diag, obligation);
             diag.emit();
         });
@@ -1640,6 +1668,7 @@ fn report_projection_error(
 
     fn maybe_detailed_projection_msg(
         &self,
+        span: Span,
         pred: ty::ProjectionPredicate<'tcx>,
         normalized_ty: ty::Term<'tcx>,
         expected_ty: ty::Term<'tcx>,
@@ -1668,6 +1697,14 @@ fn maybe_detailed_projection_msg(
                     "expected `{self_ty}` to be an iterator that yields `{expected_ty}`, but it \
                      yields `{normalized_ty}`"
                 ))
+            } else if Some(trait_def_id) == self.tcx.lang_items().try_trait()
+                && span.is_desugaring(DesugaringKind::TryBlock)
+            {
+                // The tail of a `try` block is wrapped in `Try::from_output`.
+                Some(format!(
+                    "expected `try` block of type `{self_ty}` to produce `{normalized_ty}`, but it \
+                     produces `{expected_ty}`"
+                ))
             } else {
                 None
             }
@@ -3027,6 +3064,45 @@ fn report_closure_error(
         err
     }
 
+    /// If an `async` block resolves to `()` only because of a trailing semicolon after
+    /// an expression of the expected output type, suggest removing that semicolon.
+    fn suggest_remove_semicolon_in_async_block(
+        &self,
+        err: &mut Diagnostic,
+        pred: ty::ProjectionPredicate<'tcx>,
+        expected_term: ty::Term<'tcx>,
+    ) {
+        if Some(pred.projection_ty.trait_def_id(self.tcx)) != self.tcx.lang_items().future_trait() {
+            return;
+        }
+        let ty::Generator(def_id, ..) = *pred.projection_ty.self_ty().kind() else { return };
+        let Some(def_id) = def_id.as_local() else { return };
+        let Some(expected_ty) = expected_term.ty() else { return };
+        if self.tcx.generator_kind(def_id)
+            != Some(hir::GeneratorKind::Async(hir::AsyncGeneratorKind::Block))
+        {
+            return;
+        }
+        // The types of the statements in the block are only available while
+        // type-checking the body the block is part of.
+        let hir_id = self.tcx.hir().local_def_id_to_hir_id(def_id);
+        if self.typeck_results.as_ref().map_or(true, |results| results.hir_owner != hir_id.owner) {
+            return;
+        }
+        let body = self.tcx.hir().body(self.tcx.hir().body_owned_by(def_id));
+        let hir::ExprKind::Block(blk, _) = body.value.kind else { return };
+        if let Some((span_semi, StatementAsExpression::CorrectType)) =
+            self.could_remove_semicolon(blk, expected_ty)
+        {
+            err.span_suggestion_short(
+                span_semi,
+                "remove this semicolon to resolve to this value",
+                "",
+                Applicability::MachineApplicable,
+            );
+        }
+    }
+
     fn report_type_parameter_mismatch_cyclic_type_error(
         &self,
         obligation: &PredicateObligation<'tcx>,
@@ -35,7 +35,7 @@
     TypeSuperFoldable, TypeVisitableExt, TypeckResults,
 };
 use rustc_span::def_id::LocalDefId;
-use rustc_span::symbol::{sym, Ident, Symbol};
+use rustc_span::symbol::{kw, sym, Ident, Symbol};
 use rustc_span::{BytePos, DesugaringKind, ExpnKind, MacroKind, Span, DUMMY_SP};
 use rustc_target::spec::abi;
 use std::iter;
@@ -256,7 +256,17 @@ fn suggest_semicolon_removal(
         trait_pred: ty::PolyTraitPredicate<'tcx>,
     ) -> bool;
 
-    fn return_type_span(&self, obligation: &PredicateObligation<'tcx>) -> Option<Span>;
+    fn question_mark_return_ty(
+        &self,
+        obligation: &PredicateObligation<'tcx>,
+    ) -> Option<&'tcx hir::Ty<'tcx>>;
+
+    fn suggest_question_mark_error_types(
+        &self,
+        err: &mut Diagnostic,
+        obligation: &PredicateObligation<'tcx>,
+        trait_ref: ty::PolyTraitRef<'tcx>,
+    );
 
     fn suggest_impl_trait(
         &self,
@@ -285,6 +295,8 @@ fn report_closure_arg_mismatch(
 
     fn note_conflicting_closure_bounds(
         &self,
+        span: Span,
+        found: ty::PolyTraitRef<'tcx>,
         cause: &ObligationCauseCode<'tcx>,
         err: &mut DiagnosticBuilder<'tcx, ErrorGuaranteed>,
     );
@@ -366,6 +378,16 @@ fn note_function_argument_obligation(
         param_env: ty::ParamEnv<'tcx>,
         predicate: ty::Predicate<'tcx>,
         call_hir_id: HirId,
+        apit_param: Option<DefId>,
+    );
+    fn note_apit_argument(&self, err: &mut Diagnostic, arg_hir_id: HirId, apit_param: DefId);
+    fn suggest_repeat_alternatives(
+        &self,
+        err: &mut Diagnostic,
+        predicate: ty::Predicate<'tcx>,
+        param_env: ty::ParamEnv<'tcx>,
+        body_id: LocalDefId,
+        repeat: &hir::Expr<'_>,
     );
     fn point_at_chain(
         &self,
@@ -1777,13 +1799,91 @@ fn suggest_semicolon_removal(
         false
     }
 
-    fn return_type_span(&self, obligation: &PredicateObligation<'tcx>) -> Option<Span> {
-        let hir = self.tcx.hir();
-        let Some(hir::Node::Item(hir::Item { kind: hir::ItemKind::Fn(sig, ..), .. })) = hir.find_by_def_id(obligation.cause.body_id) else {
+    /// The return type of the function that a failed `?` conversion returns from, as recorded
+    /// in the obligation cause when the `?` was type checked.
+    fn question_mark_return_ty(
+        &self,
+        obligation: &PredicateObligation<'tcx>,
+    ) -> Option<&'tcx hir::Ty<'tcx>> {
+        let ObligationCauseCode::QuestionMark(Some(hir_id)) = *obligation.cause.code().peel_derives() else {
             return None;
         };
+        match self.tcx.hir().find(hir_id) {
+            Some(hir::Node::Ty(ty)) => Some(ty),
+            _ => None,
+        }
+    }
+
+    /// When `?` can't convert an error into the error type of the function's `Result`, suggest
+    /// error types that the error can be converted into: `Box<dyn Error>`, and the error type of
+    /// `anyhow` when it is a dependency of the current crate.
+    fn suggest_question_mark_error_types(
+        &self,
+        err: &mut Diagnostic,
+        obligation: &PredicateObligation<'tcx>,
+        trait_ref: ty::PolyTraitRef<'tcx>,
+    ) {
+        let tcx = self.tcx;
+        let Some(ret_ty) = self.question_mark_return_ty(obligation) else { return };
+        let hir::TyKind::Path(hir::QPath::Resolved(None, path)) = ret_ty.kind else { return };
+        let hir::def::Res::Def(DefKind::Enum, def_id) = path.res else { return };
+        if !tcx.is_diagnostic_item(sym::Result, def_id) {
+            return;
+        }
+        let Some(hir::GenericArg::Type(err_ty)) =
+            path.segments.last().and_then(|segment| segment.args?.args.get(1))
+        else {
+            return;
+        };
+        let source_ty = self.resolve_vars_if_possible(trait_ref.skip_binder().substs.type_at(1));
+        if source_ty.has_non_region_infer() || source_ty.references_error() {
+            return;
+        }
+        let implements = |trait_def_id: DefId, substs: &[ty::GenericArg<'tcx>]| {
+            let trait_ref = ty::TraitRef::new(tcx, trait_def_id, substs.iter().copied());
+            self.predicate_must_hold_modulo_regions(&Obligation::new(
+                tcx,
+                ObligationCause::dummy(),
+                obligation.param_env,
+                ty::Binder::dummy(trait_ref),
+            ))
+        };
 
-        if let hir::FnRetTy::Return(ret_ty) = sig.decl.output { Some(ret_ty.span) } else { None }
+        let mut candidates = vec![];
+        if let Some(error_trait) = tcx.get_diagnostic_item(sym::Error)
+            && implements(error_trait, &[source_ty.into()])
+        {
+            candidates.push("Box<dyn std::error::Error>".to_string());
+        }
+        if let Some(&krate) = tcx.crates(()).iter().find(|&&krate| {
+                tcx.crate_name(krate).as_str() == "anyhow"
+                    && tcx.extern_crate(krate.as_def_id()).is_some_and(|krate| krate.is_direct())
+            })
+            && let Some(anyhow_error) = tcx.module_children(krate.as_def_id()).iter().find_map(|child| {
+                match child.res {
+                    hir::def::Res::Def(DefKind::Struct, def_id) if child.ident.name == sym::Error => {
+                        Some(def_id)
+                    }
+                    _ => None,
+                }
+            })
+            && let Some(from_trait) = tcx.get_diagnostic_item(sym::From)
+            && implements(
+                from_trait,
+                &[tcx.type_of(anyhow_error).subst_identity().into(), source_ty.into()],
+            )
+        {
+            candidates.push("anyhow::Error".to_string());
+        }
+        if candidates.is_empty() {
+            return;
+        }
+        err.span_suggestions(
+            err_ty.span,
+            format!("consider using an error type that `{source_ty}` can be converted into"),
+            candidates,
+            Applicability::MaybeIncorrect,
+        );
     }
 
     /// If all conditions are met to identify a returned `dyn Trait`, suggest using `impl Trait` if
@@ -2101,6 +2201,7 @@ pub(crate) fn build_fn_sig_ty<'tcx>(
         let found_span = found_span.unwrap_or(span);
         err.span_label(found_span, "found signature defined here");
 
+        let found_trait_ref = found;
         let expected = build_fn_sig_ty(self, expected);
         let found = build_fn_sig_ty(self, found);
 
@@ -2109,7 +2210,7 @@ pub(crate) fn build_fn_sig_ty<'tcx>(
         let signature_kind = format!("{argument_kind} signature");
         err.note_expected_found(&signature_kind, expected_str, &signature_kind, found_str);
 
-        self.note_conflicting_closure_bounds(cause, &mut err);
+        self.note_conflicting_closure_bounds(span, found_trait_ref, cause, &mut err);
 
         if let Some(found_node) = found_node {
             hint_missing_borrow(self, param_env, span, found, expected, found_node, &mut err);
@@ -2122,6 +2223,8 @@ pub(crate) fn build_fn_sig_ty<'tcx>(
     // requirements, which will always cause a closure to have a type error.
     fn note_conflicting_closure_bounds(
         &self,
+        span: Span,
+        found: ty::PolyTraitRef<'tcx>,
         cause: &ObligationCauseCode<'tcx>,
         err: &mut DiagnosticBuilder<'tcx, ErrorGuaranteed>,
     ) {
@@ -2170,8 +2273,25 @@ fn note_conflicting_closure_bounds(
                     other_pred_span,
                     "closure inferred to have a different signature due to this bound",
                 );
+                return;
             }
         }
+
+        // Otherwise, the signature of the closure may have been deduced from an
+        // expectation elsewhere, like a different call that the closure is passed to.
+        if let ty::Closure(def_id, _) = *found.skip_binder().self_ty().kind()
+            && let Some(def_id) = def_id.as_local()
+            && let Some(typeck_results) = &self.typeck_results
+            && let hir_id = self.tcx.hir().local_def_id_to_hir_id(def_id)
+            && typeck_results.hir_owner == hir_id.owner
+            && let Some(&origin_span) = typeck_results.closure_sig_origins().get(hir_id)
+            && !origin_span.overlaps(span)
+        {
+            err.span_note(
+                origin_span,
+                "closure inferred to have a different signature due to this expectation",
+            );
+        }
     }
 
     fn suggest_fully_qualified_path(
@@ -2773,7 +2893,6 @@ fn note_obligation_cause_code<T>(
             | ObligationCauseCode::Pattern { .. }
             | ObligationCauseCode::IfExpression { .. }
             | ObligationCauseCode::IfExpressionWithNoElse
-            | ObligationCauseCode::MainFunctionType
             | ObligationCauseCode::StartFunctionType
             | ObligationCauseCode::IntrinsicType
             | ObligationCauseCode::MethodReceiver
@@ -2788,7 +2907,7 @@ fn note_obligation_cause_code<T>(
             | ObligationCauseCode::BlockTailExpression(_)
             | ObligationCauseCode::AwaitableExpr(_)
             | ObligationCauseCode::ForLoopIterator
-            | ObligationCauseCode::QuestionMark
+            | ObligationCauseCode::QuestionMark(_)
             | ObligationCauseCode::CheckAssociatedTypeBounds { .. }
             | ObligationCauseCode::LetElse
             | ObligationCauseCode::BinOp { .. }
@@ -2872,7 +2991,7 @@ fn note_obligation_cause_code<T>(
                     ));
                 }
             }
-            ObligationCauseCode::RepeatElementCopy { is_const_fn } => {
+            ObligationCauseCode::RepeatElementCopy { is_const_fn, repeat_hir_id } => {
                 err.note(
                     "the `Copy` trait is required because this value will be copied for each element of the array",
                 );
@@ -2885,12 +3004,33 @@ fn note_obligation_cause_code<T>(
                     );
                 }
 
-                if self.tcx.sess.is_nightly_build() && is_const_fn {
+                let repeat = match tcx.hir().find(repeat_hir_id) {
+                    Some(Node::Expr(repeat)) if !repeat.span.from_expansion() => Some(repeat),
+                    _ => None,
+                };
+
+                if is_const_fn
+                    && let Some(hir::Expr { kind: hir::ExprKind::Repeat(elem, _), .. }) = repeat
+                    && tcx.features().inline_const
+                {
+                    err.multipart_suggestion_verbose(
+                        "consider wrapping the function call in an inline `const` block",
+                        vec![
+                            (elem.span.shrink_to_lo(), "const { ".to_string()),
+                            (elem.span.shrink_to_hi(), " }".to_string()),
+                        ],
+                        Applicability::MachineApplicable,
+                    );
+                } else if self.tcx.sess.is_nightly_build() && is_const_fn {
                     err.help(
                         "create an inline `const` block, see RFC #2920 \
                          <https://github.com/rust-lang/rfcs/pull/2920> for more information",
                     );
                 }
+
+                if let Some(repeat) = repeat {
+                    self.suggest_repeat_alternatives(err, predicate, param_env, body_id, repeat);
+                }
             }
             ObligationCauseCode::VariableType(hir_id) => {
                 let parent_node = self.tcx.hir().parent_id(hir_id);
@@ -2988,6 +3128,36 @@ fn note_obligation_cause_code<T>(
             ObligationCauseCode::StructInitializerSized => {
                 err.note("structs must have a statically known size to be initialized");
             }
+            ObligationCauseCode::StructInitializerFieldSized { field } => {
+                err.span_note(
+                    tcx.def_span(field),
+                    "the last field of a struct may have a dynamically sized type, but then the \
+                     struct is dynamically sized too",
+                );
+                err.note("structs must have a statically known size to be initialized");
+                let Some(hir::Node::Field(field)) = tcx.hir().get_if_local(field) else { return };
+                match field.ty.kind {
+                    hir::TyKind::TraitObject(..) => {
+                        err.multipart_suggestion_verbose(
+                            "consider storing a boxed trait object in the field instead",
+                            vec![
+                                (field.ty.span.shrink_to_lo(), "Box<".to_string()),
+                                (field.ty.span.shrink_to_hi(), ">".to_string()),
+                            ],
+                            Applicability::MaybeIncorrect,
+                        );
+                    }
+                    hir::TyKind::Slice(..) => {
+                        err.span_suggestion_verbose(
+                            field.ty.span.shrink_to_lo(),
+                            "consider storing a borrowed slice in the field instead",
+                            "&",
+                            Applicability::MaybeIncorrect,
+                        );
+                    }
+                    _ => {}
+                }
+            }
             ObligationCauseCode::FieldSized { adt_kind: ref item, last, span } => {
                 match *item {
                     AdtKind::Struct => {
@@ -3176,6 +3346,16 @@ fn note_obligation_cause_code<T>(
                     });
                 }
             }
+            ObligationCauseCode::MainFunctionType => {
+                // When the return type of `main` doesn't implement `Termination` because one
+                // of the impl's bounds isn't met, the error is about that bound, so point out
+                // where the requirement comes from.
+                if let Some(term_did) = tcx.lang_items().termination()
+                    && seen_requirements.contains(&term_did)
+                {
+                    err.note("`main` can only return types that implement `Termination`");
+                }
+            }
             ObligationCauseCode::ImplDerivedObligation(ref data) => {
                 let mut parent_trait_pred =
                     self.resolve_vars_if_possible(data.derived.parent_trait_pred);
@@ -3324,18 +3504,22 @@ fn note_obligation_cause_code<T>(
             ObligationCauseCode::FunctionArgumentObligation {
                 arg_hir_id,
                 call_hir_id,
+                apit_param,
                 ref parent_code,
-                ..
             } => {
-                self.note_function_argument_obligation(
-                    body_id,
-                    err,
-                    arg_hir_id,
-                    parent_code,
-                    param_env,
-                    predicate,
-                    call_hir_id,
-                );
+                // The arguments of builtin macros are passed to calls the user didn't write.
+                if !matches!(**parent_code, ObligationCauseCode::BuiltinMacroArgument { .. }) {
+                    self.note_function_argument_obligation(
+                        body_id,
+                        err,
+                        arg_hir_id,
+                        parent_code,
+                        param_env,
+                        predicate,
+                        call_hir_id,
+                        apit_param,
+                    );
+                }
                 ensure_sufficient_stack(|| {
                     self.note_obligation_cause_code(
                         body_id,
@@ -3348,6 +3532,45 @@ fn note_obligation_cause_code<T>(
                     )
                 });
             }
+            ObligationCauseCode::BuiltinMacroArgument { macro_name, arg_idx, ref parent_code } => {
+                let trait_pred = predicate.to_opt_poly_trait_pred();
+                if let Some(trait_pred) = trait_pred {
+                    let trait_name = tcx.item_name(trait_pred.def_id());
+                    match arg_idx {
+                        Some(idx) => err.note(format!(
+                            "argument {} of `{macro_name}!` must implement `{trait_name}`",
+                            idx + 1,
+                        )),
+                        None => err.note(format!(
+                            "values captured by the format string of `{macro_name}!` must \
+                             implement `{trait_name}`",
+                        )),
+                    };
+                }
+                // `Debug` and `Display` are required of the argument by the desugared code
+                // directly, which is an implementation detail of the macro. Other formatting
+                // traits can be required through impls, e.g. `str: UpperHex` for formatting a
+                // `&str`, which are still worth explaining.
+                let is_debug_or_display = trait_pred.is_some_and(|trait_pred| {
+                    matches!(
+                        tcx.get_diagnostic_name(trait_pred.def_id()),
+                        Some(sym::Debug | sym::Display)
+                    )
+                });
+                if !is_debug_or_display {
+                    ensure_sufficient_stack(|| {
+                        self.note_obligation_cause_code(
+                            body_id,
+                            err,
+                            predicate,
+                            param_env,
+                            &parent_code,
+                            obligated_types,
+                            seen_requirements,
+                        )
+                    });
+                }
+            }
             ObligationCauseCode::CompareImplItemObligation { trait_item_def_id, kind, .. } => {
                 let item_name = self.tcx.item_name(trait_item_def_id);
                 let msg = format!(
@@ -3567,6 +3790,7 @@ fn note_function_argument_obligation(
         param_env: ty::ParamEnv<'tcx>,
         failed_pred: ty::Predicate<'tcx>,
         call_hir_id: HirId,
+        apit_param: Option<DefId>,
     ) {
         let tcx = self.tcx;
         let hir = tcx.hir();
@@ -3670,6 +3894,108 @@ fn note_function_argument_obligation(
                 err.span_label(*span, "required by a bound introduced by this call");
             }
         }
+        if let Some(apit_param) = apit_param {
+            self.note_apit_argument(err, arg_hir_id, apit_param);
+        }
+    }
+
+    /// Points at the argument-position `impl Trait` parameter an argument was passed
+    /// to, written out as it is in the signature, e.g. `x: impl Debug + Clone`.
+    fn note_apit_argument(&self, err: &mut Diagnostic, arg_hir_id: HirId, apit_param: DefId) {
+        let source_map = self.tcx.sess.source_map();
+        let ty_span = self.tcx.def_span(apit_param);
+        let Ok(ty_snippet) = source_map.span_to_snippet(ty_span) else { return };
+        // The parameter is the last one whose name comes before its type.
+        let fn_def_id = self.tcx.parent(apit_param);
+        let name = self
+            .tcx
+            .fn_arg_names(fn_def_id)
+            .iter()
+            .filter(|ident| ident.span.eq_ctxt(ty_span) && ident.span.hi() <= ty_span.lo())
+            .max_by_key(|ident| ident.span.hi());
+        let (span, param) = match name {
+            Some(name) if name.name != kw::Empty && name.name != kw::Underscore => {
+                (name.span.to(ty_span), format!("{name}: {ty_snippet}"))
+            }
+            _ => (ty_span, ty_snippet),
+        };
+        let arg = match self.tcx.hir().find(arg_hir_id) {
+            Some(Node::Expr(arg)) => source_map
+                .span_to_snippet(arg.span)
+                .ok()
+                .filter(|snippet| !snippet.contains('\n'))
+                .map_or_else(|| "the argument".to_string(), |snippet| format!("`{snippet}`")),
+            _ => "the argument".to_string(),
+        };
+        err.span_note(span, format!("{arg} is passed to the parameter `{param}`"));
+    }
+
+    /// Suggests ways of building an array out of a repeated element that isn't `Copy`:
+    /// cloning it into a `Vec` with `vec![elem; N]`, or evaluating it once per element
+    /// with `std::array::from_fn`.
+    fn suggest_repeat_alternatives(
+        &self,
+        err: &mut Diagnostic,
+        predicate: ty::Predicate<'tcx>,
+        param_env: ty::ParamEnv<'tcx>,
+        body_id: LocalDefId,
+        repeat: &hir::Expr<'_>,
+    ) {
+        let hir::ExprKind::Repeat(elem, count) = repeat.kind else { return };
+        // Neither alternative can be used in a constant.
+        if self.tcx.hir().body_const_context(body_id).is_some() {
+            return;
+        }
+        let Some(trait_pred) = predicate.to_opt_poly_trait_pred() else { return };
+        let elem_ty = trait_pred.skip_binder().self_ty();
+        if elem_ty.has_non_region_infer() || elem_ty.has_escaping_bound_vars() {
+            return;
+        }
+
+        // A `Vec` can't be used where an array was explicitly asked for.
+        let annotated = matches!(
+            self.tcx.hir().find_parent(repeat.hir_id),
+            Some(Node::Local(hir::Local { ty: Some(_), .. }))
+        );
+        if !annotated
+            && let Some(clone_trait) = self.tcx.lang_items().clone_trait()
+            && self
+                .type_implements_trait(clone_trait, [elem_ty], param_env)
+                .must_apply_modulo_regions()
+        {
+            err.span_suggestion_verbose(
+                repeat.span.shrink_to_lo(),
+                "consider using a `Vec` instead, which clones the value for each element",
+                "vec!",
+                Applicability::MaybeIncorrect,
+            );
+        }
+
+        // Evaluating a place once per element would move out of it more than once.
+        if let hir::ExprKind::Path(_) = elem.kind {
+            return;
+        }
+        let hir::ArrayLen::Body(count) = count else { return };
+        let source_map = self.tcx.sess.source_map();
+        let count_span = self.tcx.hir().span(count.hir_id);
+        let (Ok(elem_snippet), Ok(count_snippet)) =
+            (source_map.span_to_snippet(elem.span), source_map.span_to_snippet(count_span))
+        else {
+            return;
+        };
+        // Generic const arguments that aren't literals or paths need braces.
+        let count_snippet = match self.tcx.hir().body(count.body).value.kind {
+            hir::ExprKind::Lit(_) | hir::ExprKind::Path(_) | hir::ExprKind::Block(..) => {
+                count_snippet
+            }
+            _ => format!("{{ {count_snippet} }}"),
+        };
+        err.span_suggestion_verbose(
+            repeat.span,
+            "consider using `std::array::from_fn` to evaluate the expression once for each element",
+            format!("std::array::from_fn::<_, {count_snippet}, _>(|_| {elem_snippet})"),
+            Applicability::MaybeIncorrect,
+        );
     }
 
     fn point_at_chain(
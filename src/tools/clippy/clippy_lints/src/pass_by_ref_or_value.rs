@@ -194,7 +194,7 @@ fn check_poly_fn(&mut self, cx: &LateContext<'tcx>, def_id: LocalDefId, decl: &F
                                 || typeck
                                     .adjustments()
                                     .items()
-                                    .flat_map(|(_, a)| a)
+                                    .flat_map(|(_, a)| a.iter())
                                     .any(|a| matches!(a.kind, Adjust::Pointer(PointerCast::UnsafeFnPointer)))
                             {
                                 continue;
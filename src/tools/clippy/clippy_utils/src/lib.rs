@@ -1007,7 +1007,7 @@ fn pat_capture_kind(cx: &LateContext<'_>, pat: &Pat<'_>) -> CaptureKind {
             .typeck_results()
             .adjustments()
             .get(child_id)
-            .map_or(&[][..], |x| &**x)
+            .map_or(&[][..], |x| &x[..])
         {
             if let rustc_ty::RawPtr(TypeAndMut { mutbl: mutability, .. }) | rustc_ty::Ref(_, _, mutability) =
                 *adjust.last().map_or(target, |a| a.target).kind()
@@ -9,6 +9,7 @@
 static GROUP_DESCRIPTIONS: &[(&str, &str)] = &[
     ("unused", "Lints that detect things being declared but not used, or excess syntax"),
     ("let-underscore", "Lints that detect wildcard let bindings that are likely to be invalid"),
+    ("unreachable-code", "Lints that detect code that can never be executed"),
     ("rustdoc", "Rustdoc-specific lints"),
     ("rust-2018-idioms", "Lints to nudge you toward idiomatic features of Rust 2018"),
     ("nonstandard-style", "Violation of standard naming conventions"),
@@ -1,7 +1,10 @@
 use colored::*;
 use regex::bytes::Regex;
-use std::ffi::OsString;
+use std::collections::{BTreeMap, HashMap};
+use std::ffi::{OsStr, OsString};
 use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
 use std::{env, process::Command};
 use ui_test::status_emitter::StatusEmitter;
 use ui_test::CommandBuilder;
@@ -46,6 +49,130 @@ fn build_so_for_c_ffi_tests() -> PathBuf {
     so_file_path
 }
 
+/// Builds every crate in `aux_dir` once for `target` and returns the flags needed to make them
+/// available to the tests. Procedural macros (crates with `#![crate_type = "proc-macro"]`) are
+/// built for the host; all other crates become rlibs for the target, built against the Miri
+/// sysroot. Crates are built in alphabetical order and can depend on the ones built before them.
+fn build_aux_crates(target: &str, aux_dir: &Path) -> Vec<OsString> {
+    let out_dir = Path::new(&env::var_os("CARGO_TARGET_DIR").unwrap())
+        .join("miri-aux")
+        .join(target)
+        .join(aux_dir.parent().unwrap().file_name().unwrap());
+    std::fs::create_dir_all(&out_dir).expect("failed to create directory for auxiliary crates");
+    let mut aux_files: Vec<PathBuf> = std::fs::read_dir(aux_dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "rs"))
+        .collect();
+    aux_files.sort();
+
+    let mut dir_flag = OsString::from("dependency=");
+    dir_flag.push(&out_dir);
+    let mut flags = vec!["-L".into(), dir_flag];
+    for aux_file in aux_files {
+        let crate_name = aux_file.file_stem().unwrap().to_str().unwrap().replace('-', "_");
+        let source = std::fs::read_to_string(&aux_file).unwrap();
+        let is_proc_macro = source.contains(r#"#![crate_type = "proc-macro"]"#);
+
+        let mut cmd = Command::new(miri_path());
+        cmd.env_clear()
+            .envs(env::vars_os().filter(|(var, _)| env_var_allowed(var)))
+            .arg(&aux_file)
+            .args(["--crate-name", &crate_name, "--edition", "2021", "--emit=link"])
+            .arg("--crate-type")
+            .arg(if is_proc_macro { "proc-macro" } else { "lib" })
+            .arg("--out-dir")
+            .arg(&out_dir)
+            .args(&flags);
+        if is_proc_macro {
+            cmd.env("MIRI_BE_RUSTC", "host");
+        } else {
+            // This uses `MIRI_SYSROOT`, just like the tests themselves.
+            cmd.env("MIRI_BE_RUSTC", "target").args(["--target", target]);
+        }
+        let output = cmd.output().expect("failed to run Miri to build an auxiliary crate");
+        if !output.status.success() {
+            panic!(
+                "failed to build auxiliary crate {}:\n{}",
+                aux_file.display(),
+                String::from_utf8_lossy(&output.stderr)
+            );
+        }
+
+        // Ask for the file name rather than guessing the platform-specific naming scheme.
+        let output = cmd.args(["--print", "file-names"]).output().unwrap();
+        assert!(output.status.success());
+        let file_name = String::from_utf8(output.stdout).unwrap();
+        let mut extern_flag = OsString::from(format!("{crate_name}="));
+        extern_flag.push(out_dir.join(file_name.trim()));
+        flags.push("--extern".into());
+        flags.push(extern_flag);
+    }
+    flags
+}
+
+/// The only environment variables of the caller that are passed on to the commands the test suite
+/// runs. Everything else, like `RUSTFLAGS`, `MIRIFLAGS`, `RUST_LOG` or `RUST_BACKTRACE`, is removed
+/// so that the output of tests doesn't depend on the machine running them. Tests that need a
+/// variable set it with `//@rustc-env`.
+const ENV_ALLOWLIST: &[&str] = &[
+    // Needed to find and run Miri and the toolchain.
+    "PATH",
+    "LD_LIBRARY_PATH",
+    "DYLD_LIBRARY_PATH",
+    "DYLD_FALLBACK_LIBRARY_PATH",
+    "RUSTUP_HOME",
+    "RUSTUP_TOOLCHAIN",
+    "CARGO_HOME",
+    "MIRI_SYSROOT",
+    // Needed by the OS and the standard library.
+    "HOME",
+    "USERPROFILE",
+    "SYSTEMROOT",
+    "TMPDIR",
+    "TMP",
+    "TEMP",
+    // Set by the test suite itself, see `main`.
+    "MIRI_ENV_VAR_TEST",
+    "MIRI_TEMP",
+];
+
+fn env_var_allowed(var: &OsStr) -> bool {
+    var.to_str().is_some_and(|var| ENV_ALLOWLIST.contains(&var))
+}
+
+/// Renders `cmd` as a shell command line that reruns it outside of the test suite, in the same
+/// environment that the test suite ran it in.
+fn reproduction_command(cmd: &Command) -> String {
+    fn quote(s: &OsStr) -> String {
+        let s = s.to_string_lossy();
+        if !s.is_empty() && s.chars().all(|c| c.is_ascii_alphanumeric() || "-_=/.,:+@%".contains(c))
+        {
+            s.into_owned()
+        } else {
+            format!("'{}'", s.replace('\'', r"'\''"))
+        }
+    }
+    let cwd = cmd.get_current_dir().map_or_else(|| env::current_dir().unwrap(), Path::to_owned);
+    let mut line = format!("cd {} && env -i", quote(cwd.as_os_str()));
+    let explicit: HashMap<&OsStr, Option<&OsStr>> = cmd.get_envs().collect();
+    let inherited = env::vars_os()
+        .filter(|(var, _)| env_var_allowed(var) && !explicit.contains_key(var.as_os_str()));
+    let set = cmd.get_envs().filter_map(|(var, val)| Some((var.to_owned(), val?.to_owned())));
+    for (var, val) in inherited.chain(set) {
+        let mut assignment = var;
+        assignment.push("=");
+        assignment.push(val);
+        line.push(' ');
+        line.push_str(&quote(&assignment));
+    }
+    for arg in std::iter::once(cmd.get_program()).chain(cmd.get_args()) {
+        line.push(' ');
+        line.push_str(&quote(arg));
+    }
+    line
+}
+
 fn test_config(target: &str, path: &str, mode: Mode, with_dependencies: bool) -> Config {
     // Miri is rustc-like, so we create a default builder for rustc and modify it
     let mut program = CommandBuilder::rustc();
@@ -59,7 +186,8 @@ fn test_config(target: &str, path: &str, mode: Mode, with_dependencies: bool) ->
         // (We often get warnings when e.g. a feature gets stabilized or some lint gets added/improved.)
         program.args.push("-Astable-features".into());
         program.args.push("-Aunused".into());
-    } else {
+    } else if !path.starts_with("tests/lint") {
+        // The lint tests are all about warnings, so they get to decide on lint levels themselves.
         program.args.push("-Dwarnings".into());
         program.args.push("-Dunused".into());
     }
@@ -72,6 +200,21 @@ fn test_config(target: &str, path: &str, mode: Mode, with_dependencies: bool) ->
     program.args.push("--target".into());
     program.args.push(target.into());
 
+    // Miri's non-determinism is seeded with 0 by default; `MIRI_TEST_SEED` can be used to try a
+    // different seed, unless `MIRIFLAGS` already picked one.
+    if let Ok(seed) = env::var("MIRI_TEST_SEED") {
+        if !program.args.iter().any(|arg| arg.to_string_lossy().starts_with("-Zmiri-seed=")) {
+            program.args.push(format!("-Zmiri-seed={seed}").into());
+        }
+    }
+    // `CommandBuilder` can't clear the environment, so remove every variable that isn't allowed.
+    program.envs.extend(
+        env::vars_os()
+            .map(|(var, _)| var)
+            .filter(|var| !env_var_allowed(var))
+            .map(|var| (var, None)),
+    );
+
     // If we're on linux, and we're testing the extern-so functionality,
     // then build the shared object file for testing external C function calls
     // and push the relevant compiler flag.
@@ -117,25 +260,44 @@ fn test_config(target: &str, path: &str, mode: Mode, with_dependencies: bool) ->
             "miri".into(),
             "run".into(), // There is no `cargo miri build` so we just use `cargo miri run`.
         ];
+
+        // Helper crates of the tests in this suite, built once for all tests that use them.
+        let aux_dir = Path::new(path).join("auxiliary");
+        if aux_dir.is_dir() {
+            config.program.args.extend(build_aux_crates(target, &aux_dir));
+        }
     }
     config
 }
 
-fn run_tests(mode: Mode, path: &str, target: &str, with_dependencies: bool) -> Result<()> {
-    let mut config = test_config(target, path, mode, with_dependencies);
-
-    // Handle command-line arguments.
+/// Applies the command-line arguments to `config` and returns the shard to run, if any.
+fn handle_args(config: &mut Config) -> Option<Shard> {
     let mut after_dashdash = false;
+    let mut shard = env::var("TEST_SHARD").ok().map(|shard| Shard::parse(&shard));
+    let mut expect_shard = false;
     config.path_filter.extend(std::env::args().skip(1).filter(|arg| {
         if after_dashdash {
             // Just propagate everything.
             return true;
         }
+        if expect_shard {
+            shard = Some(Shard::parse(arg));
+            expect_shard = false;
+            return false;
+        }
         match &**arg {
             "--quiet" => {
                 config.quiet = true;
                 false
             }
+            "--shard" => {
+                expect_shard = true;
+                false
+            }
+            s if s.starts_with("--shard=") => {
+                shard = Some(Shard::parse(&s["--shard=".len()..]));
+                false
+            }
             "--" => {
                 after_dashdash = true;
                 false
@@ -146,18 +308,165 @@ fn run_tests(mode: Mode, path: &str, target: &str, with_dependencies: bool) -> R
             _ => true,
         }
     }));
+    assert!(!expect_shard, "`--shard` must be followed by `K/N`");
+    shard
+}
+
+fn run_tests(mode: Mode, path: &str, target: &str, with_dependencies: bool) -> Result<()> {
+    let mut config = test_config(target, path, mode, with_dependencies);
+    let shard = handle_args(&mut config);
 
     eprintln!("   Compiler: {}", config.program.display());
+    if let Some(shard) = shard {
+        eprintln!("      Shard: {}/{}", shard.index + 1, shard.count);
+    }
     ui_test::run_tests_generic(
         config,
-        // The files we're actually interested in (all `.rs` files).
-        |path| path.extension().is_some_and(|ext| ext == "rs"),
+        // The files we're actually interested in (all `.rs` files in our shard, except for the
+        // auxiliary crates, which are built by `build_aux_crates` instead of being run).
+        |path| {
+            path.extension().is_some_and(|ext| ext == "rs")
+                && !path.components().any(|c| c.as_os_str() == "auxiliary")
+                && shard.map_or(true, |shard| shard.contains(target, path))
+        },
         // This could be used to overwrite the `Config` on a per-test basis.
-        |_, _| None,
+        // We only use it to note down when the test started, for the summary.
+        |_, path| {
+            SUMMARY.lock().unwrap().started.insert(path.to_owned(), Instant::now());
+            None
+        },
         TextAndGha,
     )
 }
 
+/// How the lint under test is reported in one of the configurations tried by
+/// `check_lint_levels`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum LintOutcome {
+    Warning,
+    Error,
+    Silent,
+}
+
+/// Every test in `tests/lint` is named after the lint it exercises. Besides the regular ui test
+/// run, every test is compiled again with the level of that lint changed on the command line, and
+/// we check that the lint then gets reported at the expected level (or not at all).
+fn check_lint_levels(target: &str) -> Result<()> {
+    let msg = format!("## Checking lint levels in tests/lint against miri for {target}");
+    eprintln!("{}", msg.green().bold());
+
+    let mut config =
+        test_config(target, "tests/lint", Mode::Yolo, /* with dependencies */ false);
+    let shard = handle_args(&mut config);
+    let mut tests: Vec<PathBuf> = std::fs::read_dir("tests/lint")?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<_, _>>()?;
+    tests.retain(|path| {
+        let path_display = path.display().to_string();
+        path.extension().is_some_and(|ext| ext == "rs")
+            && (config.path_filter.is_empty()
+                || config.path_filter.iter().any(|filter| path_display.contains(filter)))
+            && shard.map_or(true, |shard| shard.contains(target, path))
+    });
+    tests.sort();
+
+    SUMMARY.lock().unwrap().start_suite("tests/lint (levels)", target);
+    let mut failures = 0;
+    for path in tests {
+        let start = Instant::now();
+        let mut passed = true;
+        let lint = path.file_stem().unwrap().to_str().unwrap();
+        for (flags, expected) in [
+            (vec![], LintOutcome::Warning),
+            (vec![format!("-D{lint}")], LintOutcome::Error),
+            (vec![format!("-D{lint}"), "--cap-lints=warn".into()], LintOutcome::Warning),
+            (vec![format!("-A{lint}")], LintOutcome::Silent),
+        ] {
+            let mut config = config.clone();
+            config.program.args.extend(flags.iter().map(OsString::from));
+            let mut cmd = ui_test::test_command(config, &path)?;
+            let output = cmd.output()?;
+            let outcome = lint_outcome(&output.stderr, lint);
+            // Only an error is supposed to stop Miri from running the program.
+            let status_ok = output.status.success() == (outcome != LintOutcome::Error);
+            if outcome == expected && status_ok {
+                eprintln!("{} with {flags:?} ... {}", path.display(), "ok".green());
+            } else {
+                failures += 1;
+                passed = false;
+                eprintln!(
+                    "{} with {flags:?} ... {}: expected {expected:?}, got {outcome:?} ({})",
+                    path.display(),
+                    "FAILED".red().bold(),
+                    output.status,
+                );
+                eprintln!("command: {cmd:?}");
+                std::io::Write::write_all(&mut std::io::stderr(), &output.stderr)?;
+            }
+        }
+        let test = format!("{} (levels)", path.display());
+        SUMMARY.lock().unwrap().record(test, passed, start.elapsed());
+    }
+
+    if failures > 0 {
+        return Err(ui_test::color_eyre::eyre::eyre!("{failures} lint level checks failed"));
+    }
+    Ok(())
+}
+
+/// Determines at which level `lint` was reported in the JSON diagnostics in `stderr`.
+fn lint_outcome(stderr: &[u8], lint: &str) -> LintOutcome {
+    let code = format!(r#""code":{{"code":"{lint}""#);
+    let mut outcome = LintOutcome::Silent;
+    for line in String::from_utf8_lossy(stderr).lines() {
+        let Some(pos) = line.find(&code) else { continue };
+        // The top-level `level` directly follows the `code`; the children come later.
+        let level =
+            line[pos..].split(r#""level":""#).nth(1).and_then(|rest| rest.split('"').next());
+        match level {
+            Some("error") => return LintOutcome::Error,
+            Some("warning") => outcome = LintOutcome::Warning,
+            _ => {}
+        }
+    }
+    outcome
+}
+
+/// The `K/N` shard of the test suite that this run is responsible for. Tests are assigned to
+/// shards by a stable hash of their path, so adding a test does not move any other test to a
+/// different shard.
+#[derive(Clone, Copy)]
+struct Shard {
+    /// Zero-based, unlike the `K` in `K/N`.
+    index: u64,
+    count: u64,
+}
+
+impl Shard {
+    fn parse(shard: &str) -> Self {
+        let parse = || -> Option<Self> {
+            let (k, n) = shard.split_once('/')?;
+            let (k, n): (u64, u64) = (k.trim().parse().ok()?, n.trim().parse().ok()?);
+            (1..=n).contains(&k).then_some(Shard { index: k - 1, count: n })
+        };
+        parse()
+            .unwrap_or_else(|| panic!("invalid shard `{shard}`, expected `K/N` with 1 <= K <= N"))
+    }
+
+    fn contains(&self, target: &str, path: &Path) -> bool {
+        // FNV-1a, which (unlike `DefaultHasher`) is guaranteed to be stable across machines and
+        // Rust versions. The target is included so that the tests of one suite are spread out
+        // differently for each target of the CI matrix.
+        let path = path.display().to_string().replace('\\', "/");
+        let mut hash: u64 = 0xcbf29ce484222325;
+        for byte in target.bytes().chain([0]).chain(path.bytes()) {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(0x100000001b3);
+        }
+        hash % self.count == self.index
+    }
+}
+
 macro_rules! regexes {
     ($name:ident: $($regex:expr => $replacement:expr,)*) => {lazy_static::lazy_static! {
         static ref $name: Vec<(Match, &'static [u8])> = vec![
@@ -218,6 +527,7 @@ enum Dependencies {
 fn ui(mode: Mode, path: &str, target: &str, with_dependencies: Dependencies) -> Result<()> {
     let msg = format!("## Running ui tests in {path} against miri for {target}");
     eprintln!("{}", msg.green().bold());
+    SUMMARY.lock().unwrap().start_suite(path, target);
 
     let with_dependencies = match with_dependencies {
         WithDependencies => true,
@@ -242,6 +552,9 @@ fn main() -> Result<()> {
         if first == "--miri-run-dep-mode" {
             return run_dep_mode(target, args);
         }
+        if first == "--miri-bench-mode" {
+            return bench_mode(&target, args);
+        }
     }
 
     // Add a test env var to do environment communication tests.
@@ -249,21 +562,32 @@ fn main() -> Result<()> {
     // Let the tests know where to store temp files (they might run for a different target, which can make this hard to find).
     env::set_var("MIRI_TEMP", env::temp_dir());
 
-    ui(Mode::Pass, "tests/pass", &target, WithoutDependencies)?;
-    ui(Mode::Pass, "tests/pass-dep", &target, WithDependencies)?;
-    ui(Mode::Panic, "tests/panic", &target, WithDependencies)?;
-    ui(Mode::Fail { require_patterns: true }, "tests/fail", &target, WithDependencies)?;
+    // Run all suites even if some of them fail, so that the summary at the end is complete.
+    let mut results = vec![
+        ui(Mode::Pass, "tests/pass", &target, WithoutDependencies),
+        ui(Mode::Pass, "tests/pass-dep", &target, WithDependencies),
+        ui(Mode::Panic, "tests/panic", &target, WithDependencies),
+        ui(Mode::Fail { require_patterns: true }, "tests/fail", &target, WithDependencies),
+        ui(Mode::Pass, "tests/lint", &target, WithoutDependencies),
+        check_lint_levels(&target),
+    ];
     if cfg!(target_os = "linux") {
-        ui(Mode::Pass, "tests/extern-so/pass", &target, WithoutDependencies)?;
-        ui(
+        results.push(ui(Mode::Pass, "tests/extern-so/pass", &target, WithoutDependencies));
+        results.push(ui(
             Mode::Fail { require_patterns: true },
             "tests/extern-so/fail",
             &target,
             WithoutDependencies,
-        )?;
+        ));
     }
 
-    Ok(())
+    SUMMARY.lock().unwrap().print();
+    let mut errors: Vec<_> = results.into_iter().filter_map(Result::err).collect();
+    match errors.len() {
+        0 => Ok(()),
+        1 => Err(errors.pop().unwrap()),
+        n => Err(ui_test::color_eyre::eyre::eyre!("{n} test suites failed")),
+    }
 }
 
 fn run_dep_mode(target: String, mut args: impl Iterator<Item = OsString>) -> Result<()> {
@@ -281,6 +605,275 @@ fn run_dep_mode(target: String, mut args: impl Iterator<Item = OsString>) -> Res
     if cmd.spawn()?.wait()?.success() { Ok(()) } else { std::process::exit(1) }
 }
 
+/// Where the compile-time baseline for `tests/perf` is stored.
+const BENCH_BASELINE: &str = "tests/perf/baseline.txt";
+
+/// Compiles every test in `tests/perf` `MIRI_BENCH_RUNS` times, prints the median wall time, and
+/// compares the number of queries recorded by `-Zself-profile` against the stored baseline. Any
+/// benchmark whose query count grew by more than `MIRI_BENCH_THRESHOLD` percent is reported as a
+/// regression. With `MIRI_BLESS`, the baseline is overwritten instead.
+///
+/// Only the query counts are stored and compared, since they are the same on every machine, unlike
+/// the wall time.
+fn bench_mode(target: &str, args: impl Iterator<Item = OsString>) -> Result<()> {
+    let filters: Vec<String> =
+        args.map(|arg| arg.into_string().expect("non-UTF-8 benchmark filter")).collect();
+    let runs: usize = match env::var("MIRI_BENCH_RUNS") {
+        Ok(runs) => runs.parse()?,
+        Err(_) => 5,
+    };
+    if runs == 0 {
+        return Err(ui_test::color_eyre::eyre::eyre!("MIRI_BENCH_RUNS must be at least 1"));
+    }
+    let threshold: f64 = match env::var("MIRI_BENCH_THRESHOLD") {
+        Ok(threshold) => threshold.parse()?,
+        Err(_) => 10.0,
+    };
+    let bless = env::var_os("MIRI_BLESS").is_some();
+    let profile_dir =
+        Path::new(&env::var_os("CARGO_TARGET_DIR").unwrap()).join("miri-perf-profiles");
+
+    let mut tests: Vec<PathBuf> = std::fs::read_dir("tests/perf")?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<_, _>>()?;
+    tests.retain(|path| {
+        path.extension().is_some_and(|ext| ext == "rs")
+            && (filters.is_empty()
+                || filters.iter().any(|f| path.display().to_string().contains(f)))
+    });
+    tests.sort();
+
+    let msg = format!("## Running compile-time benchmarks in tests/perf ({runs} runs each)");
+    eprintln!("{}", msg.green().bold());
+
+    let mut config = test_config(target, "tests/perf", Mode::Pass, /* with dependencies */ false);
+    // Only measure compilation: have Miri act as rustc for the target and stop after analysis,
+    // instead of also interpreting the program.
+    config.program.args.retain(|arg| !arg.to_string_lossy().starts_with("-Zmiri-"));
+    config.program.args.push("--emit=metadata".into());
+    config.program.envs.push(("MIRI_BE_RUSTC".into(), Some("target".into())));
+    let baseline = read_bench_baseline(Path::new(BENCH_BASELINE))?;
+    let mut results = BTreeMap::new();
+    let mut regressions = 0;
+    for path in tests {
+        let name = path.display().to_string();
+        let test_profile_dir = profile_dir.join(path.file_stem().unwrap());
+        let mut walls = Vec::with_capacity(runs);
+        for _ in 0..runs {
+            // Only keep the self-profile of the most recent run around.
+            let _ = std::fs::remove_dir_all(&test_profile_dir);
+            let mut config = config.clone();
+            let mut flag = OsString::from("-Zself-profile=");
+            flag.push(&test_profile_dir);
+            config.program.args.push(flag);
+            let mut cmd = ui_test::test_command(config, &path)?;
+            let start = Instant::now();
+            let output = cmd.output()?;
+            walls.push(start.elapsed());
+            if !output.status.success() {
+                eprintln!("{name} {}", "FAILED:".red().bold());
+                std::io::Write::write_all(&mut std::io::stderr(), &output.stderr)?;
+                return Err(ui_test::color_eyre::eyre::eyre!("benchmark `{name}` failed"));
+            }
+        }
+        walls.sort();
+        let queries = count_self_profile_queries(&test_profile_dir)?;
+
+        let mut line = format!("{name}: {:.1?}, {queries} queries", walls[walls.len() / 2]);
+        match baseline.get(&name) {
+            Some(&old) if !bless => {
+                let change = (queries as f64 - old as f64) / old as f64 * 100.0;
+                line.push_str(&format!(" ({change:+.1}%)"));
+                if change > threshold {
+                    regressions += 1;
+                    eprintln!("{line} ... {}", "REGRESSED".red().bold());
+                } else {
+                    eprintln!("{line} ... {}", "ok".green());
+                }
+            }
+            _ => eprintln!("{line} ... {}", "no baseline".yellow()),
+        }
+        results.insert(name, queries);
+    }
+
+    if bless {
+        write_bench_baseline(Path::new(BENCH_BASELINE), &results)?;
+        eprintln!("Wrote new baseline to {BENCH_BASELINE}");
+    }
+    if regressions > 0 {
+        return Err(ui_test::color_eyre::eyre::eyre!(
+            "{regressions} benchmarks regressed by more than {threshold}%"
+        ));
+    }
+    Ok(())
+}
+
+/// The baseline file has one line per benchmark: the test path and the number of queries
+/// recorded by `-Zself-profile`.
+fn read_bench_baseline(path: &Path) -> Result<BTreeMap<String, u64>> {
+    let mut baseline = BTreeMap::new();
+    if !path.exists() {
+        return Ok(baseline);
+    }
+    for line in std::fs::read_to_string(path)?.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let malformed =
+            || ui_test::color_eyre::eyre::eyre!("malformed line in {}: `{line}`", path.display());
+        let mut fields = line.split_whitespace();
+        let (Some(name), Some(queries), None) = (fields.next(), fields.next(), fields.next())
+        else {
+            return Err(malformed());
+        };
+        baseline.insert(name.to_owned(), queries.parse().map_err(|_| malformed())?);
+    }
+    Ok(baseline)
+}
+
+fn write_bench_baseline(path: &Path, results: &BTreeMap<String, u64>) -> Result<()> {
+    let mut out = String::from(
+        "# Generated by `MIRI_BLESS=1 ./miri perf`; do not edit manually.\n\
+         # <test> <number of queries recorded by -Zself-profile>\n",
+    );
+    for (name, queries) in results {
+        out.push_str(&format!("{name} {queries}\n"));
+    }
+    std::fs::write(path, out)?;
+    Ok(())
+}
+
+/// Runs `summarize` from `measureme` on the self-profile data in `dir` and adds up the item counts
+/// of all queries and other activities it lists.
+fn count_self_profile_queries(dir: &Path) -> Result<u64> {
+    let profile = std::fs::read_dir(dir)?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .find(|path| path.extension().is_some_and(|ext| ext == "mm_profdata"))
+        .ok_or_else(|| {
+            ui_test::color_eyre::eyre::eyre!("no self-profile data in {}", dir.display())
+        })?;
+    let output =
+        Command::new("summarize").arg("summarize").arg(&profile).output().map_err(|e| {
+            ui_test::color_eyre::eyre::eyre!(
+                "failed to run `summarize`, install it with \
+                 `cargo install --git https://github.com/rust-lang/measureme summarize`: {e}"
+            )
+        })?;
+    if !output.status.success() {
+        return Err(ui_test::color_eyre::eyre::eyre!(
+            "`summarize` failed:\n{}",
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+    // `summarize` prints a table with one row per query; find the column with the counts in the
+    // header and add up that column in all following rows.
+    let stdout = String::from_utf8(output.stdout)?;
+    let cells = |line: &str| line.split('|').map(str::trim).collect::<Vec<_>>();
+    let mut lines = stdout.lines().filter(|line| line.trim_start().starts_with('|'));
+    let column = lines
+        .next()
+        .and_then(|header| cells(header).iter().position(|&cell| cell == "Item count"))
+        .ok_or_else(|| {
+            ui_test::color_eyre::eyre::eyre!("unexpected output of `summarize`:\n{stdout}")
+        })?;
+    let mut total = 0;
+    for line in lines {
+        let count = cells(line).get(column).and_then(|count| count.parse::<u64>().ok());
+        total += count.ok_or_else(|| {
+            ui_test::color_eyre::eyre::eyre!("unexpected line in the output of `summarize`: {line}")
+        })?;
+    }
+    Ok(total)
+}
+
+/// The results of one test suite for one target.
+struct SuiteSummary {
+    path: String,
+    target: String,
+    passed: usize,
+    failed: usize,
+    ignored: usize,
+}
+
+/// Everything we need to print a summary of all test suites once they have all been run.
+#[derive(Default)]
+struct TestSummary {
+    suites: Vec<SuiteSummary>,
+    /// When each test was started, filled in by the `per_file_config` callback of `ui_test`.
+    started: HashMap<PathBuf, Instant>,
+    /// How long each test took (including all its revisions).
+    durations: HashMap<String, Duration>,
+}
+
+lazy_static::lazy_static! {
+    static ref SUMMARY: Mutex<TestSummary> = Mutex::default();
+}
+
+impl TestSummary {
+    fn start_suite(&mut self, path: &str, target: &str) {
+        self.suites.push(SuiteSummary {
+            path: path.to_owned(),
+            target: target.to_owned(),
+            passed: 0,
+            failed: 0,
+            ignored: 0,
+        });
+    }
+
+    fn record(&mut self, test: String, passed: bool, duration: Duration) {
+        let suite = self.suites.last_mut().expect("test result outside of a test suite");
+        if passed {
+            suite.passed += 1;
+        } else {
+            suite.failed += 1;
+        }
+        self.durations.insert(test, duration);
+    }
+
+    fn test_result(&mut self, path: &Path, result: &ui_test::TestResult) {
+        let passed = match result {
+            ui_test::TestResult::Ok => true,
+            ui_test::TestResult::Errored { .. } => false,
+            ui_test::TestResult::Ignored => {
+                self.suites.last_mut().expect("test result outside of a test suite").ignored += 1;
+                return;
+            }
+            ui_test::TestResult::Filtered => return,
+        };
+        // Revisions are run one after the other, so this is the time for all revisions so far.
+        let duration = self.started.get(path).map_or(Duration::ZERO, Instant::elapsed);
+        self.record(path.display().to_string(), passed, duration);
+    }
+
+    fn print(&self) {
+        eprintln!();
+        eprintln!("{}", "## Summary".green().bold());
+        for suite in &self.suites {
+            let failed = suite.failed.to_string();
+            eprintln!(
+                "{:<24} {:<32} {} passed, {} failed, {} ignored",
+                suite.path,
+                suite.target,
+                suite.passed.to_string().green(),
+                if suite.failed == 0 { failed.normal() } else { failed.red().bold() },
+                suite.ignored.to_string().yellow(),
+            );
+        }
+        let mut slowest: Vec<_> = self.durations.iter().collect();
+        slowest.sort_by(|(_, a), (_, b)| b.cmp(a));
+        if !slowest.is_empty() {
+            eprintln!();
+            eprintln!("Slowest tests:");
+            for (test, duration) in slowest.into_iter().take(10) {
+                eprintln!("{:>10.2?} {test}", duration);
+            }
+        }
+        eprintln!();
+    }
+}
+
 /// This is a custom renderer for `ui_test` output that does not emit github actions
 /// `group`s, while still producing regular github actions messages on test failures.
 struct TextAndGha;
@@ -292,10 +885,13 @@ fn failed_test<'a>(
         cmd: &'a Command,
         stderr: &'a [u8],
     ) -> Box<dyn std::fmt::Debug + 'a> {
-        Box::new((
+        let guards = (
             ui_test::status_emitter::Gha::<false>.failed_test(revision, path, cmd, stderr),
             ui_test::status_emitter::Text.failed_test(revision, path, cmd, stderr),
-        ))
+        );
+        eprintln!("to reproduce: {}", reproduction_command(cmd));
+        eprintln!();
+        Box::new(guards)
     }
 
     fn run_tests(&self, _config: &Config) -> Box<dyn ui_test::status_emitter::DuringTestRun> {
@@ -320,5 +916,6 @@ impl ui_test::status_emitter::DuringTestRun for TextAndGha {
     fn test_result(&mut self, path: &Path, revision: &str, result: &ui_test::TestResult) {
         ui_test::status_emitter::Text.test_result(path, revision, result);
         ui_test::status_emitter::Gha::<false>.test_result(path, revision, result);
+        SUMMARY.lock().unwrap().test_result(path, result);
     }
 }
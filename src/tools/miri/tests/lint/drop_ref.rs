@@ -0,0 +1,8 @@
+// The harness also checks this lint with `-D`, `-D --cap-lints=warn` and `-A` on the command line.
+
+fn main() {
+    let owned = vec![1, 2, 3];
+    drop(&owned);
+    //~^ WARN: calls to `std::mem::drop` with a reference instead of an owned value does nothing
+    assert_eq!(owned.len(), 3);
+}
@@ -0,0 +1,9 @@
+// The harness also checks this lint with `-D`, `-D --cap-lints=warn` and `-A` on the command line.
+use std::mem::forget;
+
+fn main() {
+    let owned = Box::new(42);
+    forget(&owned);
+    //~^ WARN: calls to `std::mem::forget` with a reference instead of an owned value does nothing
+    assert_eq!(*owned, 42);
+}
@@ -0,0 +1,10 @@
+// Uses the crates from `auxiliary`, which the test harness builds once and passes via `--extern`.
+
+aux_macros::make_answer!();
+
+fn main() {
+    let counter = aux_counter::Counter::new();
+    assert_eq!(counter.bump(), 1);
+    assert_eq!(counter.bump(), 2);
+    assert_eq!(answer(), 42);
+}
@@ -0,0 +1,18 @@
+// A plain library crate, available to all tests in this suite as `aux_counter`.
+
+use std::cell::Cell;
+
+pub struct Counter {
+    count: Cell<u32>,
+}
+
+impl Counter {
+    pub fn new() -> Self {
+        Counter { count: Cell::new(0) }
+    }
+
+    pub fn bump(&self) -> u32 {
+        self.count.set(self.count.get() + 1);
+        self.count.get()
+    }
+}
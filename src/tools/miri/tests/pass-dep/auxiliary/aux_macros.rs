@@ -0,0 +1,12 @@
+// A procedural macro crate; it gets built for the host and is available as `aux_macros`.
+#![crate_type = "proc-macro"]
+
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+
+/// Expands to a function `answer` returning 42.
+#[proc_macro]
+pub fn make_answer(_input: TokenStream) -> TokenStream {
+    "fn answer() -> u32 { 42 }".parse().unwrap()
+}
@@ -0,0 +1,68 @@
+// Stresses obligation selection and projection normalization in typeck: a deep chain of
+// associated-type projections that all have to be normalized, and many impls that are candidates
+// for every obligation.
+
+trait Step {
+    type Next: Step;
+    const DEPTH: usize;
+}
+
+struct Z;
+struct S<T>(T);
+
+impl Step for Z {
+    type Next = Z;
+    const DEPTH: usize = 0;
+}
+
+impl<T: Step> Step for S<T> {
+    type Next = T;
+    const DEPTH: usize = T::DEPTH + 1;
+}
+
+type N4 = S<S<S<S<Z>>>>;
+type N16 = S<S<S<S<S<S<S<S<S<S<S<S<N4>>>>>>>>>>>>;
+type N64 = S<S<S<S<S<S<S<S<S<S<S<S<S<S<S<S<S<S<S<S<S<S<S<S<S<S<S<S<S<S<S<S<S<S<S<S<S<S<S<S<S<S<S<S<S<S<S<S<N16>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>;
+
+fn depth<T: Step>() -> usize {
+    T::DEPTH
+}
+
+fn next_depth<T: Step>() -> usize {
+    <<<<T as Step>::Next as Step>::Next as Step>::Next as Step>::DEPTH
+}
+
+trait Describe {
+    fn describe(&self) -> usize;
+}
+
+macro_rules! describe_impls {
+    ($($ty:ty),*) => {
+        $(impl Describe for $ty {
+            fn describe(&self) -> usize {
+                std::mem::size_of::<$ty>()
+            }
+        })*
+    };
+}
+
+describe_impls!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, bool, char);
+
+impl<T: Describe, U: Describe> Describe for (T, U) {
+    fn describe(&self) -> usize {
+        self.0.describe() + self.1.describe()
+    }
+}
+
+impl<T: Describe> Describe for Option<T> {
+    fn describe(&self) -> usize {
+        self.as_ref().map_or(0, Describe::describe)
+    }
+}
+
+fn main() {
+    assert_eq!(depth::<N64>(), 64);
+    assert_eq!(next_depth::<N16>(), 13);
+    let nested = ((1u8, (2u16, Some((3u32, 4u64)))), (Some(true), ('x', (5i128, 6isize))));
+    assert_eq!(nested.describe(), 1 + 2 + 4 + 8 + 1 + 4 + 16 + 8);
+}
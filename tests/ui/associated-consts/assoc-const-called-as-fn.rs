@@ -0,0 +1,25 @@
+// run-rustfix
+// Check the suggestions when an associated constant is called like a function.
+
+struct S;
+
+impl S {
+    const C: u32 = 1;
+    const ARR: [u8; 3] = [1, 2, 3];
+    const PAIR: (u8, char) = (1, 'a');
+}
+
+trait T {
+    const K: u8;
+}
+
+impl T for S {
+    const K: u8 = 3;
+}
+
+fn main() {
+    let _ = S::C(); //~ ERROR expected function, found associated constant `S::C`
+    let _ = S::ARR(1); //~ ERROR expected function, found associated constant `S::ARR`
+    let _ = S::PAIR(1); //~ ERROR expected function, found associated constant `S::PAIR`
+    let _ = <S as T>::K(); //~ ERROR expected function, found associated constant `<S as T>::K`
+}
@@ -0,0 +1,12 @@
+// edition:2021
+
+use std::future::Future;
+
+fn produce() -> impl Future<Output = u8> {
+    //~^ ERROR to be a future that resolves to `u8`, but it resolves to `()`
+    async {
+        1u8;
+    }
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+// edition:2021
+
+async fn f() -> u32 {
+    //~^ ERROR mismatched types
+    drop(1u32);
+}
+
+struct S;
+
+impl S {
+    async fn g(&self) -> u32 {
+        //~^ ERROR mismatched types
+        drop(1u32);
+    }
+}
+
+fn main() {}
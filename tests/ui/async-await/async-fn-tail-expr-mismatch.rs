@@ -0,0 +1,10 @@
+// edition:2021
+
+// A mismatched tail expression of an `async fn` is reported on the expression itself,
+// just like in a regular function.
+
+async fn f() -> u32 {
+    "hello" //~ ERROR mismatched types
+}
+
+fn main() {}
@@ -0,0 +1,60 @@
+// check-pass
+// edition:2021
+
+// Typeck used to re-select every pending obligation of a body once per generator
+// when resolving generator interiors. Make sure a body with hundreds of small async
+// blocks still type-checks.
+
+use std::future::Future;
+use std::pin::Pin;
+
+type BoxFuture = Pin<Box<dyn Future<Output = u32> + Send>>;
+
+macro_rules! push16 {
+    ($v:ident, $x:expr) => {
+        $v.push(Box::pin(async move { $x }) as BoxFuture);
+        $v.push(Box::pin(async move { $x }) as BoxFuture);
+        $v.push(Box::pin(async move { $x }) as BoxFuture);
+        $v.push(Box::pin(async move { $x }) as BoxFuture);
+        $v.push(Box::pin(async move { $x }) as BoxFuture);
+        $v.push(Box::pin(async move { $x }) as BoxFuture);
+        $v.push(Box::pin(async move { $x }) as BoxFuture);
+        $v.push(Box::pin(async move { $x }) as BoxFuture);
+        $v.push(Box::pin(async move { $x }) as BoxFuture);
+        $v.push(Box::pin(async move { $x }) as BoxFuture);
+        $v.push(Box::pin(async move { $x }) as BoxFuture);
+        $v.push(Box::pin(async move { $x }) as BoxFuture);
+        $v.push(Box::pin(async move { $x }) as BoxFuture);
+        $v.push(Box::pin(async move { $x }) as BoxFuture);
+        $v.push(Box::pin(async move { $x }) as BoxFuture);
+        $v.push(Box::pin(async move { $x }) as BoxFuture);
+    };
+}
+
+macro_rules! push256 {
+    ($v:ident, $x:expr) => {
+        push16!($v, $x);
+        push16!($v, $x);
+        push16!($v, $x);
+        push16!($v, $x);
+        push16!($v, $x);
+        push16!($v, $x);
+        push16!($v, $x);
+        push16!($v, $x);
+        push16!($v, $x);
+        push16!($v, $x);
+        push16!($v, $x);
+        push16!($v, $x);
+        push16!($v, $x);
+        push16!($v, $x);
+        push16!($v, $x);
+        push16!($v, $x);
+    };
+}
+
+fn main() {
+    let mut futures = Vec::new();
+    let x = 1u32;
+    push256!(futures, x);
+    assert_eq!(futures.len(), 256);
+}
@@ -0,0 +1,44 @@
+// Check that `a op= b` suggests `a = a op b` when only the non-assigning
+// operator is implemented.
+
+// run-rustfix
+
+use std::ops::{Add, Mul, Sub};
+
+#[derive(Clone, Copy)]
+struct Meters(f64);
+
+impl Add for Meters {
+    type Output = Meters;
+
+    fn add(self, rhs: Meters) -> Meters {
+        Meters(self.0 + rhs.0)
+    }
+}
+
+impl Mul<f64> for Meters {
+    type Output = Meters;
+
+    fn mul(self, rhs: f64) -> Meters {
+        Meters(self.0 * rhs)
+    }
+}
+
+impl Sub for Meters {
+    type Output = Meters;
+
+    fn sub(self, rhs: Meters) -> Meters {
+        Meters(self.0 - rhs.0)
+    }
+}
+
+fn main() {
+    let mut total = Meters(0.0);
+    total += Meters(1.5);
+    //~^ ERROR binary assignment operation `+=` cannot be applied to type `Meters`
+    total *= 2.0;
+    //~^ ERROR binary assignment operation `*=` cannot be applied to type `Meters`
+    total -= Meters(1.0) + Meters(0.5);
+    //~^ ERROR binary assignment operation `-=` cannot be applied to type `Meters`
+    let _ = total;
+}
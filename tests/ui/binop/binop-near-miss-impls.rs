@@ -0,0 +1,32 @@
+// Check that failing to apply an operator lists the impls of the operator trait
+// that would apply if one of the operands had a different type.
+
+use std::ops::{Add, Sub};
+
+struct Meters(f64);
+
+impl Add for Meters {
+    type Output = Meters;
+
+    fn add(self, rhs: Meters) -> Meters {
+        Meters(self.0 + rhs.0)
+    }
+}
+
+struct Grid;
+
+impl Sub<Grid> for &Grid {
+    type Output = ();
+
+    fn sub(self, _: Grid) {}
+}
+
+fn main() {
+    let a = Meters(1.0);
+    let b = Meters(2.0);
+    let _ = &a + b;
+    //~^ ERROR cannot add `Meters` to `&Meters`
+
+    let _ = Grid - Grid;
+    //~^ ERROR cannot subtract `Grid` from `Grid`
+}
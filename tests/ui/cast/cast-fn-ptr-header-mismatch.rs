@@ -0,0 +1,25 @@
+// Check that casts between function types that only differ in their ABI,
+// unsafety or C-variadicness explain what differs.
+
+fn foo() {}
+unsafe fn bar() {}
+
+extern "C" {
+    fn variadic(x: i32, ...);
+}
+
+fn main() {
+    let _ = foo as extern "C" fn();
+    //~^ ERROR cannot cast `fn() {foo}` as `extern "C" fn()` because their ABIs differ
+    let _ = bar as fn();
+    //~^ ERROR because the function is `unsafe` but the function pointer is not
+    let f: fn() = foo;
+    let _ = f as unsafe extern "C" fn();
+    //~^ ERROR because their ABIs differ
+    let _ = variadic as unsafe extern "C" fn(i32);
+    //~^ ERROR because only one of them is C-variadic
+    let _ = bar as extern "C" fn();
+    //~^ ERROR because their ABI, unsafety or C-variadicness differ
+    let _ = foo as extern "C" fn(i32);
+    //~^ ERROR non-primitive cast
+}
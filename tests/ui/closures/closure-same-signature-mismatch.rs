@@ -0,0 +1,10 @@
+fn main() {
+    let mut f = |x: i32| x + 1;
+    f = |x: i32| x * 2;
+    //~^ ERROR mismatched types
+
+    let y = 3;
+    let mut g = |x: i32| x + y;
+    g = |x: i32| x * y;
+    //~^ ERROR mismatched types
+}
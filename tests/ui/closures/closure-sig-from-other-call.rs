@@ -0,0 +1,19 @@
+// When a closure's signature was deduced from a different expectation than the one
+// it fails to satisfy, point at where the signature came from.
+
+fn takes_i32<F: Fn(i32)>(_: &mut F) {}
+fn takes_str<F: Fn(&str)>(_: &mut F) {}
+
+fn main() {
+    let mut f = None;
+    if let Some(f) = &mut f {
+        takes_i32(f);
+        //~^ NOTE closure inferred to have a different signature due to this expectation
+        takes_str(f);
+        //~^ ERROR type mismatch in closure arguments
+        //~| NOTE expected due to this
+        //~| NOTE expected closure signature
+    }
+    f = Some(|_| {});
+    //~^ NOTE found signature defined here
+}
@@ -0,0 +1,20 @@
+// check-pass
+// An rvalue borrowed where a pointer to a pointer is expected is coerced to
+// the inner pointer type before it is borrowed.
+
+#![feature(coerce_borrowed_pointers)]
+
+fn takes_str(_: &&str) {}
+fn takes_slice(_: &mut &mut [i32]) {}
+
+fn main() {
+    let s = String::new();
+    takes_str(&&s);
+
+    let _: &&[i32] = &&[1, 2, 3];
+
+    let mut a = [1, 2];
+    takes_slice(&mut &mut a);
+
+    let _: &*const [u8] = &&[1u8, 2];
+}
@@ -0,0 +1,12 @@
+// Check that the error of an array length naming a constant that fails to
+// evaluate also points at the type annotation the length is written in.
+
+const ONE: usize = 1;
+const TWO: usize = 2;
+const LEN: usize = ONE - TWO;
+//~^ ERROR evaluation of constant value failed
+
+fn main() {
+    let a: [u8; LEN] = [];
+    let b: Option<[[u8; LEN]; 2]> = None;
+}
@@ -0,0 +1,13 @@
+// Check that a value in the `then` branch of an `if` without `else` that is the
+// tail of a block expected to be `()` points at the missing `else`.
+
+fn foo(c: bool) {
+    if c {
+        1
+        //~^ ERROR mismatched types
+    }
+}
+
+fn main() {
+    foo(true);
+}
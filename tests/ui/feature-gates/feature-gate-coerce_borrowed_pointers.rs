@@ -0,0 +1,13 @@
+// Without the feature, a borrowed rvalue is not coerced to the pointer type
+// expected behind the borrow.
+
+fn takes_str(_: &&str) {}
+
+fn main() {
+    let s = String::new();
+    takes_str(&&s);
+    //~^ ERROR mismatched types
+
+    let _: &&[i32] = &&[1, 2, 3];
+    //~^ ERROR mismatched types
+}
@@ -0,0 +1,21 @@
+// Errors in the arguments of formatting macros point out which argument of the macro
+// written by the user is at fault, rather than the code the macro desugars into.
+
+use std::fmt::Write;
+
+struct NotDisplay;
+
+fn main() {
+    let mut s = String::new();
+    let _ = write!(s, "{}", NotDisplay);
+    //~^ ERROR `NotDisplay` doesn't implement `std::fmt::Display`
+    assert_eq!(1, 1, "{} {}", 1, NotDisplay);
+    //~^ ERROR `NotDisplay` doesn't implement `std::fmt::Display`
+    let _ = format!("{1} {0}", 1, NotDisplay);
+    //~^ ERROR `NotDisplay` doesn't implement `std::fmt::Display`
+    let x = NotDisplay;
+    let _ = format!("{x}");
+    //~^ ERROR `NotDisplay` doesn't implement `std::fmt::Display`
+    let _ = format!("{} {}", "inlined", NotDisplay);
+    //~^ ERROR `NotDisplay` doesn't implement `std::fmt::Display`
+}
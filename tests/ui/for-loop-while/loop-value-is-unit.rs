@@ -0,0 +1,13 @@
+// Check that using a `for` or `while` loop as a value explains that such loops
+// evaluate to `()`.
+
+fn main() {
+    let _: i32 = for i in 0..10 {
+        //~^ ERROR mismatched types
+        if i > 5 {
+            break;
+        }
+    };
+    let _: i32 = while let Some(_) = None::<i32> {};
+    //~^ ERROR mismatched types
+}
@@ -0,0 +1,13 @@
+// Check that an unsatisfied bound on an `impl Trait` nested in the bounds of an
+// argument-position `impl Trait` is blamed on the argument passed to it.
+
+use std::fmt::Debug;
+
+fn debug_all(items: impl Iterator<Item = impl Debug>) {}
+
+struct NotDebug;
+
+fn main() {
+    debug_all(std::iter::once(NotDebug));
+    //~^ ERROR `NotDebug` doesn't implement `Debug`
+}
@@ -0,0 +1,18 @@
+use std::collections::HashMap;
+
+fn vec() {
+    let _v: Vec<_> = Vec::new();
+    //~^ ERROR type annotations needed
+}
+
+fn map() {
+    let _m: HashMap<_, Vec<_>> = HashMap::new();
+    //~^ ERROR type annotations needed
+}
+
+fn tuple() {
+    let (_a, _b): (u32, _) = (1, Default::default());
+    //~^ ERROR type annotations needed
+}
+
+fn main() {}
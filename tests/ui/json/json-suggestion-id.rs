@@ -0,0 +1,9 @@
+// compile-flags: --json=diagnostic-short --error-format=json
+
+// Suggestions carry a stable identifier in JSON diagnostics.
+
+fn foo() -> usize {
+    22;
+}
+
+fn main() {}
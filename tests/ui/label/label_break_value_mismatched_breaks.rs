@@ -0,0 +1,15 @@
+// Check that a `break 'label` with a mismatched value points at the earlier `break`s that
+// determined the type of the labeled block.
+
+fn main() {
+    let _ = 'a: {
+        if true {
+            break 'a 1;
+        }
+        if false {
+            break 'a 2;
+        }
+        break 'a "three";
+        //~^ ERROR mismatched types
+    };
+}
@@ -0,0 +1,40 @@
+// run-pass
+// Breaks out of nested loops and labeled blocks reach the right target, with
+// each breakable expression getting the type of the values broken out of it.
+
+fn classify(n: u32, skip: u32) -> (&'static str, u64) {
+    let mut total = 0u64;
+    let kind = 'outer: loop {
+        let step: u64 = 'block: {
+            for i in 0..n {
+                if i == 3 {
+                    break 'outer "three";
+                }
+                let inner = loop {
+                    if i == skip {
+                        break 'block 7;
+                    }
+                    break i;
+                };
+                total += u64::from(inner);
+            }
+            break 'block u64::from(n) * 50;
+        };
+        total += step;
+        while total < 100 {
+            if total > 50 {
+                break 'outer "large";
+            }
+            total = total * 2 + 1;
+        }
+        break "small";
+    };
+    (kind, total)
+}
+
+fn main() {
+    assert_eq!(classify(0, 9), ("large", 63));
+    assert_eq!(classify(2, 9), ("small", 101));
+    assert_eq!(classify(5, 9), ("three", 3));
+    assert_eq!(classify(5, 1), ("large", 63));
+}
@@ -0,0 +1,17 @@
+// Calls to the same closure that can't be resolved to a call trait are reported together.
+
+#![feature(lang_items)]
+#![feature(no_core)]
+
+#![no_core]
+fn main() {
+    let f = || {};
+    f();
+    f();
+    //~^^ ERROR: failed to find an overloaded call trait for closure call
+    //~| HELP: alternatively, use a `fn` item
+}
+#[lang = "sized"]
+trait Sized {}
+#[lang = "copy"]
+trait Copy {}
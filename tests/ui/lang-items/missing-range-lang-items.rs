@@ -0,0 +1,20 @@
+// Check that range syntax in a `no_core` crate reports which lang item is missing
+// at the range expression instead of aborting.
+
+#![feature(lang_items, start, no_core)]
+#![no_core]
+
+#[lang = "sized"]
+trait Sized {}
+
+#[lang = "copy"]
+trait Copy {}
+
+#[start]
+fn start(_: isize, _: *const *const u8) -> isize {
+    let _ = 1..2;
+    //~^ ERROR this range expression requires the `Range` lang item, which is not defined
+    let _ = 1..=2;
+    //~^ ERROR this range expression requires the `range_inclusive_new` lang item
+    0
+}
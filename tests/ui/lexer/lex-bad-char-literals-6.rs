@@ -7,6 +7,7 @@ fn main() {
     //~^ ERROR: character literal may only contain one codepoint
 
     if x == y {}
+    //~^ ERROR can't compare `&str` with `char`
     if y == z {}  // no error here
     if x == z {}
 
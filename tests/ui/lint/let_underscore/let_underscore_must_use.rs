@@ -0,0 +1,43 @@
+// check-pass
+#![warn(let_underscore_must_use)]
+
+#[must_use = "the guard releases its resource when dropped"]
+struct Guard<T>(T);
+
+fn guard<T>(value: T) -> Guard<T> {
+    Guard(value)
+}
+
+fn parse(s: &str) -> Result<u32, std::num::ParseIntError> {
+    s.parse()
+}
+
+fn main() {
+    let _ = guard(1u8); //~ WARNING value of type `Guard<u8>` that must be used is discarded
+    let _ = Box::new(guard("boxed")); //~ WARNING that must be used is discarded
+    let _ = parse("1"); //~ WARNING that must be used is discarded
+    match parse("2") { //~ WARNING that must be used is discarded
+        _ => {}
+    }
+
+    let _unused = parse("3");
+    let _ = 1u8;
+    match parse("4") {
+        Ok(_) => {}
+        _ => {}
+    }
+
+    // Wildcard patterns don't move out of places, so these values aren't discarded.
+    let r = parse("5");
+    let _ = r;
+    match r {
+        _ => {}
+    }
+    let g = guard((parse("6"), 0u8));
+    let _ = g.0;
+    let _ = (g.0).0;
+    let _used = (r, g);
+
+    // A field of a temporary is discarded together with the temporary.
+    let _ = guard((parse("8"), 0u8)).0.0; //~ WARNING that must be used is discarded
+}
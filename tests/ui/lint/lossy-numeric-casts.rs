@@ -0,0 +1,28 @@
+// edition:2021
+#![deny(lossy_numeric_casts)]
+
+fn narrow(x: u32) -> u8 {
+    x as u8
+    //~^ ERROR casting `u32` to `u8` may lose information
+}
+
+fn narrow_in_result(x: i64) -> Result<i32, std::num::TryFromIntError> {
+    Ok(x as i32)
+    //~^ ERROR casting `i64` to `i32` may lose information
+}
+
+fn narrow_in_option(x: u16) -> Option<u8> {
+    Some(x as u8)
+    //~^ ERROR casting `u16` to `u8` may lose information
+}
+
+fn float_to_int(x: f64) -> i32 {
+    x as i32
+    //~^ ERROR casting `f64` to `i32` may lose information
+}
+
+fn lossless(x: u8, y: i16) -> (u32, i64, f64) {
+    (x as u32, y as i64, x as f64)
+}
+
+fn main() {}
@@ -0,0 +1,59 @@
+#![deny(numeric_fallback)]
+
+fn show<T: std::fmt::Debug>(x: T) {
+    println!("{:?}", x);
+}
+
+fn unconstrained() {
+    show(1);
+    //~^ ERROR the type of `1` falls back to `i32`
+    show(2.5);
+    //~^ ERROR the type of `2.5` falls back to `f64`
+    show(3.);
+    //~^ ERROR the type of `3.` falls back to `f64`
+}
+
+fn unified() {
+    // Only the literal that created the variable is reported.
+    let a = 0x10;
+    //~^ ERROR the type of `0x10` falls back to `i32`
+    let b = a + 20;
+    show(b);
+}
+
+fn constrained() {
+    let c: u8 = 4;
+    show(c);
+    show(5u64);
+    show(6.0f32);
+}
+
+#[allow(numeric_fallback)]
+fn allowed() {
+    show(7);
+}
+
+// The level at each literal counts, not the one of the whole body.
+#[allow(numeric_fallback)]
+fn denied_inside_allowed() {
+    #[deny(numeric_fallback)]
+    let d = 8;
+    //~^ ERROR the type of `8` falls back to `i32`
+    show(d);
+    show(9);
+}
+
+fn allowed_inside_denied() {
+    #[allow(numeric_fallback)]
+    let e = 10;
+    show(e);
+}
+
+fn main() {
+    unconstrained();
+    unified();
+    constrained();
+    allowed();
+    denied_inside_allowed();
+    allowed_inside_denied();
+}
@@ -0,0 +1,22 @@
+// normalize-stderr-test "pointers are \d+ bits" -> "pointers are $$BITS bits"
+
+#![deny(pointer_integer_casts)]
+
+fn foo() {}
+
+fn main() {
+    let x = 0u8;
+    let addr = &x as *const u8 as usize;
+    //~^ ERROR casting pointer `*const u8` to integer `usize`
+    let _: *const u8 = addr as *const _;
+    //~^ ERROR casting integer `usize` to pointer `*const u8`
+    let n = 0;
+    let _ = n as *mut u8;
+    //~^ ERROR casting integer `i32` to pointer `*mut u8`
+    let _ = foo as fn() as usize;
+    //~^ ERROR casting pointer `fn()` to integer `usize`
+
+    // Casts between pointers and between integers are not linted.
+    let _ = &x as *const u8 as *const i8;
+    let _ = addr as u64;
+}
@@ -0,0 +1,41 @@
+// Errors reported at the same place in several expansions of a macro are
+// only emitted once, with a note saying how often they were repeated.
+
+macro_rules! call_missing {
+    () => {
+        ().missing_method()
+    };
+}
+
+macro_rules! call_with_two {
+    ($e:expr) => {
+        takes_one($e, $e)
+    };
+}
+
+macro_rules! call_on {
+    ($e:expr) => {
+        $e.missing()
+    };
+}
+
+fn takes_one(_: i32) {}
+
+macro_rules! call_with {
+    ($($e:expr),*) => {
+        takes_one($($e),*)
+    };
+}
+
+fn main() {
+    call_missing!();
+    call_missing!();
+    call_missing!();
+    call_with_two!(1);
+    call_with_two!(2);
+    call_on!(1u8);
+    call_on!(2u8);
+    call_on!("");
+    call_with!(1, 2);
+    call_with!(1, 2, 3);
+}
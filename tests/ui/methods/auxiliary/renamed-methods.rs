@@ -0,0 +1,19 @@
+#![feature(deprecated_suggestion)]
+
+pub struct Counter;
+
+impl Counter {
+    pub fn len(&self) -> usize {
+        0
+    }
+
+    #[deprecated(note = "renamed to `len`", suggestion = "len")]
+    pub fn get_length(&self) -> usize {
+        0
+    }
+
+    #[doc(alias = "size")]
+    pub fn count(&self) -> usize {
+        0
+    }
+}
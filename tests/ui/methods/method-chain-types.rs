@@ -0,0 +1,10 @@
+// compile-flags: -Zmethod-chain-types
+
+fn main() {
+    let _: u32 = "  hello ".to_string().trim().len();
+    //~^ ERROR mismatched types
+
+    // A single call is not a chain.
+    let _: u32 = "hello".len();
+    //~^ ERROR mismatched types
+}
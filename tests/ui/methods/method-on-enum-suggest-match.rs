@@ -0,0 +1,26 @@
+enum Data {
+    Text(String),
+    Bytes(Vec<u8>),
+}
+
+enum Shape {
+    Circle { name: String, radius: f64 },
+    Square { name: String, side: f64 },
+}
+
+fn len(data: Data) -> usize {
+    data.len()
+    //~^ ERROR no method named `len` found for enum `Data` in the current scope
+}
+
+fn name(shape: &Shape) -> &String {
+    shape.name()
+    //~^ ERROR no method named `name` found for reference `&Shape` in the current scope
+}
+
+fn owned_name(shape: Shape) -> String {
+    shape.name()
+    //~^ ERROR no method named `name` found for enum `Shape` in the current scope
+}
+
+fn main() {}
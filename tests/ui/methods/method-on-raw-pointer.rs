@@ -0,0 +1,41 @@
+struct Counter {
+    count: u32,
+}
+
+impl Counter {
+    fn get(&self) -> u32 {
+        self.count
+    }
+
+    fn bump(&mut self) {
+        self.count += 1;
+    }
+}
+
+fn read(ptr: *const Counter) -> u32 {
+    ptr.get()
+    //~^ ERROR no method named `get` found for raw pointer `*const Counter` in the current scope
+}
+
+fn write(ptr: *mut Counter) {
+    ptr.bump();
+    //~^ ERROR no method named `bump` found for raw pointer `*mut Counter` in the current scope
+}
+
+unsafe fn read_unsafe(ptr: *const Counter) -> u32 {
+    ptr.get()
+    //~^ ERROR no method named `get` found for raw pointer `*const Counter` in the current scope
+}
+
+fn missing(ptr: *const Counter) {
+    ptr.reset();
+    //~^ ERROR no method named `reset` found for raw pointer `*const Counter` in the current scope
+}
+
+fn main() {
+    let mut counter = Counter { count: 0 };
+    write(&mut counter);
+    read(&counter);
+    unsafe { read_unsafe(&counter) };
+    missing(&counter);
+}
@@ -0,0 +1,13 @@
+// aux-build:renamed-methods.rs
+// run-rustfix
+
+extern crate renamed_methods;
+
+use renamed_methods::Counter;
+
+fn main() {
+    let _ = Counter.get_lenght();
+    //~^ ERROR no method named `get_lenght` found for struct `Counter` in the current scope
+    let _ = Counter.size();
+    //~^ ERROR no method named `size` found for struct `Counter` in the current scope
+}
@@ -0,0 +1,47 @@
+// Unit test for the "user substitutions" annotated on method calls whose
+// turbofish contains projections, possibly nested in other types.
+
+// compile-flags:-Zverbose
+
+#![feature(rustc_attrs)]
+
+trait Tr {
+    type Assoc;
+}
+
+impl Tr for u8 {
+    type Assoc = u16;
+}
+
+impl<'a> Tr for &'a u8 {
+    type Assoc = &'a u16;
+}
+
+struct S;
+
+impl S {
+    fn method<U>(&self, _: U) {}
+}
+
+trait Ext {
+    fn ext<U>(&self, _: U) {}
+}
+
+impl Ext for S {}
+
+#[rustc_dump_user_substs]
+fn main() {
+    let y = S;
+
+    // Projections are kept unnormalized, as they were written.
+    y.method::<<u8 as Tr>::Assoc>(1); //~ ERROR [<u8 as Tr>::Assoc]
+    y.method::<<&'static u8 as Tr>::Assoc>(&1); //~ ERROR [<&ReStatic u8 as Tr>::Assoc]
+
+    // Projections nested in other types are kept as well.
+    y.method::<Option<<&'static u8 as Tr>::Assoc>>(None);
+    //~^ ERROR [std::option::Option<<&ReStatic u8 as Tr>::Assoc>]
+
+    // Only the method's own parameters are given, the trait's are variables.
+    y.ext::<(u32, <&'static u8 as Tr>::Assoc)>((0, &1));
+    //~^ ERROR [^0, (u32, <&ReStatic u8 as Tr>::Assoc)]
+}
@@ -0,0 +1,41 @@
+// Check that user-written types in a method's turbofish are enforced by NLL,
+// including when they are only visible under a projection.
+
+trait Tr {
+    type Assoc;
+}
+
+impl<'a> Tr for &'a u8 {
+    type Assoc = &'a u16;
+}
+
+struct S;
+
+impl S {
+    fn method<U>(&self, _: U) {}
+}
+
+trait Ext {
+    fn ext<U>(&self, _: U) {}
+}
+
+impl Ext for S {}
+
+fn main() {
+    let y = S;
+
+    let a = 1;
+    y.method::<<&'static u8 as Tr>::Assoc>(&a); //~ ERROR `a` does not live long enough
+
+    let b = 1;
+    y.method::<Option<<&'static u8 as Tr>::Assoc>>(Some(&b));
+    //~^ ERROR `b` does not live long enough
+
+    let c = 1;
+    y.ext::<(u32, <&'static u8 as Tr>::Assoc)>((0, &c));
+    //~^ ERROR `c` does not live long enough
+
+    let d = 1;
+    let _ = || y.method::<<&'static u8 as Tr>::Assoc>(&d);
+    //~^ ERROR `d` does not live long enough
+}
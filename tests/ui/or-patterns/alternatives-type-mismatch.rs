@@ -0,0 +1,17 @@
+// Test that type mismatches in several alternatives of an or-pattern are reported together,
+// while mismatches in the subpatterns of the alternatives are reported as usual.
+
+struct A;
+struct B;
+struct C;
+
+fn main() {
+    match A {
+        A | B {} | C {} => {} //~ ERROR mismatched types
+    }
+    match (A, A) {
+        (A, B {}) | (C {}, A) => {}
+        //~^ ERROR mismatched types
+        //~| ERROR mismatched types
+    }
+}
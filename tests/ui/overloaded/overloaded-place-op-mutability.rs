@@ -0,0 +1,64 @@
+// run-pass
+// Overloaded indices and derefs of places that need to be mutable use
+// `IndexMut` and `DerefMut`, also when nested in other place projections
+// and when the place is only used inside nested closures.
+
+use std::ops::{Deref, DerefMut, Index, IndexMut};
+
+struct OnlyMut<T>(T);
+
+impl<T> Index<usize> for OnlyMut<T> {
+    type Output = T;
+    fn index(&self, _: usize) -> &T {
+        panic!("`Index` used for a mutable place")
+    }
+}
+
+impl<T> IndexMut<usize> for OnlyMut<T> {
+    fn index_mut(&mut self, _: usize) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<T> Deref for OnlyMut<T> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        panic!("`Deref` used for a mutable place")
+    }
+}
+
+impl<T> DerefMut for OnlyMut<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+struct Wrapper {
+    inner: OnlyMut<OnlyMut<i32>>,
+}
+
+fn main() {
+    let mut w = Wrapper { inner: OnlyMut(OnlyMut(0)) };
+    w.inner[0][0] = 1;
+    *w.inner[0] += 1;
+    let r = &mut w.inner[0][0];
+    *r += 1;
+    match w.inner[0][0] {
+        ref mut x => *x += 1,
+    }
+    let mut bump = || w.inner[0][0] += 1;
+    bump();
+    let mut outer = || {
+        let mut inner = || {
+            *w.inner[0] += 1;
+            let mut innermost = || w.inner[0][0] = *&mut w.inner[0][0] + 1;
+            innermost();
+        };
+        inner();
+        let r = &mut w.inner[0];
+        let mut set = |v| **r = v + 1;
+        set(7);
+    };
+    outer();
+    assert_eq!((w.inner.0).0, 8);
+}
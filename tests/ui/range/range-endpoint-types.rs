@@ -0,0 +1,24 @@
+// Both endpoints of a range literal are checked against the same expectation, and when
+// the end conflicts with the type decided by the start, the error points at both of them.
+
+use std::ops::Range;
+
+fn contains(x: u64, n: u64) -> bool {
+    (0..n).contains(&x) && (n..0).contains(&x) && (0..=n).contains(&x)
+}
+
+fn mismatched(a: u8, b: u32) {
+    let _ = a..b;
+    //~^ ERROR mismatched types
+    let _ = b..=a;
+    //~^ ERROR mismatched types
+    let _: Range<u64> = a..b;
+    //~^ ERROR mismatched types
+    //~| ERROR mismatched types
+    let _ = 0u16..1u32;
+    //~^ ERROR mismatched types
+}
+
+fn main() {
+    contains(3, 10);
+}
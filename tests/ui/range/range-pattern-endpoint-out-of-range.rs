@@ -0,0 +1,27 @@
+// Check that range pattern endpoints that don't fit in the scrutinee's type are
+// reported with the type's range, and that the range is capped where possible.
+
+#![feature(exclusive_range_pattern)]
+#![allow(unreachable_patterns, overlapping_range_endpoints)]
+
+fn main() {
+    match 0u8 {
+        0..=300 => {}
+        //~^ ERROR range pattern endpoint is out of range for `u8`
+        0..=300u8 => {}
+        //~^ ERROR range pattern endpoint is out of range for `u8`
+        10..300 => {}
+        //~^ ERROR range pattern endpoint is out of range for `u8`
+        300..=400 => {}
+        //~^ ERROR range pattern endpoint is out of range for `u8`
+        //~| ERROR range pattern endpoint is out of range for `u8`
+        _ => {}
+    }
+
+    match 0i8 {
+        -200..=100 => {}
+        //~^ ERROR range pattern endpoint is out of range for `i8`
+        -128..=127 => {}
+        _ => {}
+    }
+}
@@ -0,0 +1,21 @@
+#![deny(unreachable_code)]
+
+fn diverge() -> ! {
+    panic!()
+}
+
+fn f() {}
+
+fn call(_: u8, _: &str) {}
+
+fn main() {
+    // The unreachable argument is still type-checked and reported on its own.
+    call(diverge(), 1);
+    //~^ ERROR unreachable expression
+    //~| ERROR mismatched types
+
+    // A block without a tail expression has type `()`, even after a diverging argument.
+    call(diverge(), { f(); });
+    //~^ ERROR unreachable expression
+    //~| ERROR mismatched types
+}
@@ -0,0 +1,24 @@
+// Check that the call-argument case of `unreachable_code` can be allowed on its own.
+
+#![deny(unreachable_code)]
+#![allow(unreachable_call_args)]
+#![allow(unused_variables)]
+#![allow(dead_code)]
+
+fn foo(x: u8, y: u8) {}
+
+fn placeholder() {
+    foo(todo!(), 22);
+}
+
+fn tail() -> u8 {
+    return 1;
+    22 //~ ERROR unreachable expression
+}
+
+fn statement() {
+    return;
+    foo(1, 2); //~ ERROR unreachable statement
+}
+
+fn main() {}
@@ -0,0 +1,29 @@
+// Suggest alternatives to `[expr; N]` when `expr` isn't `Copy`.
+
+#![feature(inline_const)]
+
+struct NotClone;
+
+const fn not_clone() -> Option<NotClone> {
+    None
+}
+
+fn strings() {
+    let _ = [String::new(); 3];
+    //~^ ERROR the trait bound `String: Copy` is not satisfied
+}
+
+fn vecs() {
+    let _ = [Vec::<u8>::with_capacity(1); 2 * 2];
+    //~^ ERROR the trait bound `Vec<u8>: Copy` is not satisfied
+}
+
+fn not_cloneable<const N: usize>() {
+    let _ = [not_clone(); N];
+    //~^ ERROR the trait bound `NotClone: Copy` is not satisfied
+}
+
+const STRINGS: [String; 2] = [String::new(); 2];
+//~^ ERROR the trait bound `String: Copy` is not satisfied
+
+fn main() {}
@@ -0,0 +1,15 @@
+fn compute(_: &u32) -> u32 {
+    0
+}
+
+fn main() {
+    let count = compute(&count);
+    //~^ ERROR cannot find value `count` in this scope
+
+    let (total, _rest) = (1, total);
+    //~^ ERROR cannot find value `total` in this scope
+
+    // Shadowing a previous binding is fine.
+    let y = 1;
+    let y = compute(&y);
+}
@@ -0,0 +1,22 @@
+// Struct literals whose path fails to resolve suggest importing a matching
+// struct or variant from another module.
+
+mod geometry {
+    pub struct Point {
+        pub x: i32,
+        pub y: i32,
+    }
+
+    pub enum Shape {
+        Circle { radius: u32 },
+    }
+}
+
+fn main() {
+    let _ = Point { x: 0, y: 0 };
+    //~^ ERROR cannot find struct, variant or union type `Point` in this scope
+    let _ = Circle { radius: 1 };
+    //~^ ERROR cannot find struct, variant or union type `Circle` in this scope
+    let _ = Shape::Circle { radius: 1 };
+    //~^ ERROR failed to resolve: use of undeclared type `Shape`
+}
@@ -0,0 +1,13 @@
+// The return type of `main` is checked together with its body. When the error
+// is about a bound of the `Termination` impl, point out where it comes from.
+
+struct NoDebug;
+
+fn main() -> Result<(), NoDebug> {
+    //~^ ERROR `NoDebug` doesn't implement `Debug`
+    Ok(())
+}
+
+fn other() {
+    let _: u32 = ""; //~ ERROR mismatched types
+}
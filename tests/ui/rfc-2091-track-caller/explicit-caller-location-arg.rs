@@ -0,0 +1,15 @@
+use std::panic::Location;
+
+#[track_caller]
+fn tracked(_x: u32) -> &'static Location<'static> {
+    Location::caller()
+}
+
+fn main() {
+    tracked(1, Location::caller());
+    //~^ ERROR this function takes 1 argument but 2 arguments were supplied
+    tracked(1, 2);
+    //~^ ERROR this function takes 1 argument but 2 arguments were supplied
+    tracked();
+    //~^ ERROR this function takes 1 argument but 0 arguments were supplied
+}
@@ -0,0 +1,10 @@
+// Test that a type mismatch caused by a binding being bound by reference through default binding
+// modes points at the pattern and suggests matching on the reference explicitly.
+
+struct Foo(i32);
+
+fn main() {
+    let foo = &Foo(42);
+    let Foo(x) = foo;
+    let y: i32 = x; //~ ERROR mismatched types
+}
@@ -0,0 +1,17 @@
+// Check that a base expression of the wrong type points at the fields that are taken from it.
+
+struct S<A, B> {
+    a: A,
+    b: B,
+    c: u8,
+}
+
+fn main() {
+    let base: S<u8, u16> = S { a: 0, b: 1, c: 2 };
+    let _: S<u8, u8> = S { a: 1, ..base };
+    //~^ ERROR type changing struct updating is experimental
+    //~| ERROR mismatched types
+
+    // Literals in the base expression are inferred from the struct literal.
+    let _: S<u8, u8> = S { a: 1, ..S { a: 2, b: 3, c: 4 } };
+}
@@ -0,0 +1,20 @@
+// Check that mismatches in struct literal fields whose type is built from the struct's generic
+// parameters note the generic arguments, but not for fields with a bare type parameter type.
+
+struct Pair<T, U> {
+    items: Vec<(T, U)>,
+    first: T,
+}
+
+struct Array<T, const N: usize> {
+    data: [T; N],
+}
+
+fn main() {
+    let _ = Pair::<u8, bool> { items: Vec::<(u8, u8)>::new(), first: 0 };
+    //~^ ERROR mismatched types
+    let _: Pair<u8, bool> = Pair { items: Vec::new(), first: "" };
+    //~^ ERROR mismatched types
+    let _ = Array::<u8, 2> { data: [1, 2, 3] };
+    //~^ ERROR mismatched types
+}
@@ -0,0 +1,41 @@
+// Struct literals that are missing fields suggest `..Default::default()` when the struct
+// implements `Default` in the body's param-env.
+
+#[derive(Default)]
+struct Config {
+    verbose: bool,
+    retries: u32,
+    name: String,
+}
+
+#[derive(Default)]
+struct Wrapper<T> {
+    value: T,
+    count: usize,
+}
+
+struct NoDefault {
+    a: u8,
+    b: u8,
+}
+
+fn wrap<T: Default>(count: usize) -> Wrapper<T> {
+    Wrapper { count }
+    //~^ ERROR missing field `value` in initializer of `Wrapper<_>`
+}
+
+fn wrap_any<T>(count: usize) -> Wrapper<T> {
+    Wrapper { count }
+    //~^ ERROR missing field `value` in initializer of `Wrapper<_>`
+}
+
+fn main() {
+    let _ = Config { verbose: true };
+    //~^ ERROR missing fields `name` and `retries` in initializer of `Config`
+    let _ = Config { verbose: true, };
+    //~^ ERROR missing fields `name` and `retries` in initializer of `Config`
+    let _ = Config {};
+    //~^ ERROR missing fields `name`, `retries` and `verbose` in initializer of `Config`
+    let _ = NoDefault { a: 1 };
+    //~^ ERROR missing field `b` in initializer of `NoDefault`
+}
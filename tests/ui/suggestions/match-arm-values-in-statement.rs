@@ -0,0 +1,20 @@
+// Check the suggestions for a `match` in statement position whose arms produce a value.
+
+fn discard(n: u32) {
+    match n { //~ ERROR mismatched types
+        0 => return,
+        1 => n + 1,
+        _ => n * 2,
+    }
+    println!("{n}");
+}
+
+fn tail(n: u32) -> u32 { //~ ERROR mismatched types
+    match n { //~ ERROR mismatched types
+        0 => 1,
+        _ => n,
+    }
+    println!("{n}");
+}
+
+fn main() {}
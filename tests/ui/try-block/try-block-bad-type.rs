@@ -9,10 +9,10 @@ pub fn main() {
     };
 
     let res: Result<i32, i32> = try {
-        "" //~ ERROR type mismatch
+        "" //~ ERROR expected `try` block of type `Result<i32, i32>` to produce `i32`
     };
 
-    let res: Result<i32, i32> = try { }; //~ ERROR type mismatch
+    let res: Result<i32, i32> = try { }; //~ ERROR to produce `i32`, but it produces `()`
 
     let res: () = try { };
     //~^ ERROR a `try` block must return `Result` or `Option`
@@ -8,11 +8,11 @@ fn main() {
     let _: Option<f32> = try {
         foo()?;
         42
-        //~^ ERROR type mismatch
+        //~^ ERROR expected `try` block of type `Option<f32>` to produce `f32`
     };
 
     let _: Option<i32> = try {
         foo()?;
     };
-    //~^ ERROR type mismatch
+    //~^ ERROR expected `try` block of type `Option<i32>` to produce `i32`
 }
@@ -0,0 +1,51 @@
+// aux-build:anyhow.rs
+// Check that a failed `?` conversion names both error types, points at the return type that the
+// `?` returns from, and suggests error types that the error can be converted into.
+
+extern crate anyhow;
+
+use std::fmt;
+
+#[derive(Debug)]
+struct ParseError;
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "parse error")
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[derive(Debug)]
+struct AppError;
+
+fn parse() -> Result<u32, ParseError> {
+    Err(ParseError)
+}
+
+fn run() -> Result<u32, AppError> {
+    let n = parse()?;
+    //~^ ERROR `?` couldn't convert the error from `ParseError` to `AppError`
+    Ok(n)
+}
+
+struct App;
+
+impl App {
+    fn run(&self) -> Result<(), AppError> {
+        for _ in 0..3 {
+            parse()?;
+            //~^ ERROR `?` couldn't convert the error from `ParseError` to `AppError`
+        }
+        Ok(())
+    }
+}
+
+fn in_closure() -> Result<u32, ParseError> {
+    let f = || -> Result<u32, u8> { Ok(parse()?) };
+    //~^ ERROR `?` couldn't convert the error from `ParseError` to `u8`
+    f().map_err(|_| ParseError)
+}
+
+fn main() {}
@@ -0,0 +1,44 @@
+// run-pass
+// Exercises the places where typeck and writeback rewrite the adjustments
+// already recorded for an expression, which are stored as interned lists.
+
+use std::collections::HashMap;
+
+fn main() {
+    // Builtin comparison and compound assignment: writeback drops the autorefs
+    // that were recorded for the overloaded operator.
+    let (mut a, b) = (1u32, 2u32);
+    assert!(a < b && a != b);
+    a += b;
+    a <<= 1;
+    assert_eq!(a, 6);
+
+    // Builtin indexing of an array: the autoref and unsizing of the base are dropped.
+    let arr = [10, 20, 30];
+    assert_eq!(arr[1], 20);
+    let r = &&arr;
+    assert_eq!(r[2], 30);
+
+    // Overloaded comparison: an autoref is appended to the rhs adjustments.
+    let s = String::from("x");
+    assert!(s == String::from("x"));
+    let bs = Box::new(String::from("y"));
+    assert!(*bs > s);
+
+    // Mutable place ops: the recorded autoderefs and autoref of the base are
+    // converted to `DerefMut`/`IndexMut` after the fact.
+    let mut v = vec![1, 2, 3];
+    let rv = &mut v;
+    rv[0] = 4;
+    rv[1] += 1;
+    let mut bv = Box::new(vec![vec![0u8; 2]]);
+    bv[0][1] = 7;
+    bv.push(vec![]);
+    let mut m: HashMap<u8, Vec<u8>> = HashMap::new();
+    m.entry(0).or_default().push(1);
+    *m.get_mut(&0).unwrap().first_mut().unwrap() += 1;
+    assert_eq!(v, [4, 3, 3]);
+    assert_eq!(bv[0], [0, 7]);
+    assert_eq!(bv.len(), 2);
+    assert_eq!(m[&0], [2]);
+}
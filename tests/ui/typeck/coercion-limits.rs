@@ -0,0 +1,19 @@
+// compile-flags: -Zcoercion-nesting-limit=3 -Zcoercion-sites-limit=3
+
+fn nested(a: bool) -> u32 {
+    let x = if a { 1 } else if a { 2 } else if a { 3 } else { 4 };
+    //~^ ERROR blocks, `if` and `match` expressions are nested too deeply to check their types
+    x
+}
+
+fn arms(n: u32) -> u32 {
+    match n {
+        0 => 0,
+        1 => { 1 + 1 }
+        2 => 2,
+        _ => 3,
+        //~^ ERROR too many expressions to unify their types
+    }
+}
+
+fn main() {}
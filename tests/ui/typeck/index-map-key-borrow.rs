@@ -0,0 +1,17 @@
+use std::collections::{BTreeMap, HashMap};
+
+fn main() {
+    let map: HashMap<String, i32> = HashMap::new();
+    let key: &str = "a";
+    let _ = map[&key];
+    //~^ ERROR the type `HashMap<String, i32>` cannot be indexed by `&&str`
+
+    let map: BTreeMap<&str, i32> = BTreeMap::new();
+    let key = String::from("a");
+    let _ = map[&key];
+    //~^ ERROR the type `BTreeMap<&str, i32>` cannot be indexed by `&String`
+
+    let map: HashMap<String, i32> = HashMap::new();
+    let _ = map[&1];
+    //~^ ERROR the type `HashMap<String, i32>` cannot be indexed by `&{integer}`
+}
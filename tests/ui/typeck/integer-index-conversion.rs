@@ -0,0 +1,47 @@
+// Indexing a slice with an integer type other than `usize` suggests converting the index,
+// with `usize::try_from` if an out-of-range index can be propagated out of the function.
+
+use std::error::Error;
+use std::num::TryFromIntError;
+
+fn unsigned(v: &[u8], i: u64) -> u8 {
+    v[i]
+    //~^ ERROR the type `[u8]` cannot be indexed by `u64`
+}
+
+fn signed(v: &[u8], i: i32) -> u8 {
+    v[i - 1]
+    //~^ ERROR the type `[u8]` cannot be indexed by `i32`
+}
+
+fn propagate_result(v: Vec<u8>, i: i32) -> Result<u8, TryFromIntError> {
+    Ok(v[i])
+    //~^ ERROR the type `[u8]` cannot be indexed by `i32`
+}
+
+fn propagate_boxed(a: [u8; 4], i: i64) -> Result<u8, Box<dyn Error>> {
+    Ok(a[i])
+    //~^ ERROR the type `[u8]` cannot be indexed by `i64`
+}
+
+fn propagate_option(v: &[u8], i: isize) -> Option<u8> {
+    Some(v[i])
+    //~^ ERROR the type `[u8]` cannot be indexed by `isize`
+}
+
+fn unconvertible_error(v: &[u8], i: i32) -> Result<u8, String> {
+    Ok(v[i])
+    //~^ ERROR the type `[u8]` cannot be indexed by `i32`
+}
+
+fn propagate_wide(v: &[u8], i: u64) -> Option<u8> {
+    Some(v[i])
+    //~^ ERROR the type `[u8]` cannot be indexed by `u64`
+}
+
+fn lossless(v: &[u8], i: u16) -> Option<u8> {
+    Some(v[i])
+    //~^ ERROR the type `[u8]` cannot be indexed by `u16`
+}
+
+fn main() {}
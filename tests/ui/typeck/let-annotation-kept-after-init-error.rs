@@ -0,0 +1,19 @@
+// Check that a `let` with a type annotation keeps the annotated type when its
+// initializer has an error, so later uses of the binding are still checked.
+
+fn main() {
+    let x: u8 = missing_fn();
+    //~^ ERROR cannot find function `missing_fn` in this scope
+    let _: String = x;
+    //~^ ERROR mismatched types
+
+    let v: Vec<u8> = missing_fn();
+    //~^ ERROR cannot find function `missing_fn` in this scope
+    let _: bool = v[0];
+    //~^ ERROR mismatched types
+
+    // Without a full annotation there is nothing to check later uses against.
+    let w: Vec<_> = missing_fn();
+    //~^ ERROR cannot find function `missing_fn` in this scope
+    let _: String = w;
+}
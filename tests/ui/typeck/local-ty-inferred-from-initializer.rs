@@ -0,0 +1,30 @@
+// Check that a mismatch on a binding whose type was inferred from a method call
+// points at the initializer the type came from, and that other initializers aren't
+// pointed at.
+
+struct S {
+    field: u8,
+}
+
+fn make() -> S {
+    S { field: 0 }
+}
+
+fn main() {
+    let name = "ferris".to_uppercase();
+    let len: usize = name;
+    //~^ ERROR mismatched types
+
+    // The type is visible in the initializer here, so it isn't pointed at.
+    let count = 3u8;
+    let flag: bool = count;
+    //~^ ERROR mismatched types
+
+    // Neither is the function being called, nor the struct being constructed.
+    let made = make();
+    let made_flag: bool = made;
+    //~^ ERROR mismatched types
+    let built = S { field: 1 };
+    let built_flag: bool = built;
+    //~^ ERROR mismatched types
+}
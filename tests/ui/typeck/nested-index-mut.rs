@@ -0,0 +1,43 @@
+// run-pass
+// Check that every level of a chained index expression used as a mutable place selects
+// `IndexMut`, including when the outer indexing goes through an overloaded `IndexMut`.
+
+use std::collections::HashMap;
+use std::ops::{Index, IndexMut};
+
+struct Map(HashMap<&'static str, Vec<i32>>);
+
+impl Index<&str> for Map {
+    type Output = Vec<i32>;
+    fn index(&self, key: &str) -> &Vec<i32> {
+        &self.0[key]
+    }
+}
+
+impl IndexMut<&str> for Map {
+    fn index_mut(&mut self, key: &str) -> &mut Vec<i32> {
+        self.0.get_mut(key).unwrap()
+    }
+}
+
+fn main() {
+    let mut map = Map(HashMap::new());
+    map.0.insert("a", vec![0, 0]);
+    map["a"][1] = 2;
+    map["a"][0] += 1;
+    assert_eq!(map["a"], [1, 2]);
+
+    let mut boxed = Box::new(map);
+    boxed["a"][1] *= 3;
+    assert_eq!(boxed["a"], [1, 6]);
+
+    let mut nested = vec![Map(HashMap::new())];
+    nested[0].0.insert("b", vec![0]);
+    nested[0]["b"][0] = 4;
+    assert_eq!(nested[0]["b"][0], 4);
+
+    let mut map: HashMap<&str, Vec<i32>> = HashMap::new();
+    map.insert("c", vec![0]);
+    map.get_mut("c").unwrap()[0] = 5;
+    assert_eq!(map["c"][0], 5);
+}
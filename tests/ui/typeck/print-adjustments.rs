@@ -0,0 +1,18 @@
+// check-pass
+// compile-flags: -Zprint-adjustments=main
+
+struct S;
+
+impl S {
+    fn get(&self) -> u8 {
+        0
+    }
+}
+
+fn takes_slice(_: &[i32]) {}
+
+fn main() {
+    let a = [1, 2];
+    takes_slice(&a);
+    S.get();
+}
@@ -0,0 +1,21 @@
+// Check that struct expressions whose last field is unsized point at that field.
+
+struct Slice {
+    len: usize,
+    data: [u8],
+}
+
+trait Trait {}
+
+struct Object {
+    object: dyn Trait,
+}
+
+fn build(data: &[u8], object: &dyn Trait) {
+    let _ = Slice { len: data.len(), data: *data };
+    //~^ ERROR the size for values of type `[u8]` cannot be known at compilation time
+    let _ = Object { object: *object };
+    //~^ ERROR the size for values of type `(dyn Trait + 'static)` cannot be known
+}
+
+fn main() {}
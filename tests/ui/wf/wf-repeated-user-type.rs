@@ -0,0 +1,12 @@
+// Check that a user-written type that is repeated within a body is still WF-checked, but only
+// reported once, while a different type is reported on its own.
+
+struct MustBeCopy<T: Copy> {
+    t: T,
+}
+
+fn main() {
+    let _: Option<MustBeCopy<String>> = None; //~ ERROR E0277
+    let _: Option<MustBeCopy<String>> = None;
+    let _: Option<MustBeCopy<Vec<u8>>> = None; //~ ERROR E0277
+}